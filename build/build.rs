@@ -3,11 +3,11 @@
 
 use bindgen;
 use bindgen::callbacks::EnumVariantValue;
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use std::env::var;
 use std::fmt::Debug;
 use std::io::Write;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 use std::sync::{Arc, Mutex};
 
 macro_rules! cargo_warning {
@@ -28,6 +28,8 @@ type EnumVec = Vec<Enum>;
 
 type EnumMap = HashMap<String, EnumVec>;
 
+type ExtensionNameVec = Vec<(String, String)>;
+
 fn main() {
     let vulkan_sdk_path = PathBuf::from(var("VULKAN_SDK").expect("VULKAN_SDK not set"));
     let vulkan_include_dir: PathBuf = vulkan_sdk_path.join("Include");
@@ -39,14 +41,38 @@ fn main() {
         panic!("Vulkan header not found at {:?}", vulkan_header_path);
     });
 
+    let vulkan_registry_path = vulkan_sdk_path
+        .join("share")
+        .join("vulkan")
+        .join("registry")
+        .join("vk.xml");
+
+    (!vulkan_registry_path.exists()).then(|| {
+        panic!("Vulkan registry not found at {:?}", vulkan_registry_path);
+    });
+    println!("cargo:rerun-if-changed={}", vulkan_registry_path.display());
+
     let enum_map = Arc::new(Mutex::new(EnumMap::new()));
+    let extension_names = Arc::new(Mutex::new(ExtensionNameVec::new()));
+
+    // window system headers are only included for the platforms enabled through features
+    let platform_defines = [
+        ("CARGO_FEATURE_XLIB", "-DVK_USE_PLATFORM_XLIB_KHR=1"),
+        ("CARGO_FEATURE_XCB", "-DVK_USE_PLATFORM_XCB_KHR=1"),
+        ("CARGO_FEATURE_WAYLAND", "-DVK_USE_PLATFORM_WAYLAND_KHR=1"),
+    ]
+    .into_iter()
+    .filter(|(feature, _)| var(feature).is_ok())
+    .map(|(_, define)| define);
 
     bindgen::builder()
         .parse_callbacks(Box::new(FormatCallback {
             enum_map: enum_map.clone(),
+            extension_names: extension_names.clone(),
             cargo_callbacks: bindgen::CargoCallbacks::new(),
         }))
         .clang_args(&["-I", vulkan_include_dir.to_str().unwrap()])
+        .clang_args(platform_defines)
         .header("./src/wrapper.h")
         .allowlist_recursively(false)
         .allowlist_file(".*vulkan.*")
@@ -89,6 +115,58 @@ fn main() {
             }
         }
     }
+
+    {
+        // 64-bit flag bits are `static const` variables rather than enums, so bindgen never
+        // reports them and they are read from the header text instead.
+        let core_header_path = vulkan_include_dir.join("vulkan").join("vulkan_core.h");
+        let core_header = std::fs::read_to_string(&core_header_path).unwrap();
+
+        let flags64_path = out_path.join("flags64.rs");
+        let mut flags64_file = std::fs::File::create(&flags64_path).unwrap();
+
+        for config in build_flags64_configs() {
+            let variants = parse_flags64(&core_header, config.name);
+            write_flags64(&mut flags64_file, &config, variants.iter());
+        }
+    }
+
+    {
+        let extension_names_path = out_path.join("extension_names.rs");
+        let mut extension_names_file = std::fs::File::create(&extension_names_path).unwrap();
+
+        let mut extension_names = extension_names.lock().unwrap();
+        extension_names.sort();
+        extension_names.dedup();
+
+        write_extension_names(&mut extension_names_file, extension_names.iter());
+    }
+
+    {
+        let extends_path = out_path.join("extends.rs");
+        let mut extends_file = std::fs::File::create(&extends_path).unwrap();
+
+        write_extends(&mut extends_file, &vulkan_registry_path);
+    }
+
+    {
+        let format_info_path = out_path.join("format_info.rs");
+        let mut format_info_file = std::fs::File::create(&format_info_path).unwrap();
+
+        let formats = enum_map
+            .lock()
+            .unwrap()
+            .get("VkFormat")
+            .map(|formats| {
+                formats
+                    .iter()
+                    .filter_map(|(name, _)| format_enum_variant_name("VK_FORMAT_", name))
+                    .collect::<HashSet<_>>()
+            })
+            .unwrap_or_default();
+
+        write_format_info(&mut format_info_file, &vulkan_registry_path, &formats);
+    }
 }
 
 fn format_enum_name(name: &str) -> String {
@@ -228,9 +306,338 @@ fn write_flags<'a, W: Write, I: Iterator<Item = &'a Enum>>(
     writeln!(writer).unwrap();
 }
 
+/// Collects every `static const <name> VK_..._BIT = 0x...ULL;` declaration, aliases included.
+fn parse_flags64(header: &str, name: &str) -> Vec<(String, u64)> {
+    let declaration = format!("static const {} ", name);
+
+    let mut variants: Vec<(String, u64)> = Vec::new();
+    for line in header.lines().map(str::trim) {
+        let declared = match line.strip_prefix(declaration.as_str()) {
+            Some(declared) => declared,
+            None => continue,
+        };
+
+        let (variant, value) = match declared.trim_end_matches(';').split_once('=') {
+            Some((variant, value)) => (variant.trim(), value.trim().trim_end_matches("ULL")),
+            None => continue,
+        };
+
+        let value = match value.strip_prefix("0x") {
+            Some(hex) => u64::from_str_radix(hex, 16),
+            None => value.parse::<u64>(),
+        };
+        let value = match value {
+            Ok(value) => value,
+            Err(_) => {
+                cargo_warning!("Failed to parse value of flag: {}", variant);
+                continue;
+            }
+        };
+
+        variants.push((variant.to_string(), value));
+    }
+    variants
+}
+
+fn write_flags64<'a, W: Write, I: Iterator<Item = &'a (String, u64)>>(
+    writer: &mut W,
+    config: &Flags64Config,
+    variants: I,
+) {
+    writeln!(writer, "bitflags! {{").unwrap();
+    writeln!(writer, "    #[derive(Default, Clone, Copy, PartialEq, Eq)]").unwrap();
+    writeln!(writer, "    pub struct {}: u64 {{", config.custom_name).unwrap();
+
+    let mut written = HashSet::new();
+    for (variant, value) in variants {
+        let formatted = match format_flag_variant_name(config.prefix, variant) {
+            Some(formatted) => formatted,
+            None => continue,
+        };
+        if !written.insert(formatted.clone()) {
+            continue;
+        }
+
+        writeln!(writer, "        const {} = {:#x};", formatted, value).unwrap();
+    }
+
+    writeln!(writer, "    }}").unwrap();
+    writeln!(writer, "}}").unwrap();
+    writeln!(
+        writer,
+        "assert_eq_size!({}, {});",
+        config.custom_name, config.name
+    )
+    .unwrap();
+    writeln!(writer).unwrap();
+}
+
+fn format_extension_name_constant(name: &str) -> Option<String> {
+    let formatted = name.strip_prefix("VK_")?.strip_suffix("_EXTENSION_NAME")?;
+    (formatted.starts_with("KHR_") || formatted.starts_with("EXT_")).then(|| formatted.to_string())
+}
+
+fn write_extension_names<'a, W: Write, I: Iterator<Item = &'a (String, String)>>(
+    writer: &mut W,
+    extension_names: I,
+) {
+    for (constant, extension_name) in extension_names {
+        writeln!(
+            writer,
+            "pub const {}: &CStr = c\"{}\";",
+            constant, extension_name
+        )
+        .unwrap();
+    }
+}
+
+fn supports_vulkan(node: roxmltree::Node, attribute: &str) -> bool {
+    node.attribute(attribute)
+        .map_or(true, |apis| apis.split(',').any(|api| api == "vulkan"))
+}
+
+fn collect_required_types(node: roxmltree::Node, types: &mut HashSet<String>) {
+    for require in node
+        .children()
+        .filter(|child| child.has_tag_name("require"))
+        .filter(|require| supports_vulkan(*require, "api"))
+    {
+        for ty in require
+            .children()
+            .filter(|child| child.has_tag_name("type"))
+        {
+            if let Some(name) = ty.attribute("name") {
+                types.insert(name.to_string());
+            }
+        }
+    }
+}
+
+/// Emits an `Extends<Parent>` impl for every `structextends` pair in the registry.
+///
+/// Only types present in the generated bindings are considered, so platform specific and
+/// provisional extensions are skipped.
+fn write_extends<W: Write>(writer: &mut W, registry_path: &Path) {
+    let registry = std::fs::read_to_string(registry_path).unwrap();
+    let document = roxmltree::Document::parse(&registry).unwrap();
+    let root = document.root_element();
+
+    let mut available = HashSet::new();
+
+    for feature in root
+        .children()
+        .filter(|child| child.has_tag_name("feature"))
+        .filter(|feature| supports_vulkan(*feature, "api"))
+    {
+        collect_required_types(feature, &mut available);
+    }
+
+    for extension in root
+        .descendants()
+        .filter(|node| node.has_tag_name("extension"))
+        .filter(|extension| supports_vulkan(*extension, "supported"))
+        .filter(|extension| !extension.has_attribute("platform"))
+        .filter(|extension| extension.attribute("provisional") != Some("true"))
+    {
+        collect_required_types(extension, &mut available);
+    }
+
+    let mut impls = Vec::new();
+
+    for ty in root
+        .descendants()
+        .filter(|node| node.has_tag_name("type"))
+        .filter(|ty| ty.attribute("category") == Some("struct"))
+        .filter(|ty| supports_vulkan(*ty, "api"))
+    {
+        let (name, extends) = match (ty.attribute("name"), ty.attribute("structextends")) {
+            (Some(name), Some(extends)) => (name, extends),
+            _ => continue,
+        };
+
+        if !available.contains(name) {
+            continue;
+        }
+
+        for parent in extends
+            .split(',')
+            .filter(|parent| available.contains(*parent))
+        {
+            impls.push((parent.to_string(), name.to_string()));
+        }
+    }
+
+    impls.sort();
+    impls.dedup();
+
+    for (parent, child) in impls {
+        writeln!(writer, "unsafe impl Extends<{}> for {} {{}}", parent, child).unwrap();
+    }
+}
+
+fn format_component_name(name: &str) -> Option<&'static str> {
+    match name {
+        "R" => Some("R"),
+        "G" => Some("G"),
+        "B" => Some("B"),
+        "A" => Some("A"),
+        "D" => Some("D"),
+        "S" => Some("S"),
+        _ => None,
+    }
+}
+
+fn format_numeric_format(name: &str) -> Option<&'static str> {
+    match name {
+        "UNORM" => Some("Unorm"),
+        "SNORM" => Some("Snorm"),
+        "USCALED" => Some("Uscaled"),
+        "SSCALED" => Some("Sscaled"),
+        "UINT" => Some("Uint"),
+        "SINT" => Some("Sint"),
+        "UFLOAT" => Some("Ufloat"),
+        "SFLOAT" => Some("Sfloat"),
+        "SRGB" => Some("Srgb"),
+        "SFIXED5" => Some("Sfixed5"),
+        _ => None,
+    }
+}
+
+fn format_component(component: roxmltree::Node) -> Option<String> {
+    let name = component.attribute("name")?;
+    let name = match format_component_name(name) {
+        Some(name) => name,
+        None => {
+            cargo_warning!("Unknown format component: {}", name);
+            return None;
+        }
+    };
+
+    let numeric_format = component.attribute("numericFormat")?;
+    let numeric_format = match format_numeric_format(numeric_format) {
+        Some(numeric_format) => numeric_format,
+        None => {
+            cargo_warning!("Unknown numeric format: {}", numeric_format);
+            return None;
+        }
+    };
+
+    let bits = match component.attribute("bits")? {
+        "compressed" => "None".to_string(),
+        bits => format!("Some({})", bits.parse::<u8>().ok()?),
+    };
+
+    Some(format!(
+        "FormatComponent {{ name: ComponentName::{}, bits: {}, numeric_format: NumericFormat::{} }}",
+        name, bits, numeric_format
+    ))
+}
+
+/// Emits `Format::info` from the `<formats>` block of the registry.
+///
+/// Formats without a matching `Format` variant, or using components and numeric formats that
+/// `FormatInfo` cannot describe, are left out and report `None`.
+fn write_format_info<W: Write>(writer: &mut W, registry_path: &Path, formats: &HashSet<String>) {
+    let registry = std::fs::read_to_string(registry_path).unwrap();
+    let document = roxmltree::Document::parse(&registry).unwrap();
+    let root = document.root_element();
+
+    writeln!(writer, "impl Format {{").unwrap();
+    writeln!(
+        writer,
+        "    /// Texel block layout of the format, or `None` for `UNDEFINED`."
+    )
+    .unwrap();
+    writeln!(
+        writer,
+        "    pub const fn info(&self) -> Option<&'static FormatInfo> {{"
+    )
+    .unwrap();
+    writeln!(writer, "        match self {{").unwrap();
+
+    for format in root
+        .descendants()
+        .filter(|node| node.has_tag_name("formats"))
+        .flat_map(|formats| formats.children())
+        .filter(|node| node.has_tag_name("format"))
+    {
+        let variant = match format
+            .attribute("name")
+            .and_then(|name| format_enum_variant_name("VK_FORMAT_", name))
+        {
+            Some(variant) if formats.contains(&variant) => variant,
+            _ => continue,
+        };
+
+        let components = format
+            .children()
+            .filter(|child| child.has_tag_name("component"))
+            .map(format_component)
+            .collect::<Option<Vec<_>>>();
+        let components = match components {
+            Some(components) => components,
+            None => continue,
+        };
+
+        let class = format.attribute("class").unwrap_or_default();
+        let block_size = format.attribute("blockSize").unwrap_or("0");
+        let texels_per_block = format.attribute("texelsPerBlock").unwrap_or("1");
+        let block_extent = format.attribute("blockExtent").unwrap_or("1,1,1");
+        let block_extent = block_extent.split(',').collect::<Vec<_>>();
+        let compression = format
+            .attribute("compressed")
+            .map_or("None".to_string(), |scheme| format!("Some({:?})", scheme));
+        let packed = format
+            .attribute("packed")
+            .map_or("None".to_string(), |packed| format!("Some({})", packed));
+        let plane_count = format
+            .children()
+            .filter(|child| child.has_tag_name("plane"))
+            .count()
+            .max(1);
+
+        writeln!(
+            writer,
+            "            Self::{} => Some(&FormatInfo {{",
+            variant
+        )
+        .unwrap();
+        writeln!(writer, "                class: {:?},", class).unwrap();
+        writeln!(writer, "                block_size: {},", block_size).unwrap();
+        writeln!(
+            writer,
+            "                block_extent: [{}],",
+            block_extent.join(", ")
+        )
+        .unwrap();
+        writeln!(
+            writer,
+            "                texels_per_block: {},",
+            texels_per_block
+        )
+        .unwrap();
+        writeln!(writer, "                compression: {},", compression).unwrap();
+        writeln!(writer, "                packed: {},", packed).unwrap();
+        writeln!(writer, "                plane_count: {},", plane_count).unwrap();
+        writeln!(
+            writer,
+            "                components: &[{}],",
+            components.join(", ")
+        )
+        .unwrap();
+        writeln!(writer, "            }}),").unwrap();
+    }
+
+    writeln!(writer, "            _ => None,").unwrap();
+    writeln!(writer, "        }}").unwrap();
+    writeln!(writer, "    }}").unwrap();
+    writeln!(writer, "}}").unwrap();
+}
+
 #[derive(Debug)]
 struct FormatCallback {
     enum_map: Arc<Mutex<EnumMap>>,
+    extension_names: Arc<Mutex<ExtensionNameVec>>,
     cargo_callbacks: bindgen::CargoCallbacks,
 }
 
@@ -272,6 +679,27 @@ impl bindgen::callbacks::ParseCallbacks for FormatCallback {
         None
     }
 
+    fn str_macro(&self, name: &str, value: &[u8]) {
+        let constant = match format_extension_name_constant(name) {
+            Some(constant) => constant,
+            None => return,
+        };
+
+        let value = value.strip_suffix(&[0]).unwrap_or(value);
+        let extension_name = match std::str::from_utf8(value) {
+            Ok(extension_name) => extension_name.to_string(),
+            Err(_) => {
+                cargo_warning!("Extension name is not valid utf-8: {}", name);
+                return;
+            }
+        };
+
+        self.extension_names
+            .lock()
+            .unwrap()
+            .push((constant, extension_name));
+    }
+
     fn header_file(&self, filename: &str) {
         self.cargo_callbacks.header_file(filename);
     }
@@ -281,6 +709,28 @@ impl bindgen::callbacks::ParseCallbacks for FormatCallback {
     }
 }
 
+#[derive(Debug, Clone)]
+struct Flags64Config<'a> {
+    name: &'a str,
+    custom_name: &'a str,
+    prefix: &'a str,
+}
+
+fn build_flags64_configs() -> Vec<Flags64Config<'static>> {
+    vec![
+        Flags64Config {
+            name: "VkPipelineStageFlagBits2",
+            custom_name: "PipelineStageFlags2",
+            prefix: "VK_PIPELINE_STAGE_2_",
+        },
+        Flags64Config {
+            name: "VkAccessFlagBits2",
+            custom_name: "AccessFlags2",
+            prefix: "VK_ACCESS_2_",
+        },
+    ]
+}
+
 #[derive(Debug, Clone)]
 struct EnumConfig<'a> {
     name: &'a str,
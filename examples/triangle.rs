@@ -1,30 +1,22 @@
 // Copyright (c) 2024 Jacob R. Green
 // All rights reserved.
 
-use std::ffi::{c_char, CStr};
+use std::ffi::CStr;
 use vulkan::*;
 
 use glfw::*;
-use vulkan_sys::vkGetDeviceProcAddr;
 
 const MAX_FRAMES_IN_FLIGHT: usize = 3;
 
 const APP_NAME: &CStr = c"Hello Triangle";
 
-const INSTANCE_LAYERS: &[*const c_char] = &[c"VK_LAYER_KHRONOS_validation".as_ptr()];
-const INSTANCE_EXTENSIONS: &[*const c_char] =
-    &[c"VK_KHR_surface".as_ptr(), c"VK_KHR_win32_surface".as_ptr()];
+const INSTANCE_LAYERS: &[&CStr] = &[c"VK_LAYER_KHRONOS_validation"];
 
 static APP_INFO: ApplicationInfo<'static> = ApplicationInfo::new()
     .with_api_version(ApiVersion::VERSION_1_0)
     .with_application_name(APP_NAME);
 
-static INSTANCE_CREATE_INFO: InstanceCreateInfo<'static> = InstanceCreateInfo::new()
-    .with_application_info(&APP_INFO)
-    .with_enabled_layers(INSTANCE_LAYERS)
-    .with_enabled_extensions(INSTANCE_EXTENSIONS);
-
-const DEVICE_EXTENSIONS: &[*const c_char] = &[c"VK_KHR_swapchain".as_ptr()];
+const DEVICE_EXTENSIONS: &[&CStr] = &[extension_names::KHR_SWAPCHAIN];
 
 const QUEUE_FAMILY_INDEX: u32 = 0;
 const QUEUE_PRIORITY: f32 = 1.0;
@@ -33,9 +25,8 @@ const QUEUE_CREATE_INFO: DeviceQueueCreateInfo<'static> = DeviceQueueCreateInfo:
     .with_queue_family_index(QUEUE_FAMILY_INDEX)
     .with_queue_priorities(&[QUEUE_PRIORITY]);
 
-static DEVICE_CREATE_INFO: DeviceCreateInfo<'static> = DeviceCreateInfo::new()
-    .with_queue_create_infos(&[QUEUE_CREATE_INFO])
-    .with_enabled_extensions(DEVICE_EXTENSIONS);
+static DEVICE_CREATE_INFO: DeviceCreateInfo<'static> =
+    DeviceCreateInfo::new().with_queue_create_infos(&[QUEUE_CREATE_INFO]);
 
 // static COMMAND_POOL_CREATE_INFO: CommandPoolCreateInfo = CommandPoolCreateInfo::new()
 //     .with_flags(CommandPoolCreateFlags::TRANSIENT | CommandPoolCreateFlags::RESET_COMMAND_BUFFER)
@@ -52,26 +43,36 @@ fn main() {
 
     window.set_key_polling(true);
 
-    let instance = Instance::create(&INSTANCE_CREATE_INFO, None).unwrap();
+    let instance_layers = CStrArray::new(INSTANCE_LAYERS);
+    let instance_extensions = CStrArray::new(
+        SurfaceKHR::required_extensions(&*window)
+            .expect("no surface extensions for this window system, enable its cargo feature"),
+    );
+
+    let instance = {
+        let create_info = InstanceCreateInfo::new()
+            .with_application_info(&APP_INFO)
+            .with_enabled_layer_names(&instance_layers)
+            .with_enabled_extension_names(&instance_extensions);
+
+        Instance::create_checked(&create_info, None).unwrap()
+    };
 
     let physical_devices = instance.enumerate_physical_devices().unwrap();
-    let physical_device = physical_devices[0];
+    let physical_device = &physical_devices[0];
 
     let properties = instance.get_physical_device_properties(physical_device);
-    let features = instance.get_physical_device_features(physical_device);
-
-    let surface = {
-        let create_info = Win32SurfaceCreateInfoKHR::new().with_hwnd(
-            windows::Win32::Foundation::HWND(window.get_win32_window() as isize),
-        );
-        instance
-            .create_win32_surface_khr(&create_info, None)
-            .unwrap()
-    };
+    let features = PhysicalDeviceFeatures::new();
+
+    let surface = SurfaceKHR::from_window(&instance, &*window, None).unwrap();
 
-    let device = Device::create(
+    let device_extensions = CStrArray::new(DEVICE_EXTENSIONS);
+    let device = Device::create_checked(
         physical_device,
-        &DEVICE_CREATE_INFO.clone().with_enabled_features(&features),
+        &DEVICE_CREATE_INFO
+            .clone()
+            .with_enabled_extension_names(&device_extensions)
+            .with_enabled_features(&features),
         None,
     )
     .unwrap();
@@ -228,7 +229,7 @@ fn main() {
         let curr_in_flight = in_flight_fences[current_frame];
         let curr_image_available = image_available_semaphores[current_frame];
         let curr_render_finished = render_finished_semaphores[current_frame];
-        let curr_command_buffer = buffers[current_frame];
+        let curr_command_buffer = &buffers[current_frame];
 
         device
             .wait_for_fences(&[curr_in_flight], true, u64::MAX)
@@ -273,7 +274,7 @@ fn main() {
             let wait_semaphores = [curr_image_available];
             let wait_stages = [PipelineStageFlags::COLOR_ATTACHMENT_OUTPUT];
             let signal_semaphores = [curr_render_finished];
-            let command_buffers = [curr_command_buffer];
+            let command_buffers: CommandBufferArray = [curr_command_buffer].into_iter().collect();
 
             let submit_info = SubmitInfo::new()
                 .with_wait_semaphores(&wait_semaphores)
@@ -0,0 +1,342 @@
+// Copyright (c) 2024 Jacob R. Green
+// All rights reserved.
+
+use crate::*;
+
+/*
+   Resource Kind
+*/
+
+/// Distinguishes linear resources (buffers, linear images) from optimally tiled images.
+///
+/// Neighbouring resources of different kinds must not share a `bufferImageGranularity` page.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ResourceKind {
+    Linear,
+    Optimal,
+}
+
+/*
+   Free List
+*/
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Range {
+    offset: DeviceSize,
+    size: DeviceSize,
+    kind: Option<ResourceKind>,
+}
+
+impl Range {
+    const fn end(&self) -> DeviceSize {
+        self.offset + self.size
+    }
+
+    const fn is_free(&self) -> bool {
+        self.kind.is_none()
+    }
+}
+
+/// Best-fit sub-allocator over a single `[0, size)` address range.
+///
+/// Ranges are kept sorted and contiguous, and no two free ranges are ever adjacent.
+#[derive(Debug, Clone)]
+pub struct FreeList {
+    size: DeviceSize,
+    granularity: DeviceSize,
+    ranges: Vec<Range>,
+    used: DeviceSize,
+    allocation_count: u32,
+}
+
+impl FreeList {
+    pub fn new(size: DeviceSize, granularity: DeviceSize) -> Self {
+        Self {
+            size,
+            granularity: granularity.max(1),
+            ranges: vec![Range {
+                offset: 0,
+                size,
+                kind: None,
+            }],
+            used: 0,
+            allocation_count: 0,
+        }
+    }
+
+    pub const fn size(&self) -> DeviceSize {
+        self.size
+    }
+
+    pub const fn used(&self) -> DeviceSize {
+        self.used
+    }
+
+    pub const fn available(&self) -> DeviceSize {
+        self.size - self.used
+    }
+
+    pub const fn allocation_count(&self) -> u32 {
+        self.allocation_count
+    }
+
+    pub const fn is_empty(&self) -> bool {
+        self.allocation_count == 0
+    }
+
+    /// Reserves `size` bytes aligned to `alignment`, returning the offset of the reservation.
+    pub fn allocate(
+        &mut self,
+        size: DeviceSize,
+        alignment: DeviceSize,
+        kind: ResourceKind,
+    ) -> Option<DeviceSize> {
+        if size == 0 || size > self.available() {
+            return None;
+        }
+
+        let alignment = alignment.max(1);
+
+        let mut best: Option<(usize, DeviceSize)> = None;
+        for (index, range) in self.ranges.iter().enumerate() {
+            if !range.is_free() || range.size < size {
+                continue;
+            }
+
+            if let Some((best_index, _)) = best {
+                if self.ranges[best_index].size <= range.size {
+                    continue;
+                }
+            }
+
+            if let Some(offset) = self.fit(index, size, alignment, kind) {
+                best = Some((index, offset));
+            }
+        }
+
+        let (index, offset) = best?;
+        self.split(index, offset, size, kind);
+        self.used += size;
+        self.allocation_count += 1;
+        Some(offset)
+    }
+
+    /// Releases the reservation starting at `offset`, returning its size.
+    pub fn free(&mut self, offset: DeviceSize) -> Option<DeviceSize> {
+        let mut index = self
+            .ranges
+            .binary_search_by_key(&offset, |range| range.offset)
+            .ok()?;
+
+        let range = &mut self.ranges[index];
+        if range.is_free() {
+            return None;
+        }
+
+        let size = range.size;
+        range.kind = None;
+        self.used -= size;
+        self.allocation_count -= 1;
+
+        if index + 1 < self.ranges.len() && self.ranges[index + 1].is_free() {
+            self.ranges[index].size += self.ranges[index + 1].size;
+            self.ranges.remove(index + 1);
+        }
+
+        if index > 0 && self.ranges[index - 1].is_free() {
+            self.ranges[index - 1].size += self.ranges[index].size;
+            self.ranges.remove(index);
+            index -= 1;
+        }
+
+        debug_assert!(self.ranges[index].is_free());
+        Some(size)
+    }
+
+    fn fit(
+        &self,
+        index: usize,
+        size: DeviceSize,
+        alignment: DeviceSize,
+        kind: ResourceKind,
+    ) -> Option<DeviceSize> {
+        let range = &self.ranges[index];
+        let mut offset = align_up(range.offset, alignment);
+
+        if let Some(prev) = index.checked_sub(1).map(|i| &self.ranges[i]) {
+            if prev.kind.is_some_and(|k| k != kind)
+                && same_page(prev.end() - 1, offset, self.granularity)
+            {
+                offset = align_up(offset, self.granularity);
+            }
+        }
+
+        let end = offset.checked_add(size)?;
+        if end > range.end() {
+            return None;
+        }
+
+        if let Some(next) = self.ranges.get(index + 1) {
+            if next.kind.is_some_and(|k| k != kind)
+                && same_page(end - 1, next.offset, self.granularity)
+            {
+                return None;
+            }
+        }
+
+        Some(offset)
+    }
+
+    fn split(&mut self, index: usize, offset: DeviceSize, size: DeviceSize, kind: ResourceKind) {
+        let range = self.ranges[index];
+        let end = offset + size;
+
+        let mut replacement = Vec::with_capacity(3);
+        if offset > range.offset {
+            replacement.push(Range {
+                offset: range.offset,
+                size: offset - range.offset,
+                kind: None,
+            });
+        }
+        replacement.push(Range {
+            offset,
+            size,
+            kind: Some(kind),
+        });
+        if end < range.end() {
+            replacement.push(Range {
+                offset: end,
+                size: range.end() - end,
+                kind: None,
+            });
+        }
+
+        self.ranges.splice(index..=index, replacement);
+    }
+}
+
+pub(crate) const fn align_up(value: DeviceSize, alignment: DeviceSize) -> DeviceSize {
+    value.div_ceil(alignment) * alignment
+}
+
+const fn same_page(a: DeviceSize, b: DeviceSize, granularity: DeviceSize) -> bool {
+    a / granularity == b / granularity
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn picks_the_smallest_hole_that_fits() {
+        let mut list = FreeList::new(1024, 1);
+        let a = list.allocate(100, 1, ResourceKind::Linear).unwrap();
+        let b = list.allocate(300, 1, ResourceKind::Linear).unwrap();
+        let c = list.allocate(100, 1, ResourceKind::Linear).unwrap();
+        let d = list.allocate(150, 1, ResourceKind::Linear).unwrap();
+        let e = list.allocate(100, 1, ResourceKind::Linear).unwrap();
+        assert_eq!((a, b, c, d, e), (0, 100, 400, 500, 650));
+
+        // holes of 300 at 100, 150 at 500 and 274 at 750
+        list.free(b);
+        list.free(d);
+
+        assert_eq!(list.allocate(120, 1, ResourceKind::Linear), Some(500));
+        assert_eq!(list.allocate(280, 1, ResourceKind::Linear), Some(100));
+    }
+
+    #[test]
+    fn aligns_offsets() {
+        let mut list = FreeList::new(1024, 1);
+        assert_eq!(list.allocate(10, 1, ResourceKind::Linear), Some(0));
+        assert_eq!(list.allocate(10, 64, ResourceKind::Linear), Some(64));
+        assert_eq!(list.used(), 20);
+    }
+
+    #[test]
+    fn pads_between_linear_and_optimal_resources() {
+        let mut list = FreeList::new(1024, 256);
+        assert_eq!(list.allocate(100, 1, ResourceKind::Linear), Some(0));
+        assert_eq!(list.allocate(100, 1, ResourceKind::Optimal), Some(256));
+
+        // fits before the optimal image without touching its page
+        assert_eq!(list.allocate(16, 1, ResourceKind::Linear), Some(100));
+        // too large for the gap, and must skip the rest of the optimal image's page
+        assert_eq!(list.allocate(200, 1, ResourceKind::Linear), Some(512));
+    }
+
+    #[test]
+    fn same_kind_resources_share_pages() {
+        let mut list = FreeList::new(1024, 256);
+        assert_eq!(list.allocate(100, 1, ResourceKind::Optimal), Some(0));
+        assert_eq!(list.allocate(100, 1, ResourceKind::Optimal), Some(100));
+    }
+
+    #[test]
+    fn keeps_the_end_of_a_hole_off_a_foreign_page() {
+        let mut list = FreeList::new(1024, 256);
+        let a = list.allocate(300, 1, ResourceKind::Optimal).unwrap();
+        assert_eq!(list.allocate(100, 1, ResourceKind::Optimal), Some(300));
+        list.free(a);
+
+        // [0, 300) is free, but a linear resource reaching into page 1 would share it
+        assert_eq!(list.allocate(290, 1, ResourceKind::Linear), Some(512));
+        assert_eq!(list.allocate(250, 1, ResourceKind::Linear), Some(0));
+    }
+
+    #[test]
+    fn merges_neighbouring_free_ranges() {
+        let mut list = FreeList::new(300, 1);
+        let a = list.allocate(100, 1, ResourceKind::Linear).unwrap();
+        let b = list.allocate(100, 1, ResourceKind::Linear).unwrap();
+        let c = list.allocate(100, 1, ResourceKind::Linear).unwrap();
+
+        assert_eq!(list.free(a), Some(100));
+        assert_eq!(list.free(c), Some(100));
+        assert_eq!(list.ranges.len(), 3);
+
+        assert_eq!(list.free(b), Some(100));
+        assert_eq!(list.ranges.len(), 1);
+        assert!(list.is_empty());
+        assert_eq!(list.used(), 0);
+        assert_eq!(list.allocate(300, 1, ResourceKind::Linear), Some(0));
+    }
+
+    #[test]
+    fn rejects_unknown_and_double_frees() {
+        let mut list = FreeList::new(256, 1);
+        let a = list.allocate(100, 1, ResourceKind::Linear).unwrap();
+
+        assert_eq!(list.free(50), None);
+        assert_eq!(list.free(100), None);
+        assert_eq!(list.free(a), Some(100));
+        assert_eq!(list.free(a), None);
+    }
+
+    #[test]
+    fn runs_out_of_space() {
+        let mut list = FreeList::new(256, 1);
+        assert_eq!(list.allocate(0, 1, ResourceKind::Linear), None);
+        assert_eq!(list.allocate(257, 1, ResourceKind::Linear), None);
+        assert_eq!(list.allocate(200, 1, ResourceKind::Linear), Some(0));
+        assert_eq!(list.allocate(100, 1, ResourceKind::Linear), None);
+        assert_eq!(list.allocate(56, 1, ResourceKind::Linear), Some(200));
+        assert_eq!(list.available(), 0);
+        assert_eq!(list.allocate(1, 1, ResourceKind::Linear), None);
+    }
+
+    #[test]
+    fn fragmentation_can_exhaust_space() {
+        let mut list = FreeList::new(300, 1);
+        let a = list.allocate(100, 1, ResourceKind::Linear).unwrap();
+        list.allocate(100, 1, ResourceKind::Linear).unwrap();
+        let c = list.allocate(100, 1, ResourceKind::Linear).unwrap();
+        list.free(a);
+        list.free(c);
+
+        assert_eq!(list.available(), 200);
+        assert_eq!(list.allocate(150, 1, ResourceKind::Linear), None);
+        assert_eq!(list.allocation_count(), 1);
+    }
+}
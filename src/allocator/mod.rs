@@ -0,0 +1,713 @@
+// Copyright (c) 2024 Jacob R. Green
+// All rights reserved.
+
+use crate::*;
+
+mod free_list;
+pub use free_list::*;
+
+/*
+   Memory Type Selection
+*/
+
+/// Picks the memory type allowed by `memory_type_bits` that has every `required` flag and the
+/// largest number of `preferred` flags.
+pub fn find_memory_type_index(
+    memory_types: &[MemoryType],
+    memory_type_bits: u32,
+    required: MemoryPropertyFlags,
+    preferred: MemoryPropertyFlags,
+) -> Option<u32> {
+    memory_types
+        .iter()
+        .enumerate()
+        .filter(|(index, _)| memory_type_bits & (1 << index) != 0)
+        .filter(|(_, memory_type)| memory_type.property_flags().contains(required))
+        .max_by_key(|(index, memory_type)| {
+            let score = (memory_type.property_flags() & preferred)
+                .bits()
+                .count_ones();
+            // prefer the lowest index among equally scored types
+            (score, std::cmp::Reverse(*index))
+        })
+        .map(|(index, _)| index as u32)
+}
+
+/// Rounds `size` and `alignment` up to `non_coherent_atom_size` for host visible, non-coherent
+/// memory, so the ranges of its allocations can be flushed and invalidated as they are.
+fn suballocation_layout(
+    property_flags: MemoryPropertyFlags,
+    size: DeviceSize,
+    alignment: DeviceSize,
+    non_coherent_atom_size: DeviceSize,
+) -> (DeviceSize, DeviceSize) {
+    let non_coherent = property_flags.contains(MemoryPropertyFlags::HOST_VISIBLE)
+        && !property_flags.contains(MemoryPropertyFlags::HOST_COHERENT);
+
+    if non_coherent && non_coherent_atom_size > 1 {
+        (
+            size.next_multiple_of(non_coherent_atom_size),
+            alignment.max(non_coherent_atom_size),
+        )
+    } else {
+        (size, alignment)
+    }
+}
+
+/*
+   Allocation Create Info
+*/
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct AllocationCreateInfo {
+    required_flags: MemoryPropertyFlags,
+    preferred_flags: MemoryPropertyFlags,
+    dedicated: bool,
+}
+
+impl AllocationCreateInfo {
+    pub const fn new() -> Self {
+        Self {
+            required_flags: MemoryPropertyFlags::empty(),
+            preferred_flags: MemoryPropertyFlags::empty(),
+            dedicated: false,
+        }
+    }
+
+    pub const fn with_required_flags(mut self, required_flags: MemoryPropertyFlags) -> Self {
+        self.required_flags = required_flags;
+        self
+    }
+
+    pub const fn with_preferred_flags(mut self, preferred_flags: MemoryPropertyFlags) -> Self {
+        self.preferred_flags = preferred_flags;
+        self
+    }
+
+    pub const fn with_dedicated(mut self, dedicated: bool) -> Self {
+        self.dedicated = dedicated;
+        self
+    }
+
+    pub const fn required_flags(&self) -> MemoryPropertyFlags {
+        self.required_flags
+    }
+
+    pub const fn preferred_flags(&self) -> MemoryPropertyFlags {
+        self.preferred_flags
+    }
+
+    pub const fn dedicated(&self) -> bool {
+        self.dedicated
+    }
+
+    pub const GPU_ONLY: Self = Self::new().with_preferred_flags(MemoryPropertyFlags::DEVICE_LOCAL);
+
+    pub const CPU_TO_GPU: Self = Self::new()
+        .with_required_flags(MemoryPropertyFlags::HOST_VISIBLE)
+        .with_preferred_flags(MemoryPropertyFlags::HOST_COHERENT);
+
+    pub const GPU_TO_CPU: Self = Self::new()
+        .with_required_flags(MemoryPropertyFlags::HOST_VISIBLE)
+        .with_preferred_flags(MemoryPropertyFlags::HOST_CACHED);
+}
+
+/*
+   Allocation
+*/
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Allocation {
+    memory: DeviceMemory,
+    offset: DeviceSize,
+    size: DeviceSize,
+    memory_type_index: u32,
+    block_id: u64,
+    mapped: *mut u8,
+}
+
+unsafe impl Send for Allocation {}
+unsafe impl Sync for Allocation {}
+
+impl Allocation {
+    pub const fn memory(&self) -> DeviceMemory {
+        self.memory
+    }
+
+    pub const fn offset(&self) -> DeviceSize {
+        self.offset
+    }
+
+    /// The size reserved for the allocation, rounded up to `nonCoherentAtomSize` on
+    /// non-coherent memory.
+    pub const fn size(&self) -> DeviceSize {
+        self.size
+    }
+
+    pub const fn memory_type_index(&self) -> u32 {
+        self.memory_type_index
+    }
+
+    /// Host pointer to the start of the allocation, if its memory type is host visible.
+    pub fn mapped_ptr(&self) -> Option<*mut u8> {
+        (!self.mapped.is_null()).then_some(self.mapped)
+    }
+
+    /// The range to flush or invalidate, aligned to `nonCoherentAtomSize` on non-coherent memory.
+    pub const fn mapped_range(&self) -> MappedMemoryRange<'static> {
+        MappedMemoryRange::new_init(self.memory, self.offset, self.size)
+    }
+}
+
+/*
+   Allocation Error
+*/
+
+#[derive(Debug, thiserror::Error)]
+pub enum AllocationError {
+    #[error("no memory type satisfies the required property flags")]
+    NoSuitableMemoryType,
+    #[error("vulkan error: {0:?}")]
+    Vulkan(Error),
+}
+
+impl From<Error> for AllocationError {
+    fn from(error: Error) -> Self {
+        Self::Vulkan(error)
+    }
+}
+
+pub type AllocationResult<T> = std::result::Result<T, AllocationError>;
+
+/*
+   Heap Stats
+*/
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct HeapStats {
+    pub heap_size: DeviceSize,
+    pub block_count: u32,
+    pub allocation_count: u32,
+    pub block_bytes: DeviceSize,
+    pub allocation_bytes: DeviceSize,
+}
+
+impl HeapStats {
+    pub const fn unused_bytes(&self) -> DeviceSize {
+        self.block_bytes - self.allocation_bytes
+    }
+}
+
+/*
+   Allocator
+*/
+
+const DEFAULT_BLOCK_SIZE: DeviceSize = 256 * 1024 * 1024;
+const SMALL_HEAP_SIZE: DeviceSize = 1024 * 1024 * 1024;
+
+struct MemoryBlock {
+    id: u64,
+    memory: DeviceMemory,
+    mapped: *mut u8,
+    free_list: FreeList,
+    dedicated: bool,
+}
+
+/// General purpose allocator that sub-allocates resources out of large `DeviceMemory` blocks.
+///
+/// The allocator does not track resource lifetimes; every [`Allocation`] must be freed before
+/// [`Allocator::destroy`] is called.
+pub struct Allocator {
+    device: Device,
+    memory_properties: PhysicalDeviceMemoryProperties,
+    buffer_image_granularity: DeviceSize,
+    non_coherent_atom_size: DeviceSize,
+    block_size: Option<DeviceSize>,
+    blocks: Vec<Vec<MemoryBlock>>,
+    next_block_id: u64,
+}
+
+unsafe impl Send for Allocator {}
+
+impl Allocator {
+    pub fn new(device: Device, physical_device: &PhysicalDevice) -> Self {
+        let memory_properties = physical_device.get_memory_properties();
+        let properties = physical_device.get_properties();
+        let limits = properties.limits();
+
+        Self::from_properties(
+            device,
+            memory_properties,
+            limits.bufferImageGranularity,
+            limits.nonCoherentAtomSize,
+        )
+    }
+
+    pub fn from_properties(
+        device: Device,
+        memory_properties: PhysicalDeviceMemoryProperties,
+        buffer_image_granularity: DeviceSize,
+        non_coherent_atom_size: DeviceSize,
+    ) -> Self {
+        let blocks = (0..memory_properties.memory_type_count())
+            .map(|_| Vec::new())
+            .collect();
+
+        Self {
+            device,
+            memory_properties,
+            buffer_image_granularity,
+            non_coherent_atom_size,
+            block_size: None,
+            blocks,
+            next_block_id: 0,
+        }
+    }
+
+    /// Overrides the heap-size dependent default block size.
+    pub fn with_block_size(mut self, block_size: DeviceSize) -> Self {
+        self.block_size = Some(block_size);
+        self
+    }
+
+    pub const fn memory_properties(&self) -> &PhysicalDeviceMemoryProperties {
+        &self.memory_properties
+    }
+
+    /// Allocates from the best matching memory type, falling back to the next best type when
+    /// a new memory block cannot be allocated.
+    pub fn allocate(
+        &mut self,
+        requirements: &MemoryRequirements,
+        create_info: &AllocationCreateInfo,
+        kind: ResourceKind,
+    ) -> AllocationResult<Allocation> {
+        let mut memory_type_bits = requirements.memory_type_bits();
+        let mut error = None;
+
+        while let Some(memory_type_index) = find_memory_type_index(
+            self.memory_properties.memory_types(),
+            memory_type_bits,
+            create_info.required_flags(),
+            create_info.preferred_flags(),
+        ) {
+            match self.allocate_from(memory_type_index, requirements, create_info, kind) {
+                Ok(allocation) => return Ok(allocation),
+                Err(err) => error = Some(err),
+            }
+            memory_type_bits &= !(1 << memory_type_index);
+        }
+
+        Err(error.unwrap_or(AllocationError::NoSuitableMemoryType))
+    }
+
+    fn allocate_from(
+        &mut self,
+        memory_type_index: u32,
+        requirements: &MemoryRequirements,
+        create_info: &AllocationCreateInfo,
+        kind: ResourceKind,
+    ) -> AllocationResult<Allocation> {
+        let (size, alignment) = suballocation_layout(
+            self.memory_properties.memory_types()[memory_type_index as usize].property_flags(),
+            requirements.size(),
+            requirements.alignment(),
+            self.non_coherent_atom_size,
+        );
+        let block_size = self.block_size_for(memory_type_index);
+
+        let blocks = &mut self.blocks[memory_type_index as usize];
+        if !create_info.dedicated() && size <= block_size {
+            for block in blocks.iter_mut().filter(|block| !block.dedicated) {
+                if let Some(offset) = block.free_list.allocate(size, alignment, kind) {
+                    return Ok(Self::make_allocation(
+                        block,
+                        memory_type_index,
+                        offset,
+                        size,
+                    ));
+                }
+            }
+        }
+
+        let dedicated = create_info.dedicated() || size > block_size;
+        let mut block =
+            self.create_block(memory_type_index, if dedicated { size } else { block_size })?;
+        block.dedicated = dedicated;
+
+        let offset = block
+            .free_list
+            .allocate(size, alignment, kind)
+            .expect("fresh memory block must fit the allocation");
+        let allocation = Self::make_allocation(&block, memory_type_index, offset, size);
+
+        self.blocks[memory_type_index as usize].push(block);
+        Ok(allocation)
+    }
+
+    pub fn free(&mut self, allocation: Allocation) {
+        let blocks = &mut self.blocks[allocation.memory_type_index as usize];
+        let index = blocks
+            .iter()
+            .position(|block| block.id == allocation.block_id)
+            .expect("allocation does not belong to this allocator");
+
+        blocks[index]
+            .free_list
+            .free(allocation.offset)
+            .expect("allocation was already freed");
+
+        // keep one empty shared block around to avoid thrashing vkAllocateMemory
+        let block = &blocks[index];
+        let release = block.free_list.is_empty()
+            && (block.dedicated
+                || blocks
+                    .iter()
+                    .any(|other| other.id != block.id && !other.dedicated));
+
+        if release {
+            let block = blocks.swap_remove(index);
+            self.destroy_block(block);
+        }
+    }
+
+    pub fn create_buffer(
+        &mut self,
+        create_info: &BufferCreateInfo,
+        allocation_info: &AllocationCreateInfo,
+    ) -> AllocationResult<(Buffer, Allocation)> {
+        let buffer = self.device.create_buffer(create_info, None)?;
+        let requirements = self.device.get_buffer_memory_requirements(buffer);
+
+        let bound = self
+            .allocate(&requirements, allocation_info, ResourceKind::Linear)
+            .and_then(|allocation| {
+                self.device
+                    .bind_buffer_memory(buffer, allocation.memory, allocation.offset)
+                    .map(|_| allocation)
+                    .map_err(|error| {
+                        self.free(allocation);
+                        error.into()
+                    })
+            });
+
+        match bound {
+            Ok(allocation) => Ok((buffer, allocation)),
+            Err(error) => {
+                self.device.destroy_buffer(buffer, None);
+                Err(error)
+            }
+        }
+    }
+
+    pub fn destroy_buffer(&mut self, buffer: Buffer, allocation: Allocation) {
+        self.device.destroy_buffer(buffer, None);
+        self.free(allocation);
+    }
+
+    pub fn create_image(
+        &mut self,
+        create_info: &ImageCreateInfo,
+        allocation_info: &AllocationCreateInfo,
+    ) -> AllocationResult<(Image, Allocation)> {
+        let kind = if create_info.as_raw().tiling == ImageTiling::LINEAR.as_raw() {
+            ResourceKind::Linear
+        } else {
+            ResourceKind::Optimal
+        };
+
+        let image = self.device.create_image(create_info, None)?;
+        let requirements = self.device.get_image_memory_requirements(image);
+
+        let bound = self
+            .allocate(&requirements, allocation_info, kind)
+            .and_then(|allocation| {
+                self.device
+                    .bind_image_memory(image, allocation.memory, allocation.offset)
+                    .map(|_| allocation)
+                    .map_err(|error| {
+                        self.free(allocation);
+                        error.into()
+                    })
+            });
+
+        match bound {
+            Ok(allocation) => Ok((image, allocation)),
+            Err(error) => {
+                self.device.destroy_image(image, None);
+                Err(error)
+            }
+        }
+    }
+
+    pub fn destroy_image(&mut self, image: Image, allocation: Allocation) {
+        self.device.destroy_image(image, None);
+        self.free(allocation);
+    }
+
+    /// Usage statistics indexed by memory heap.
+    pub fn heap_stats(&self) -> Vec<HeapStats> {
+        let mut stats = self
+            .memory_properties
+            .memory_heaps()
+            .iter()
+            .map(|heap| HeapStats {
+                heap_size: heap.size(),
+                ..Default::default()
+            })
+            .collect::<Vec<_>>();
+
+        for (memory_type, blocks) in self
+            .memory_properties
+            .memory_types()
+            .iter()
+            .zip(&self.blocks)
+        {
+            let heap = &mut stats[memory_type.heap_index() as usize];
+            for block in blocks {
+                heap.block_count += 1;
+                heap.allocation_count += block.free_list.allocation_count();
+                heap.block_bytes += block.free_list.size();
+                heap.allocation_bytes += block.free_list.used();
+            }
+        }
+
+        stats
+    }
+
+    /// Frees every memory block owned by the allocator.
+    pub fn destroy(mut self) {
+        for block in std::mem::take(&mut self.blocks).into_iter().flatten() {
+            self.destroy_block(block);
+        }
+    }
+
+    fn block_size_for(&self, memory_type_index: u32) -> DeviceSize {
+        if let Some(block_size) = self.block_size {
+            return block_size;
+        }
+
+        let memory_type = &self.memory_properties.memory_types()[memory_type_index as usize];
+        let heap_size =
+            self.memory_properties.memory_heaps()[memory_type.heap_index() as usize].size();
+
+        if heap_size <= SMALL_HEAP_SIZE {
+            heap_size / 8
+        } else {
+            DEFAULT_BLOCK_SIZE
+        }
+    }
+
+    fn create_block(
+        &mut self,
+        memory_type_index: u32,
+        size: DeviceSize,
+    ) -> AllocationResult<MemoryBlock> {
+        let memory = self
+            .device
+            .allocate_memory(&MemoryAllocateInfo::new_init(size, memory_type_index), None)?;
+
+        let host_visible = self.memory_properties.memory_types()[memory_type_index as usize]
+            .property_flags()
+            .contains(MemoryPropertyFlags::HOST_VISIBLE);
+
+        let mapped = if host_visible {
+            match self.device.map_memory(memory, 0, WHOLE_SIZE) {
+                Ok(ptr) => ptr.cast(),
+                Err(error) => {
+                    self.device.free_memory(memory, None);
+                    return Err(error.into());
+                }
+            }
+        } else {
+            std::ptr::null_mut()
+        };
+
+        let id = self.next_block_id;
+        self.next_block_id += 1;
+
+        Ok(MemoryBlock {
+            id,
+            memory,
+            mapped,
+            free_list: FreeList::new(size, self.buffer_image_granularity),
+            dedicated: false,
+        })
+    }
+
+    fn destroy_block(&self, block: MemoryBlock) {
+        if !block.mapped.is_null() {
+            self.device.unmap_memory(block.memory);
+        }
+        self.device.free_memory(block.memory, None);
+    }
+
+    fn make_allocation(
+        block: &MemoryBlock,
+        memory_type_index: u32,
+        offset: DeviceSize,
+        size: DeviceSize,
+    ) -> Allocation {
+        let mapped = if block.mapped.is_null() {
+            std::ptr::null_mut()
+        } else {
+            unsafe { block.mapped.add(offset as usize) }
+        };
+
+        Allocation {
+            memory: block.memory,
+            offset,
+            size,
+            memory_type_index,
+            block_id: block.id,
+            mapped,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use vulkan_sys::*;
+
+    fn memory_properties(flags: &[MemoryPropertyFlags]) -> PhysicalDeviceMemoryProperties {
+        let mut raw: VkPhysicalDeviceMemoryProperties = unsafe { std::mem::zeroed() };
+        raw.memoryTypeCount = flags.len() as u32;
+        for (memory_type, flags) in raw.memoryTypes.iter_mut().zip(flags) {
+            memory_type.propertyFlags = flags.bits();
+        }
+        PhysicalDeviceMemoryProperties::from_raw(raw)
+    }
+
+    fn discrete_gpu() -> PhysicalDeviceMemoryProperties {
+        memory_properties(&[
+            MemoryPropertyFlags::DEVICE_LOCAL,
+            MemoryPropertyFlags::HOST_VISIBLE | MemoryPropertyFlags::HOST_COHERENT,
+            MemoryPropertyFlags::HOST_VISIBLE
+                | MemoryPropertyFlags::HOST_COHERENT
+                | MemoryPropertyFlags::HOST_CACHED,
+            MemoryPropertyFlags::DEVICE_LOCAL
+                | MemoryPropertyFlags::HOST_VISIBLE
+                | MemoryPropertyFlags::HOST_COHERENT,
+        ])
+    }
+
+    #[test]
+    fn rounds_non_coherent_allocations_to_the_atom_size() {
+        let flags = MemoryPropertyFlags::HOST_VISIBLE | MemoryPropertyFlags::HOST_CACHED;
+        assert_eq!(suballocation_layout(flags, 100, 4, 64), (128, 64));
+        assert_eq!(suballocation_layout(flags, 128, 256, 64), (128, 256));
+        assert_eq!(suballocation_layout(flags, 100, 4, 1), (100, 4));
+    }
+
+    #[test]
+    fn leaves_coherent_and_device_memory_unaligned() {
+        let coherent = MemoryPropertyFlags::HOST_VISIBLE | MemoryPropertyFlags::HOST_COHERENT;
+        assert_eq!(suballocation_layout(coherent, 100, 4, 64), (100, 4));
+        assert_eq!(
+            suballocation_layout(MemoryPropertyFlags::DEVICE_LOCAL, 100, 4, 64),
+            (100, 4)
+        );
+    }
+
+    #[test]
+    fn requires_every_required_flag() {
+        let properties = discrete_gpu();
+        assert_eq!(
+            find_memory_type_index(
+                properties.memory_types(),
+                !0,
+                MemoryPropertyFlags::DEVICE_LOCAL | MemoryPropertyFlags::HOST_VISIBLE,
+                MemoryPropertyFlags::empty(),
+            ),
+            Some(3)
+        );
+        assert_eq!(
+            find_memory_type_index(
+                properties.memory_types(),
+                !0,
+                MemoryPropertyFlags::LAZILY_ALLOCATED,
+                MemoryPropertyFlags::empty(),
+            ),
+            None
+        );
+    }
+
+    #[test]
+    fn prefers_the_most_preferred_flags() {
+        let properties = discrete_gpu();
+        assert_eq!(
+            find_memory_type_index(
+                properties.memory_types(),
+                !0,
+                MemoryPropertyFlags::HOST_VISIBLE,
+                MemoryPropertyFlags::HOST_CACHED,
+            ),
+            Some(2)
+        );
+        assert_eq!(
+            find_memory_type_index(
+                properties.memory_types(),
+                !0,
+                MemoryPropertyFlags::DEVICE_LOCAL,
+                MemoryPropertyFlags::HOST_VISIBLE,
+            ),
+            Some(3)
+        );
+    }
+
+    #[test]
+    fn breaks_ties_by_lowest_index() {
+        let properties = discrete_gpu();
+        assert_eq!(
+            find_memory_type_index(
+                properties.memory_types(),
+                !0,
+                MemoryPropertyFlags::DEVICE_LOCAL,
+                MemoryPropertyFlags::empty(),
+            ),
+            Some(0)
+        );
+        assert_eq!(
+            find_memory_type_index(
+                properties.memory_types(),
+                !0,
+                MemoryPropertyFlags::HOST_VISIBLE,
+                MemoryPropertyFlags::HOST_COHERENT,
+            ),
+            Some(1)
+        );
+    }
+
+    #[test]
+    fn only_considers_allowed_memory_types() {
+        let properties = discrete_gpu();
+        assert_eq!(
+            find_memory_type_index(
+                properties.memory_types(),
+                0b0010,
+                MemoryPropertyFlags::HOST_VISIBLE,
+                MemoryPropertyFlags::HOST_CACHED,
+            ),
+            Some(1)
+        );
+        assert_eq!(
+            find_memory_type_index(
+                properties.memory_types(),
+                0b0111,
+                MemoryPropertyFlags::DEVICE_LOCAL | MemoryPropertyFlags::HOST_VISIBLE,
+                MemoryPropertyFlags::empty(),
+            ),
+            None
+        );
+        assert_eq!(
+            find_memory_type_index(
+                properties.memory_types(),
+                0,
+                MemoryPropertyFlags::empty(),
+                MemoryPropertyFlags::empty(),
+            ),
+            None
+        );
+    }
+}
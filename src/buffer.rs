@@ -5,7 +5,7 @@ use crate::macros::*;
 use crate::*;
 use vulkan_sys::*;
 
-vulkan_handle!(Buffer, VkBuffer);
+vulkan_handle!(Buffer, VkBuffer, BUFFER);
 
 vulkan_create_info_lifetime!(
     BufferCreateInfo,
@@ -14,6 +14,11 @@ vulkan_create_info_lifetime!(
 );
 
 impl<'a> BufferCreateInfo<'a> {
+    pub const fn with_flags(mut self, flags: BufferCreateFlags) -> Self {
+        self.inner.flags = flags.bits();
+        self
+    }
+
     pub const fn with_size(mut self, size: DeviceSize) -> Self {
         self.inner.size = size;
         self
@@ -36,3 +41,38 @@ impl<'a> BufferCreateInfo<'a> {
         self
     }
 }
+
+/*
+   Buffer View
+*/
+
+vulkan_handle!(BufferView, VkBufferView, BUFFER_VIEW);
+
+vulkan_create_info_lifetime!(
+    BufferViewCreateInfo,
+    VkBufferViewCreateInfo,
+    VK_STRUCTURE_TYPE_BUFFER_VIEW_CREATE_INFO
+);
+
+impl<'a> BufferViewCreateInfo<'a> {
+    pub const fn with_buffer(mut self, buffer: Buffer) -> Self {
+        self.inner.buffer = buffer.as_raw();
+        self
+    }
+
+    pub const fn with_format(mut self, format: Format) -> Self {
+        self.inner.format = format.as_raw();
+        self
+    }
+
+    pub const fn with_offset(mut self, offset: DeviceSize) -> Self {
+        self.inner.offset = offset;
+        self
+    }
+
+    /// [`WHOLE_SIZE`] views the rest of the buffer after `offset`.
+    pub const fn with_range(mut self, range: DeviceSize) -> Self {
+        self.inner.range = range;
+        self
+    }
+}
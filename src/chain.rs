@@ -0,0 +1,32 @@
+// Copyright (c) 2024 Jacob R. Green
+// All rights reserved.
+
+#![allow(unused)]
+
+use vulkan_sys::*;
+
+/*
+   Extends
+*/
+
+/// Implemented for every raw struct that may appear in the `pNext` chain of `Parent`, as declared
+/// by `structextends` in the Vulkan registry.
+///
+/// # Safety
+/// `Self` must start with `sType` and `pNext` and be accepted by the specification in the chain
+/// of `Parent`.
+pub unsafe trait Extends<Parent> {}
+
+include!(concat!(env!("OUT_DIR"), "/extends.rs"));
+
+/*
+   Raw Struct
+*/
+
+/// Links a wrapper to the raw struct it wraps so [`Extends`] can be checked on wrappers.
+///
+/// # Safety
+/// `Self` must have the same layout as `Raw`.
+pub unsafe trait RawStruct {
+    type Raw;
+}
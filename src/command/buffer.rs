@@ -5,13 +5,13 @@ use crate::macros::*;
 use crate::*;
 use vulkan_sys::*;
 
-vulkan_create_info!(
+vulkan_create_info_lifetime!(
     CommandBufferAllocateInfo,
     VkCommandBufferAllocateInfo,
     VK_STRUCTURE_TYPE_COMMAND_BUFFER_ALLOCATE_INFO
 );
 
-impl crate::CommandBufferAllocateInfo {
+impl<'a> crate::CommandBufferAllocateInfo<'a> {
     pub const fn with_command_pool(mut self, command_pool: CommandPool) -> Self {
         self.inner.commandPool = command_pool.as_raw();
         self
@@ -75,22 +75,134 @@ impl<'a> crate::RenderPassBeginInfo<'a> {
     }
 }
 
-vulkan_handle!(CommandBuffer, VkCommandBuffer);
+vulkan_create_info_lifetime!(
+    RenderingAttachmentInfo,
+    VkRenderingAttachmentInfo,
+    VK_STRUCTURE_TYPE_RENDERING_ATTACHMENT_INFO
+);
+
+impl<'a> RenderingAttachmentInfo<'a> {
+    pub const fn with_image_view(mut self, image_view: ImageView) -> Self {
+        self.inner.imageView = image_view.as_raw();
+        self
+    }
+
+    pub const fn with_image_layout(mut self, image_layout: ImageLayout) -> Self {
+        self.inner.imageLayout = image_layout.as_raw();
+        self
+    }
+
+    /// Resolves multisampled contents into `resolve_image_view` at the end of rendering.
+    pub const fn with_resolve(
+        mut self,
+        resolve_mode: ResolveModeFlags,
+        resolve_image_view: ImageView,
+        resolve_image_layout: ImageLayout,
+    ) -> Self {
+        self.inner.resolveMode = resolve_mode.bits() as _;
+        self.inner.resolveImageView = resolve_image_view.as_raw();
+        self.inner.resolveImageLayout = resolve_image_layout.as_raw();
+        self
+    }
+
+    pub const fn with_load_op(mut self, load_op: AttachmentLoadOp) -> Self {
+        self.inner.loadOp = load_op.as_raw();
+        self
+    }
+
+    pub const fn with_store_op(mut self, store_op: AttachmentStoreOp) -> Self {
+        self.inner.storeOp = store_op.as_raw();
+        self
+    }
+
+    pub const fn with_clear_value(mut self, clear_value: ClearValue) -> Self {
+        self.inner.clearValue = clear_value;
+        self
+    }
+}
+
+vulkan_create_info_lifetime!(
+    RenderingInfo,
+    VkRenderingInfo,
+    VK_STRUCTURE_TYPE_RENDERING_INFO
+);
+
+impl<'a> RenderingInfo<'a> {
+    pub const fn with_flags(mut self, flags: RenderingFlags) -> Self {
+        self.inner.flags = flags.bits();
+        self
+    }
+
+    pub const fn with_render_area(mut self, render_area: Rect2D) -> Self {
+        self.inner.renderArea = render_area;
+        self
+    }
+
+    pub const fn with_layer_count(mut self, layer_count: u32) -> Self {
+        self.inner.layerCount = layer_count;
+        self
+    }
+
+    pub const fn with_view_mask(mut self, view_mask: u32) -> Self {
+        self.inner.viewMask = view_mask;
+        self
+    }
+
+    pub const fn with_color_attachments(
+        mut self,
+        color_attachments: &'a [RenderingAttachmentInfo<'a>],
+    ) -> Self {
+        self.inner.colorAttachmentCount = color_attachments.len() as u32;
+        self.inner.pColorAttachments = color_attachments.as_ptr().cast();
+        self
+    }
+
+    pub const fn with_depth_attachment(
+        mut self,
+        depth_attachment: &'a RenderingAttachmentInfo<'a>,
+    ) -> Self {
+        self.inner.pDepthAttachment = depth_attachment.as_raw();
+        self
+    }
+
+    pub const fn with_stencil_attachment(
+        mut self,
+        stencil_attachment: &'a RenderingAttachmentInfo<'a>,
+    ) -> Self {
+        self.inner.pStencilAttachment = stencil_attachment.as_raw();
+        self
+    }
+}
+
+vulkan_dispatchable_handle!(
+    CommandBuffer,
+    VkCommandBuffer,
+    COMMAND_BUFFER,
+    dispatch::DeviceDispatch
+);
 
 impl CommandBuffer {
     pub fn reset(&self, flags: CommandBufferResetFlags) -> vulkan_sys::wrapper::Result<()> {
-        reset_command_buffer(vkResetCommandBuffer, self.as_raw(), flags.bits())
+        reset_command_buffer(
+            self.dispatch().vkResetCommandBuffer,
+            self.as_raw(),
+            flags.bits(),
+        )
     }
 
     pub fn begin(
         &self,
         begin_info: &crate::CommandBufferBeginInfo,
     ) -> vulkan_sys::wrapper::Result<()> {
-        begin_command_buffer(vkBeginCommandBuffer, self.as_raw(), begin_info.as_raw())
+        begin_command_buffer(
+            self.dispatch().vkBeginCommandBuffer,
+            self.as_raw(),
+            begin_info.as_raw(),
+        )
     }
 
     pub fn end(&self) -> vulkan_sys::wrapper::Result<()> {
-        end_command_buffer(vkEndCommandBuffer, self.as_raw())
+        end_command_buffer(self.dispatch().vkEndCommandBuffer, self.as_raw())
     }
 
     pub fn cmd_begin_render_pass(
@@ -99,7 +211,7 @@ impl CommandBuffer {
         contents: SubpassContents,
     ) {
         cmd_begin_render_pass(
-            vkCmdBeginRenderPass,
+            self.dispatch().vkCmdBeginRenderPass,
             self.as_raw(),
             render_pass_begin_info.as_raw(),
             contents.as_raw(),
@@ -107,16 +219,84 @@ impl CommandBuffer {
     }
 
     pub fn cmd_next_subpass(&self, contents: SubpassContents) {
-        cmd_next_subpass(vkCmdNextSubpass, self.as_raw(), contents.as_raw())
+        cmd_next_subpass(
+            self.dispatch().vkCmdNextSubpass,
+            self.as_raw(),
+            contents.as_raw(),
+        )
     }
 
     pub fn cmd_end_render_pass(&self) {
-        cmd_end_render_pass(vkCmdEndRenderPass, self.as_raw())
+        cmd_end_render_pass(self.dispatch().vkCmdEndRenderPass, self.as_raw())
+    }
+
+    /// Begins a render pass instance without render pass or framebuffer objects.
+    ///
+    /// Requires Vulkan 1.3 or `VK_KHR_dynamic_rendering`.
+    pub fn cmd_begin_rendering(&self, rendering_info: &RenderingInfo) {
+        let dispatch = self.dispatch();
+        cmd_begin_rendering(
+            dispatch
+                .vkCmdBeginRendering
+                .or(dispatch.vkCmdBeginRenderingKHR)
+                .expect("vkCmdBeginRendering is not available"),
+            self.as_raw(),
+            rendering_info.as_raw(),
+        )
+    }
+
+    pub fn cmd_end_rendering(&self) {
+        let dispatch = self.dispatch();
+        cmd_end_rendering(
+            dispatch
+                .vkCmdEndRendering
+                .or(dispatch.vkCmdEndRenderingKHR)
+                .expect("vkCmdEndRendering is not available"),
+            self.as_raw(),
+        )
+    }
+
+    /// Opens a debug label region. Requires `VK_EXT_debug_utils`.
+    pub fn cmd_begin_debug_label(&self, label: &DebugUtilsLabelEXT) {
+        cmd_begin_debug_utils_label_ext(
+            self.dispatch()
+                .vkCmdBeginDebugUtilsLabelEXT
+                .expect("vkCmdBeginDebugUtilsLabelEXT is not available"),
+            self.as_raw(),
+            label.as_raw(),
+        )
+    }
+
+    pub fn cmd_end_debug_label(&self) {
+        cmd_end_debug_utils_label_ext(
+            self.dispatch()
+                .vkCmdEndDebugUtilsLabelEXT
+                .expect("vkCmdEndDebugUtilsLabelEXT is not available"),
+            self.as_raw(),
+        )
+    }
+
+    pub fn cmd_insert_debug_label(&self, label: &DebugUtilsLabelEXT) {
+        cmd_insert_debug_utils_label_ext(
+            self.dispatch()
+                .vkCmdInsertDebugUtilsLabelEXT
+                .expect("vkCmdInsertDebugUtilsLabelEXT is not available"),
+            self.as_raw(),
+            label.as_raw(),
+        )
+    }
+
+    /// Opens a debug label region that is closed when the returned guard is dropped.
+    pub fn cmd_debug_label_scope(&self, label: &DebugUtilsLabelEXT) -> DebugLabelScope {
+        self.cmd_begin_debug_label(label);
+        DebugLabelScope {
+            command_buffer: self.clone(),
+        }
     }
 
     pub fn cmd_begin_query(&self, query_pool: QueryPool, query: u32, flags: QueryControlFlags) {
         cmd_begin_query(
-            vkCmdBeginQuery,
+            self.dispatch().vkCmdBeginQuery,
             self.as_raw(),
             query_pool.as_raw(),
             query,
@@ -134,7 +314,7 @@ impl CommandBuffer {
     ) {
         unsafe {
             cmd_bind_descriptor_sets(
-                vkCmdBindDescriptorSets,
+                self.dispatch().vkCmdBindDescriptorSets,
                 self.as_raw(),
                 pipeline_bind_point.as_raw(),
                 layout.as_raw(),
@@ -153,7 +333,7 @@ impl CommandBuffer {
     ) {
         unsafe {
             cmd_bind_vertex_buffers(
-                vkCmdBindVertexBuffers,
+                self.dispatch().vkCmdBindVertexBuffers,
                 self.as_raw(),
                 first_binding,
                 transmute(buffers),
@@ -164,7 +344,7 @@ impl CommandBuffer {
 
     pub fn cmd_bind_index_buffer(&self, buffer: Buffer, offset: DeviceSize, index_type: IndexType) {
         cmd_bind_index_buffer(
-            vkCmdBindIndexBuffer,
+            self.dispatch().vkCmdBindIndexBuffer,
             self.as_raw(),
             buffer.as_raw(),
             offset,
@@ -174,7 +354,7 @@ impl CommandBuffer {
 
     pub fn cmd_bind_pipeline(&self, pipeline_bind_point: PipelineBindPoint, pipeline: Pipeline) {
         cmd_bind_pipeline(
-            vkCmdBindPipeline,
+            self.dispatch().vkCmdBindPipeline,
             self.as_raw(),
             pipeline_bind_point.as_raw(),
             pipeline.as_raw(),
@@ -192,7 +372,7 @@ impl CommandBuffer {
     ) {
         unsafe {
             cmd_blit_image(
-                vkCmdBlitImage,
+                self.dispatch().vkCmdBlitImage,
                 self.as_raw(),
                 src_image.as_raw(),
                 src_image_layout.as_raw(),
@@ -207,7 +387,7 @@ impl CommandBuffer {
     pub fn cmd_clear_attachments(&self, attachments: &[ClearAttachment], rects: &[ClearRect]) {
         unsafe {
             cmd_clear_attachments(
-                vkCmdClearAttachments,
+                self.dispatch().vkCmdClearAttachments,
                 self.as_raw(),
                 transmute(attachments),
                 transmute(rects),
@@ -224,7 +404,7 @@ impl CommandBuffer {
     ) {
         unsafe {
             cmd_clear_color_image(
-                vkCmdClearColorImage,
+                self.dispatch().vkCmdClearColorImage,
                 self.as_raw(),
                 image.as_raw(),
                 image_layout.as_raw(),
@@ -243,7 +423,7 @@ impl CommandBuffer {
     ) {
         unsafe {
             cmd_clear_depth_stencil_image(
-                vkCmdClearDepthStencilImage,
+                self.dispatch().vkCmdClearDepthStencilImage,
                 self.as_raw(),
                 image.as_raw(),
                 image_layout.as_raw(),
@@ -256,7 +436,7 @@ impl CommandBuffer {
     pub fn cmd_copy_buffer(&self, src_buffer: Buffer, dst_buffer: Buffer, regions: &[BufferCopy]) {
         unsafe {
             cmd_copy_buffer(
-                vkCmdCopyBuffer,
+                self.dispatch().vkCmdCopyBuffer,
                 self.as_raw(),
                 src_buffer.as_raw(),
                 dst_buffer.as_raw(),
@@ -274,7 +454,7 @@ impl CommandBuffer {
     ) {
         unsafe {
             cmd_copy_buffer_to_image(
-                vkCmdCopyBufferToImage,
+                self.dispatch().vkCmdCopyBufferToImage,
                 self.as_raw(),
                 src_buffer.as_raw(),
                 dst_image.as_raw(),
@@ -294,7 +474,7 @@ impl CommandBuffer {
     ) {
         unsafe {
             cmd_copy_image(
-                vkCmdCopyImage,
+                self.dispatch().vkCmdCopyImage,
                 self.as_raw(),
                 src_image.as_raw(),
                 src_image_layout.as_raw(),
@@ -314,7 +494,7 @@ impl CommandBuffer {
     ) {
         unsafe {
             cmd_copy_image_to_buffer(
-                vkCmdCopyImageToBuffer,
+                self.dispatch().vkCmdCopyImageToBuffer,
                 self.as_raw(),
                 src_image.as_raw(),
                 src_image_layout.as_raw(),
@@ -326,7 +506,7 @@ impl CommandBuffer {
 
     pub fn cmd_dispatch(&self, group_count_x: u32, group_count_y: u32, group_count_z: u32) {
         cmd_dispatch(
-            vkCmdDispatch,
+            self.dispatch().vkCmdDispatch,
             self.as_raw(),
             group_count_x,
             group_count_y,
@@ -336,12 +516,12 @@ impl CommandBuffer {
 
     pub fn cmd_dispatch_indirect(
         &self,
-        command_buffer: CommandBuffer,
+        command_buffer: &CommandBuffer,
         buffer: Buffer,
         offset: DeviceSize,
     ) {
         cmd_dispatch_indirect(
-            vkCmdDispatchIndirect,
+            self.dispatch().vkCmdDispatchIndirect,
             command_buffer.as_raw(),
             buffer.as_raw(),
             offset,
@@ -356,7 +536,7 @@ impl CommandBuffer {
         first_instance: u32,
     ) {
         cmd_draw(
-            vkCmdDraw,
+            self.dispatch().vkCmdDraw,
             self.as_raw(),
             vertex_count,
             instance_count,
@@ -374,7 +554,7 @@ impl CommandBuffer {
         first_instance: u32,
     ) {
         cmd_draw_indexed(
-            vkCmdDrawIndexed,
+            self.dispatch().vkCmdDrawIndexed,
             self.as_raw(),
             index_count,
             instance_count,
@@ -386,14 +566,14 @@ impl CommandBuffer {
 
     pub fn cmd_draw_indexed_indirect(
         &self,
-        command_buffer: CommandBuffer,
+        command_buffer: &CommandBuffer,
         buffer: Buffer,
         offset: DeviceSize,
         draw_count: u32,
         stride: u32,
     ) {
         cmd_draw_indexed_indirect(
-            vkCmdDrawIndexedIndirect,
+            self.dispatch().vkCmdDrawIndexedIndirect,
             command_buffer.as_raw(),
             buffer.as_raw(),
             offset,
@@ -404,14 +584,14 @@ impl CommandBuffer {
 
     pub fn cmd_draw_indirect(
         &self,
-        command_buffer: CommandBuffer,
+        command_buffer: &CommandBuffer,
         buffer: Buffer,
         offset: DeviceSize,
         draw_count: u32,
         stride: u32,
     ) {
         cmd_draw_indirect(
-            vkCmdDrawIndirect,
+            self.dispatch().vkCmdDrawIndirect,
             command_buffer.as_raw(),
             buffer.as_raw(),
             offset,
@@ -420,21 +600,35 @@ impl CommandBuffer {
         )
     }
 
-    // end_query
+    pub fn cmd_end_query(&self, query_pool: QueryPool, query: u32) {
+        cmd_end_query(
+            self.dispatch().vkCmdEndQuery,
+            self.as_raw(),
+            query_pool.as_raw(),
+            query,
+        )
+    }
 
+    /// Records `command_buffers` in order, in batches so no allocation is needed.
     pub fn cmd_execute_commands(&self, command_buffers: &[CommandBuffer]) {
-        unsafe {
+        const BATCH_SIZE: usize = 16;
+
+        for batch in command_buffers.chunks(BATCH_SIZE) {
+            let mut raw = [std::ptr::null_mut(); BATCH_SIZE];
+            for (raw, command_buffer) in raw.iter_mut().zip(batch) {
+                *raw = command_buffer.as_raw();
+            }
             cmd_execute_commands(
-                vkCmdExecuteCommands,
+                self.dispatch().vkCmdExecuteCommands,
                 self.as_raw(),
-                transmute(command_buffers),
+                &raw[..batch.len()],
             )
         }
     }
 
     pub fn cmd_fill_buffer(&self, buffer: Buffer, offset: DeviceSize, size: DeviceSize, data: u32) {
         cmd_fill_buffer(
-            vkCmdFillBuffer,
+            self.dispatch().vkCmdFillBuffer,
             self.as_raw(),
             buffer.as_raw(),
             offset,
@@ -454,7 +648,7 @@ impl CommandBuffer {
     ) {
         unsafe {
             cmd_pipeline_barrier(
-                vkCmdPipelineBarrier,
+                self.dispatch().vkCmdPipelineBarrier,
                 self.as_raw(),
                 src_stage_mask.bits(),
                 dst_stage_mask.bits(),
@@ -466,6 +660,19 @@ impl CommandBuffer {
         }
     }
 
+    /// Requires Vulkan 1.3 or `VK_KHR_synchronization2`.
+    pub fn cmd_pipeline_barrier2(&self, dependency_info: &DependencyInfo) {
+        let dispatch = self.dispatch();
+        cmd_pipeline_barrier2(
+            dispatch
+                .vkCmdPipelineBarrier2
+                .or(dispatch.vkCmdPipelineBarrier2KHR)
+                .expect("vkCmdPipelineBarrier2 is not available"),
+            self.as_raw(),
+            dependency_info.as_raw(),
+        )
+    }
+
     pub fn cmd_push_constants(
         &self,
         layout: PipelineLayout,
@@ -476,7 +683,7 @@ impl CommandBuffer {
     ) {
         unsafe {
             cmd_push_constants(
-                vkCmdPushConstants,
+                self.dispatch().vkCmdPushConstants,
                 self.as_raw(),
                 layout.as_raw(),
                 stage_flags.bits(),
@@ -487,9 +694,58 @@ impl CommandBuffer {
         }
     }
 
+    /// Writes descriptors straight into the command buffer for a set created with the push
+    /// descriptor layout flag. Requires `VK_KHR_push_descriptor`.
+    pub fn cmd_push_descriptor_set_khr(
+        &self,
+        pipeline_bind_point: PipelineBindPoint,
+        layout: PipelineLayout,
+        set: u32,
+        descriptor_writes: &[WriteDescriptorSet],
+    ) {
+        unsafe {
+            cmd_push_descriptor_set_khr(
+                self.dispatch()
+                    .vkCmdPushDescriptorSetKHR
+                    .expect("vkCmdPushDescriptorSetKHR is not available"),
+                self.as_raw(),
+                pipeline_bind_point.as_raw(),
+                layout.as_raw(),
+                set,
+                transmute(descriptor_writes),
+            )
+        }
+    }
+
+    /// Like [`CommandBuffer::cmd_push_descriptor_set_khr`], reading the descriptors from `data`.
+    ///
+    /// # Safety
+    /// `descriptor_update_template` must have been created with
+    /// [`DescriptorUpdateTemplateCreateInfo::with_update_data`] for `T` and
+    /// [`DescriptorUpdateTemplateCreateInfo::with_push_descriptor_set`], nothing else ties the
+    /// template's entries to the layout of `data`.
+    pub unsafe fn cmd_push_descriptor_set_with_template_khr<T: DescriptorUpdateData>(
+        &self,
+        descriptor_update_template: DescriptorUpdateTemplate,
+        layout: PipelineLayout,
+        set: u32,
+        data: &T,
+    ) {
+        cmd_push_descriptor_set_with_template_khr(
+            self.dispatch()
+                .vkCmdPushDescriptorSetWithTemplateKHR
+                .expect("vkCmdPushDescriptorSetWithTemplateKHR is not available"),
+            self.as_raw(),
+            descriptor_update_template.as_raw(),
+            layout.as_raw(),
+            set,
+            (data as *const T).cast(),
+        )
+    }
+
     pub fn cmd_reset_event(&self, event: Event, stage_mask: PipelineStageFlags) {
         cmd_reset_event(
-            vkCmdResetEvent,
+            self.dispatch().vkCmdResetEvent,
             self.as_raw(),
             event.as_raw(),
             stage_mask.bits(),
@@ -498,7 +754,7 @@ impl CommandBuffer {
 
     pub fn cmd_reset_query_pool(&self, query_pool: QueryPool, first_query: u32, query_count: u32) {
         cmd_reset_query_pool(
-            vkCmdResetQueryPool,
+            self.dispatch().vkCmdResetQueryPool,
             self.as_raw(),
             query_pool.as_raw(),
             first_query,
@@ -516,7 +772,7 @@ impl CommandBuffer {
     ) {
         unsafe {
             cmd_resolve_image(
-                vkCmdResolveImage,
+                self.dispatch().vkCmdResolveImage,
                 self.as_raw(),
                 src_image.as_raw(),
                 src_image_layout.as_raw(),
@@ -528,12 +784,16 @@ impl CommandBuffer {
     }
 
     pub fn cmd_set_blend_constants(&self, blend_constants: [f32; 4]) {
-        cmd_set_blend_constants(vkCmdSetBlendConstants, self.as_raw(), blend_constants)
+        cmd_set_blend_constants(
+            self.dispatch().vkCmdSetBlendConstants,
+            self.as_raw(),
+            blend_constants,
+        )
     }
 
     pub fn cmd_set_depth_bias(&self, constant_factor: f32, clamp: f32, slope_factor: f32) {
         cmd_set_depth_bias(
-            vkCmdSetDepthBias,
+            self.dispatch().vkCmdSetDepthBias,
             self.as_raw(),
             constant_factor,
             clamp,
@@ -542,26 +802,40 @@ impl CommandBuffer {
     }
 
     pub fn cmd_set_depth_bounds(&self, min: f32, max: f32) {
-        cmd_set_depth_bounds(vkCmdSetDepthBounds, self.as_raw(), min, max)
+        cmd_set_depth_bounds(self.dispatch().vkCmdSetDepthBounds, self.as_raw(), min, max)
     }
 
     pub fn cmd_set_event(&self, event: Event, stage_mask: PipelineStageFlags) {
         cmd_set_event(
-            vkCmdSetEvent,
+            self.dispatch().vkCmdSetEvent,
             self.as_raw(),
             event.as_raw(),
             stage_mask.bits(),
         )
     }
 
+    /// Requires Vulkan 1.3 or `VK_KHR_synchronization2`.
+    pub fn cmd_set_event2(&self, event: Event, dependency_info: &DependencyInfo) {
+        let dispatch = self.dispatch();
+        cmd_set_event2(
+            dispatch
+                .vkCmdSetEvent2
+                .or(dispatch.vkCmdSetEvent2KHR)
+                .expect("vkCmdSetEvent2 is not available"),
+            self.as_raw(),
+            event.as_raw(),
+            dependency_info.as_raw(),
+        )
+    }
+
     pub fn cmd_set_line_width(&self, line_width: f32) {
-        cmd_set_line_width(vkCmdSetLineWidth, self.as_raw(), line_width)
+        cmd_set_line_width(self.dispatch().vkCmdSetLineWidth, self.as_raw(), line_width)
     }
 
     pub fn cmd_set_scissor(&self, first_scissor: u32, scissors: &[Rect2D]) {
         unsafe {
             cmd_set_scissor(
-                vkCmdSetScissor,
+                self.dispatch().vkCmdSetScissor,
                 self.as_raw(),
                 first_scissor,
                 transmute(scissors),
@@ -571,7 +845,7 @@ impl CommandBuffer {
 
     pub fn cmd_set_stencil_compare_mask(&self, face_mask: StencilFaceFlags, compare_mask: u32) {
         cmd_set_stencil_compare_mask(
-            vkCmdSetStencilCompareMask,
+            self.dispatch().vkCmdSetStencilCompareMask,
             self.as_raw(),
             face_mask.bits(),
             compare_mask,
@@ -580,7 +854,7 @@ impl CommandBuffer {
 
     pub fn cmd_set_stencil_reference(&self, face_mask: StencilFaceFlags, reference: u32) {
         cmd_set_stencil_reference(
-            vkCmdSetStencilReference,
+            self.dispatch().vkCmdSetStencilReference,
             self.as_raw(),
             face_mask.bits(),
             reference,
@@ -589,7 +863,7 @@ impl CommandBuffer {
 
     pub fn cmd_set_stencil_write_mask(&self, face_mask: StencilFaceFlags, write_mask: u32) {
         cmd_set_stencil_write_mask(
-            vkCmdSetStencilWriteMask,
+            self.dispatch().vkCmdSetStencilWriteMask,
             self.as_raw(),
             face_mask.bits(),
             write_mask,
@@ -599,7 +873,7 @@ impl CommandBuffer {
     pub fn cmd_set_viewport(&self, first_viewport: u32, viewports: &[Viewport]) {
         unsafe {
             cmd_set_viewport(
-                vkCmdSetViewport,
+                self.dispatch().vkCmdSetViewport,
                 self.as_raw(),
                 first_viewport,
                 transmute(viewports),
@@ -609,7 +883,7 @@ impl CommandBuffer {
 
     pub fn cmd_update_buffer(&self, buffer: Buffer, offset: DeviceSize, data: &[u8]) {
         cmd_update_buffer(
-            vkCmdUpdateBuffer,
+            self.dispatch().vkCmdUpdateBuffer,
             self.as_raw(),
             buffer.as_raw(),
             offset,
@@ -629,7 +903,7 @@ impl CommandBuffer {
     ) {
         unsafe {
             cmd_wait_events(
-                vkCmdWaitEvents,
+                self.dispatch().vkCmdWaitEvents,
                 self.as_raw(),
                 transmute(events),
                 src_stage_mask.bits(),
@@ -641,6 +915,31 @@ impl CommandBuffer {
         }
     }
 
+    /// Waits on each of `events` with the dependency it was set with, so `dependency_infos`
+    /// must be the same length as `events`.
+    ///
+    /// Requires Vulkan 1.3 or `VK_KHR_synchronization2`.
+    pub fn cmd_wait_events2(&self, events: &[Event], dependency_infos: &[DependencyInfo]) {
+        assert_eq!(
+            events.len(),
+            dependency_infos.len(),
+            "every event needs a matching dependency info"
+        );
+
+        let dispatch = self.dispatch();
+        unsafe {
+            cmd_wait_events2(
+                dispatch
+                    .vkCmdWaitEvents2
+                    .or(dispatch.vkCmdWaitEvents2KHR)
+                    .expect("vkCmdWaitEvents2 is not available"),
+                self.as_raw(),
+                transmute(events),
+                transmute(dependency_infos),
+            )
+        }
+    }
+
     pub fn cmd_write_timestamp(
         &self,
         pipeline_stage: PipelineStageFlags,
@@ -648,13 +947,33 @@ impl CommandBuffer {
         query: u32,
     ) {
         cmd_write_timestamp(
-            vkCmdWriteTimestamp,
+            self.dispatch().vkCmdWriteTimestamp,
             self.as_raw(),
             pipeline_stage.bits() as _,
             query_pool.as_raw(),
             query,
         )
     }
+
+    /// Requires Vulkan 1.3 or `VK_KHR_synchronization2`.
+    pub fn cmd_write_timestamp2(
+        &self,
+        stage: PipelineStageFlags2,
+        query_pool: QueryPool,
+        query: u32,
+    ) {
+        let dispatch = self.dispatch();
+        cmd_write_timestamp2(
+            dispatch
+                .vkCmdWriteTimestamp2
+                .or(dispatch.vkCmdWriteTimestamp2KHR)
+                .expect("vkCmdWriteTimestamp2 is not available"),
+            self.as_raw(),
+            stage.bits(),
+            query_pool.as_raw(),
+            query,
+        )
+    }
 }
 
 vulkan_create_info_lifetime!(
@@ -769,6 +1088,185 @@ impl<'a> ImageMemoryBarrier<'a> {
     }
 }
 
+vulkan_create_info_lifetime!(
+    MemoryBarrier2,
+    VkMemoryBarrier2,
+    VK_STRUCTURE_TYPE_MEMORY_BARRIER_2
+);
+
+impl<'a> MemoryBarrier2<'a> {
+    pub const fn with_src_stage_mask(mut self, src_stage_mask: PipelineStageFlags2) -> Self {
+        self.inner.srcStageMask = src_stage_mask.bits();
+        self
+    }
+
+    pub const fn with_src_access_mask(mut self, src_access_mask: AccessFlags2) -> Self {
+        self.inner.srcAccessMask = src_access_mask.bits();
+        self
+    }
+
+    pub const fn with_dst_stage_mask(mut self, dst_stage_mask: PipelineStageFlags2) -> Self {
+        self.inner.dstStageMask = dst_stage_mask.bits();
+        self
+    }
+
+    pub const fn with_dst_access_mask(mut self, dst_access_mask: AccessFlags2) -> Self {
+        self.inner.dstAccessMask = dst_access_mask.bits();
+        self
+    }
+}
+
+vulkan_create_info_lifetime!(
+    BufferMemoryBarrier2,
+    VkBufferMemoryBarrier2,
+    VK_STRUCTURE_TYPE_BUFFER_MEMORY_BARRIER_2
+);
+
+impl<'a> BufferMemoryBarrier2<'a> {
+    pub const fn with_src_stage_mask(mut self, src_stage_mask: PipelineStageFlags2) -> Self {
+        self.inner.srcStageMask = src_stage_mask.bits();
+        self
+    }
+
+    pub const fn with_src_access_mask(mut self, src_access_mask: AccessFlags2) -> Self {
+        self.inner.srcAccessMask = src_access_mask.bits();
+        self
+    }
+
+    pub const fn with_dst_stage_mask(mut self, dst_stage_mask: PipelineStageFlags2) -> Self {
+        self.inner.dstStageMask = dst_stage_mask.bits();
+        self
+    }
+
+    pub const fn with_dst_access_mask(mut self, dst_access_mask: AccessFlags2) -> Self {
+        self.inner.dstAccessMask = dst_access_mask.bits();
+        self
+    }
+
+    pub const fn with_src_queue_family_index(mut self, src_queue_family_index: u32) -> Self {
+        self.inner.srcQueueFamilyIndex = src_queue_family_index;
+        self
+    }
+
+    pub const fn with_dst_queue_family_index(mut self, dst_queue_family_index: u32) -> Self {
+        self.inner.dstQueueFamilyIndex = dst_queue_family_index;
+        self
+    }
+
+    pub const fn with_buffer(mut self, buffer: Buffer) -> Self {
+        self.inner.buffer = buffer.as_raw();
+        self
+    }
+
+    pub const fn with_offset(mut self, offset: DeviceSize) -> Self {
+        self.inner.offset = offset;
+        self
+    }
+
+    pub const fn with_size(mut self, size: DeviceSize) -> Self {
+        self.inner.size = size;
+        self
+    }
+}
+
+vulkan_create_info_lifetime!(
+    ImageMemoryBarrier2,
+    VkImageMemoryBarrier2,
+    VK_STRUCTURE_TYPE_IMAGE_MEMORY_BARRIER_2
+);
+
+impl<'a> ImageMemoryBarrier2<'a> {
+    pub const fn with_src_stage_mask(mut self, src_stage_mask: PipelineStageFlags2) -> Self {
+        self.inner.srcStageMask = src_stage_mask.bits();
+        self
+    }
+
+    pub const fn with_src_access_mask(mut self, src_access_mask: AccessFlags2) -> Self {
+        self.inner.srcAccessMask = src_access_mask.bits();
+        self
+    }
+
+    pub const fn with_dst_stage_mask(mut self, dst_stage_mask: PipelineStageFlags2) -> Self {
+        self.inner.dstStageMask = dst_stage_mask.bits();
+        self
+    }
+
+    pub const fn with_dst_access_mask(mut self, dst_access_mask: AccessFlags2) -> Self {
+        self.inner.dstAccessMask = dst_access_mask.bits();
+        self
+    }
+
+    pub const fn with_old_layout(mut self, old_layout: ImageLayout) -> Self {
+        self.inner.oldLayout = old_layout.as_raw();
+        self
+    }
+
+    pub const fn with_new_layout(mut self, new_layout: ImageLayout) -> Self {
+        self.inner.newLayout = new_layout.as_raw();
+        self
+    }
+
+    pub const fn with_src_queue_family_index(mut self, src_queue_family_index: u32) -> Self {
+        self.inner.srcQueueFamilyIndex = src_queue_family_index;
+        self
+    }
+
+    pub const fn with_dst_queue_family_index(mut self, dst_queue_family_index: u32) -> Self {
+        self.inner.dstQueueFamilyIndex = dst_queue_family_index;
+        self
+    }
+
+    pub const fn with_image(mut self, image: Image) -> Self {
+        self.inner.image = image.as_raw();
+        self
+    }
+
+    pub const fn with_subresource_range(
+        mut self,
+        subresource_range: ImageSubresourceRange,
+    ) -> Self {
+        self.inner.subresourceRange = unsafe { transmute(subresource_range) };
+        self
+    }
+}
+
+vulkan_create_info_lifetime!(
+    DependencyInfo,
+    VkDependencyInfo,
+    VK_STRUCTURE_TYPE_DEPENDENCY_INFO
+);
+
+impl<'a> DependencyInfo<'a> {
+    pub const fn with_dependency_flags(mut self, dependency_flags: DependencyFlags) -> Self {
+        self.inner.dependencyFlags = dependency_flags.bits();
+        self
+    }
+
+    pub const fn with_memory_barriers(mut self, memory_barriers: &'a [MemoryBarrier2<'a>]) -> Self {
+        self.inner.memoryBarrierCount = memory_barriers.len() as u32;
+        self.inner.pMemoryBarriers = memory_barriers.as_ptr().cast();
+        self
+    }
+
+    pub const fn with_buffer_memory_barriers(
+        mut self,
+        buffer_memory_barriers: &'a [BufferMemoryBarrier2<'a>],
+    ) -> Self {
+        self.inner.bufferMemoryBarrierCount = buffer_memory_barriers.len() as u32;
+        self.inner.pBufferMemoryBarriers = buffer_memory_barriers.as_ptr().cast();
+        self
+    }
+
+    pub const fn with_image_memory_barriers(
+        mut self,
+        image_memory_barriers: &'a [ImageMemoryBarrier2<'a>],
+    ) -> Self {
+        self.inner.imageMemoryBarrierCount = image_memory_barriers.len() as u32;
+        self.inner.pImageMemoryBarriers = image_memory_barriers.as_ptr().cast();
+        self
+    }
+}
+
 vulkan_struct!(ImageBlit, VkImageBlit);
 vulkan_struct!(ClearAttachment, VkClearAttachment);
 vulkan_struct!(ClearRect, VkClearRect);
@@ -812,8 +1310,6 @@ impl BufferImageCopy {
 
 vulkan_struct!(ImageCopy, VkImageCopy);
 vulkan_struct!(ImageResolve, VkImageResolve);
-vulkan_handle!(Event, VkEvent);
-vulkan_handle!(QueryPool, VkQueryPool);
 
 vulkan_struct!(ImageSubresourceLayers, VkImageSubresourceLayers);
 impl ImageSubresourceLayers {
@@ -853,3 +1349,70 @@ impl ImageSubresourceLayers {
         self
     }
 }
+
+/*
+   Command Buffer Array
+*/
+
+/// Command buffers borrowed as an owned array of raw handles, the layout `pCommandBuffers`
+/// expects.
+#[derive(Debug, Clone, Default)]
+pub struct CommandBufferArray<'a> {
+    handles: Vec<VkCommandBuffer>,
+    phantom: std::marker::PhantomData<&'a CommandBuffer>,
+}
+
+impl<'a> CommandBufferArray<'a> {
+    pub fn new(command_buffers: &'a [CommandBuffer]) -> Self {
+        command_buffers.iter().collect()
+    }
+
+    pub fn as_raw(&self) -> &[VkCommandBuffer] {
+        &self.handles
+    }
+
+    pub fn len(&self) -> usize {
+        self.handles.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.handles.is_empty()
+    }
+}
+
+impl<'a> FromIterator<&'a CommandBuffer> for CommandBufferArray<'a> {
+    fn from_iter<I: IntoIterator<Item = &'a CommandBuffer>>(command_buffers: I) -> Self {
+        Self {
+            handles: command_buffers
+                .into_iter()
+                .map(CommandBuffer::as_raw)
+                .collect(),
+            phantom: std::marker::PhantomData,
+        }
+    }
+}
+
+unsafe impl Send for CommandBufferArray<'_> {}
+unsafe impl Sync for CommandBufferArray<'_> {}
+
+/*
+   Debug Label Scope
+*/
+
+/// Ends the debug label region opened by [`CommandBuffer::cmd_debug_label_scope`] on drop.
+#[must_use = "the label region ends as soon as the scope is dropped"]
+pub struct DebugLabelScope {
+    command_buffer: CommandBuffer,
+}
+
+impl DebugLabelScope {
+    pub const fn command_buffer(&self) -> &CommandBuffer {
+        &self.command_buffer
+    }
+}
+
+impl Drop for DebugLabelScope {
+    fn drop(&mut self) {
+        self.command_buffer.cmd_end_debug_label();
+    }
+}
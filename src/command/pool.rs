@@ -14,13 +14,13 @@ use bitflags::bitflags;
 //     }
 // }
 
-vulkan_create_info!(
+vulkan_create_info_lifetime!(
     CommandPoolCreateInfo,
     VkCommandPoolCreateInfo,
     VK_STRUCTURE_TYPE_COMMAND_POOL_CREATE_INFO
 );
 
-impl crate::CommandPoolCreateInfo {
+impl<'a> crate::CommandPoolCreateInfo<'a> {
     pub const fn with_flags(mut self, flags: CommandPoolCreateFlags) -> Self {
         self.inner.flags = flags.bits();
         self
@@ -32,4 +32,4 @@ impl crate::CommandPoolCreateInfo {
     }
 }
 
-vulkan_handle!(CommandPool, VkCommandPool);
+vulkan_handle!(CommandPool, VkCommandPool, COMMAND_POOL);
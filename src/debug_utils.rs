@@ -0,0 +1,232 @@
+// Copyright (c) 2024 Jacob R. Green
+// All rights reserved.
+
+use crate::macros::*;
+use crate::*;
+use vulkan_sys::*;
+
+/*
+   Debug Utils Messenger
+*/
+
+vulkan_handle!(
+    DebugUtilsMessengerEXT,
+    VkDebugUtilsMessengerEXT,
+    DEBUG_UTILS_MESSENGER_EXT
+);
+
+/// Boxed closure invoked for every message delivered to a [`DebugUtilsMessengerEXT`].
+pub type DebugUtilsMessengerCallbackEXT = Box<
+    dyn Fn(
+            DebugUtilsMessageSeverityFlagsEXT,
+            DebugUtilsMessageTypeFlagsEXT,
+            &DebugUtilsMessengerCallbackDataEXT,
+        ) + Send
+        + Sync,
+>;
+
+/// A messenger created by [`Instance::create_debug_utils_messenger_ext`], owning its callback.
+///
+/// The callback is freed by [`Instance::destroy_debug_utils_messenger_ext`]. Dropping this
+/// without destroying the messenger leaks the callback instead of leaving it dangling.
+#[derive(Debug)]
+pub struct OwnedDebugUtilsMessengerEXT {
+    handle: DebugUtilsMessengerEXT,
+    callback: *mut DebugUtilsMessengerCallbackEXT,
+}
+
+unsafe impl Send for OwnedDebugUtilsMessengerEXT {}
+unsafe impl Sync for OwnedDebugUtilsMessengerEXT {}
+
+impl OwnedDebugUtilsMessengerEXT {
+    pub(crate) const fn new(
+        handle: DebugUtilsMessengerEXT,
+        callback: *mut DebugUtilsMessengerCallbackEXT,
+    ) -> Self {
+        Self { handle, callback }
+    }
+
+    pub const fn handle(&self) -> DebugUtilsMessengerEXT {
+        self.handle
+    }
+
+    /// # Safety
+    /// The messenger must have been destroyed, the callback is freed.
+    pub(crate) unsafe fn free_callback(self) {
+        drop(Box::from_raw(self.callback));
+    }
+}
+
+/*
+   Debug Utils Messenger Create Info
+*/
+
+vulkan_create_info_lifetime!(
+    DebugUtilsMessengerCreateInfoEXT,
+    VkDebugUtilsMessengerCreateInfoEXT,
+    VK_STRUCTURE_TYPE_DEBUG_UTILS_MESSENGER_CREATE_INFO_EXT
+);
+
+impl<'a> DebugUtilsMessengerCreateInfoEXT<'a> {
+    pub const fn with_message_severity(
+        mut self,
+        message_severity: DebugUtilsMessageSeverityFlagsEXT,
+    ) -> Self {
+        self.inner.messageSeverity = message_severity.bits();
+        self
+    }
+
+    pub const fn with_message_type(mut self, message_type: DebugUtilsMessageTypeFlagsEXT) -> Self {
+        self.inner.messageType = message_type.bits();
+        self
+    }
+
+    /// The callback is only borrowed for the call this info is passed to, which covers chaining
+    /// it into [`InstanceCreateInfo`]. [`Instance::create_debug_utils_messenger_ext`] takes its
+    /// own callback and replaces this one.
+    pub fn with_user_callback(mut self, callback: &'a DebugUtilsMessengerCallbackEXT) -> Self {
+        self.inner.pfnUserCallback = Some(debug_utils_messenger_callback);
+        self.inner.pUserData = callback as *const DebugUtilsMessengerCallbackEXT as *mut _;
+        self
+    }
+}
+
+unsafe extern "C" fn debug_utils_messenger_callback(
+    message_severity: VkDebugUtilsMessageSeverityFlagBitsEXT,
+    message_types: VkDebugUtilsMessageTypeFlagsEXT,
+    callback_data: *const VkDebugUtilsMessengerCallbackDataEXT,
+    user_data: *mut std::ffi::c_void,
+) -> VkBool32 {
+    if user_data.is_null() || callback_data.is_null() {
+        return VK_FALSE;
+    }
+
+    let callback = &*(user_data as *const DebugUtilsMessengerCallbackEXT);
+    let severity = DebugUtilsMessageSeverityFlagsEXT::from_bits_truncate(message_severity as u32);
+    let types = DebugUtilsMessageTypeFlagsEXT::from_bits_truncate(message_types);
+    let data = DebugUtilsMessengerCallbackDataEXT::from_raw(&*callback_data);
+
+    // unwinding across the FFI boundary is undefined behaviour
+    let _ = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+        callback(severity, types, &data)
+    }));
+
+    VK_FALSE
+}
+
+/*
+   Debug Utils Object Name Info
+*/
+
+vulkan_create_info_lifetime!(
+    DebugUtilsObjectNameInfoEXT,
+    VkDebugUtilsObjectNameInfoEXT,
+    VK_STRUCTURE_TYPE_DEBUG_UTILS_OBJECT_NAME_INFO_EXT
+);
+
+impl<'a> DebugUtilsObjectNameInfoEXT<'a> {
+    pub fn with_object<H: Handle>(mut self, handle: &H) -> Self {
+        self.inner.objectType = H::OBJECT_TYPE.as_raw();
+        self.inner.objectHandle = handle.object_handle();
+        self
+    }
+
+    pub const fn with_object_name(mut self, object_name: &'a CStr) -> Self {
+        self.inner.pObjectName = object_name.as_ptr();
+        self
+    }
+}
+
+/*
+   Debug Utils Label
+*/
+
+vulkan_create_info_lifetime!(
+    DebugUtilsLabelEXT,
+    VkDebugUtilsLabelEXT,
+    VK_STRUCTURE_TYPE_DEBUG_UTILS_LABEL_EXT
+);
+
+impl<'a> DebugUtilsLabelEXT<'a> {
+    pub const fn with_label_name(mut self, label_name: &'a CStr) -> Self {
+        self.inner.pLabelName = label_name.as_ptr();
+        self
+    }
+
+    pub const fn with_color(mut self, color: [f32; 4]) -> Self {
+        self.inner.color = color;
+        self
+    }
+}
+
+/*
+   Debug Utils Messenger Callback Data
+*/
+
+#[derive(Debug, Clone, Copy)]
+pub struct DebugUtilsLabel<'a> {
+    pub label_name: &'a CStr,
+    pub color: [f32; 4],
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct DebugUtilsObject<'a> {
+    pub object_type: ObjectType,
+    pub object_handle: u64,
+    pub object_name: Option<&'a CStr>,
+}
+
+/// Decoded `VkDebugUtilsMessengerCallbackDataEXT`, borrowed for the duration of the callback.
+#[derive(Debug, Clone)]
+pub struct DebugUtilsMessengerCallbackDataEXT<'a> {
+    pub message_id_name: Option<&'a CStr>,
+    pub message_id_number: i32,
+    pub message: &'a CStr,
+    pub queue_labels: Vec<DebugUtilsLabel<'a>>,
+    pub cmd_buf_labels: Vec<DebugUtilsLabel<'a>>,
+    pub objects: Vec<DebugUtilsObject<'a>>,
+}
+
+impl<'a> DebugUtilsMessengerCallbackDataEXT<'a> {
+    /// # Safety
+    /// Every pointer in `data` must be valid for `'a`.
+    pub unsafe fn from_raw(data: &'a VkDebugUtilsMessengerCallbackDataEXT) -> Self {
+        unsafe fn optional_cstr<'a>(ptr: *const c_char) -> Option<&'a CStr> {
+            (!ptr.is_null()).then(|| CStr::from_ptr(ptr))
+        }
+
+        unsafe fn slice<'a, T>(ptr: *const T, count: u32) -> &'a [T] {
+            if ptr.is_null() || count == 0 {
+                &[]
+            } else {
+                std::slice::from_raw_parts(ptr, count as usize)
+            }
+        }
+
+        let labels = |ptr: *const VkDebugUtilsLabelEXT, count: u32| -> Vec<DebugUtilsLabel<'a>> {
+            slice(ptr, count)
+                .iter()
+                .map(|label| DebugUtilsLabel {
+                    label_name: optional_cstr(label.pLabelName).unwrap_or_default(),
+                    color: label.color,
+                })
+                .collect()
+        };
+
+        Self {
+            message_id_name: optional_cstr(data.pMessageIdName),
+            message_id_number: data.messageIdNumber,
+            message: optional_cstr(data.pMessage).unwrap_or_default(),
+            queue_labels: labels(data.pQueueLabels, data.queueLabelCount),
+            cmd_buf_labels: labels(data.pCmdBufLabels, data.cmdBufLabelCount),
+            objects: slice(data.pObjects, data.objectCount)
+                .iter()
+                .map(|object| DebugUtilsObject {
+                    object_type: ObjectType::from_raw(object.objectType),
+                    object_handle: object.objectHandle,
+                    object_name: optional_cstr(object.pObjectName),
+                })
+                .collect(),
+        }
+    }
+}
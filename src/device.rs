@@ -60,6 +60,20 @@ impl<'a> DeviceCreateInfo<'a> {
         self
     }
 
+    pub fn with_enabled_extension_names(self, extension_names: &'a CStrArray<'a>) -> Self {
+        self.with_enabled_extensions(extension_names.as_ptrs())
+    }
+
+    pub fn enabled_extension_names(&self) -> Vec<&'a CStr> {
+        unsafe {
+            enabled_names(
+                self.inner.ppEnabledExtensionNames,
+                self.inner.enabledExtensionCount,
+            )
+        }
+    }
+
+    /// Must not be combined with a chained [`PhysicalDeviceFeatures2`].
     pub const fn with_enabled_features(
         mut self,
         enabled_features: &'a PhysicalDeviceFeatures,
@@ -69,41 +83,100 @@ impl<'a> DeviceCreateInfo<'a> {
     }
 }
 
-vulkan_handle!(Device, VkDevice);
+vulkan_dispatchable_handle!(Device, VkDevice, DEVICE, dispatch::DeviceDispatch);
 
 impl Device {
     #[inline]
     pub fn create(
-        physical_device: PhysicalDevice,
+        physical_device: &PhysicalDevice,
         create_info: &DeviceCreateInfo,
         allocator: Option<&AllocationCallbacks>,
-    ) -> vulkan_sys::wrapper::Result<Self> {
-        create_device(
-            vkCreateDevice,
+    ) -> std::result::Result<Self, LoaderError> {
+        let device = create_device(
+            physical_device.dispatch().vkCreateDevice,
             physical_device.as_raw(),
             create_info.as_raw(),
             allocator.map(AllocationCallbacks::as_raw),
-        )
-        .map(Self::from_raw)
+        )?;
+
+        let dispatch = match dispatch::DeviceDispatch::resolve(
+            physical_device.dispatch().vkGetDeviceProcAddr,
+            device,
+        ) {
+            Ok(dispatch) => dispatch,
+            Err(error) => {
+                // the device is unusable without its dispatch table
+                let destroy: PFN_vkDestroyDevice = unsafe {
+                    transmute((physical_device.dispatch().vkGetDeviceProcAddr)(
+                        device,
+                        c"vkDestroyDevice".as_ptr(),
+                    ))
+                };
+                if let Some(destroy) = destroy {
+                    destroy_device(destroy, device, allocator.map(AllocationCallbacks::as_raw));
+                }
+                return Err(error);
+            }
+        };
+
+        Ok(Self::from_raw(device, std::sync::Arc::new(dispatch)))
+    }
+
+    /// Like [`Device::create`], but fails with [`SupportError::Unavailable`] listing every
+    /// requested extension and feature the physical device does not provide.
+    pub fn create_checked(
+        physical_device: &PhysicalDevice,
+        create_info: &DeviceCreateInfo,
+        allocator: Option<&AllocationCallbacks>,
+    ) -> std::result::Result<Self, SupportError> {
+        physical_device.check_device_support(create_info)?;
+        Ok(Self::create(physical_device, create_info, allocator)?)
     }
 
     #[inline]
     pub fn destroy(&self, allocator: Option<&AllocationCallbacks>) {
         destroy_device(
-            vkDestroyDevice,
+            self.dispatch().vkDestroyDevice,
             self.as_raw(),
             allocator.map(AllocationCallbacks::as_raw),
         )
     }
 
+    /// Names `handle` for debuggers and validation messages. Requires `VK_EXT_debug_utils`.
+    ///
+    /// The name is truncated at the first interior nul.
+    pub fn set_object_name<H: Handle>(
+        &self,
+        handle: &H,
+        name: &str,
+    ) -> vulkan_sys::wrapper::Result<()> {
+        let name = name.split('\0').next().unwrap_or_default();
+        let name = std::ffi::CString::new(name).unwrap();
+
+        let name_info = DebugUtilsObjectNameInfoEXT::new()
+            .with_object(handle)
+            .with_object_name(&name);
+
+        set_debug_utils_object_name_ext(
+            self.dispatch()
+                .vkSetDebugUtilsObjectNameEXT
+                .expect("vkSetDebugUtilsObjectNameEXT is not available"),
+            self.as_raw(),
+            name_info.as_raw(),
+        )
+    }
+
     #[inline]
     pub fn get_device_queue(&self, queue_family_index: u32, queue_index: u32) -> Queue {
-        Queue::from_raw(get_device_queue(
-            vkGetDeviceQueue,
-            self.as_raw(),
-            queue_family_index,
-            queue_index,
-        ))
+        Queue::from_raw(
+            get_device_queue(
+                self.dispatch().vkGetDeviceQueue,
+                self.as_raw(),
+                queue_family_index,
+                queue_index,
+            ),
+            self.dispatch().clone(),
+        )
     }
 
     #[inline]
@@ -113,7 +186,9 @@ impl Device {
         allocator: Option<&AllocationCallbacks>,
     ) -> vulkan_sys::wrapper::Result<SwapchainKHR> {
         create_swapchain_khr(
-            vkCreateSwapchainKHR,
+            self.dispatch()
+                .vkCreateSwapchainKHR
+                .expect("vkCreateSwapchainKHR is not available"),
             self.as_raw(),
             create_info.as_raw(),
             allocator.map(AllocationCallbacks::as_raw),
@@ -128,7 +203,9 @@ impl Device {
         allocator: Option<&AllocationCallbacks>,
     ) {
         destroy_swapchain_khr(
-            vkDestroySwapchainKHR,
+            self.dispatch()
+                .vkDestroySwapchainKHR
+                .expect("vkDestroySwapchainKHR is not available"),
             self.as_raw(),
             swapchain.as_raw(),
             allocator.map(AllocationCallbacks::as_raw),
@@ -140,8 +217,14 @@ impl Device {
         &self,
         swapchain: SwapchainKHR,
     ) -> vulkan_sys::wrapper::Result<Vec<Image>> {
-        get_swapchain_images_khr(vkGetSwapchainImagesKHR, self.as_raw(), swapchain.as_raw())
-            .map(|images| unsafe { transmute(images) })
+        get_swapchain_images_khr(
+            self.dispatch()
+                .vkGetSwapchainImagesKHR
+                .expect("vkGetSwapchainImagesKHR is not available"),
+            self.as_raw(),
+            swapchain.as_raw(),
+        )
+        .map(|images| unsafe { transmute(images) })
     }
 
     #[inline]
@@ -153,7 +236,9 @@ impl Device {
         fence: Option<Fence>,
     ) -> vulkan_sys::wrapper::Result<u32> {
         acquire_next_image_khr(
-            vkAcquireNextImageKHR,
+            self.dispatch()
+                .vkAcquireNextImageKHR
+                .expect("vkAcquireNextImageKHR is not available"),
             self.as_raw(),
             swapchain.as_raw(),
             timeout,
@@ -162,6 +247,217 @@ impl Device {
         )
     }
 
+    #[inline]
+    pub fn create_buffer(
+        &self,
+        create_info: &BufferCreateInfo,
+        allocator: Option<&AllocationCallbacks>,
+    ) -> vulkan_sys::wrapper::Result<Buffer> {
+        create_buffer(
+            self.dispatch().vkCreateBuffer,
+            self.as_raw(),
+            create_info.as_raw(),
+            allocator.map(AllocationCallbacks::as_raw),
+        )
+        .map(Buffer::from_raw)
+    }
+
+    #[inline]
+    pub fn destroy_buffer(&self, buffer: Buffer, allocator: Option<&AllocationCallbacks>) {
+        destroy_buffer(
+            self.dispatch().vkDestroyBuffer,
+            self.as_raw(),
+            buffer.as_raw(),
+            allocator.map(AllocationCallbacks::as_raw),
+        )
+    }
+
+    #[inline]
+    pub fn create_buffer_view(
+        &self,
+        create_info: &BufferViewCreateInfo,
+        allocator: Option<&AllocationCallbacks>,
+    ) -> vulkan_sys::wrapper::Result<BufferView> {
+        create_buffer_view(
+            self.dispatch().vkCreateBufferView,
+            self.as_raw(),
+            create_info.as_raw(),
+            allocator.map(AllocationCallbacks::as_raw),
+        )
+        .map(BufferView::from_raw)
+    }
+
+    #[inline]
+    pub fn destroy_buffer_view(
+        &self,
+        buffer_view: BufferView,
+        allocator: Option<&AllocationCallbacks>,
+    ) {
+        destroy_buffer_view(
+            self.dispatch().vkDestroyBufferView,
+            self.as_raw(),
+            buffer_view.as_raw(),
+            allocator.map(AllocationCallbacks::as_raw),
+        )
+    }
+
+    #[inline]
+    pub fn get_buffer_memory_requirements(&self, buffer: Buffer) -> MemoryRequirements {
+        MemoryRequirements::from_raw(get_buffer_memory_requirements(
+            self.dispatch().vkGetBufferMemoryRequirements,
+            self.as_raw(),
+            buffer.as_raw(),
+        ))
+    }
+
+    #[inline]
+    pub fn create_image(
+        &self,
+        create_info: &ImageCreateInfo,
+        allocator: Option<&AllocationCallbacks>,
+    ) -> vulkan_sys::wrapper::Result<Image> {
+        create_image(
+            self.dispatch().vkCreateImage,
+            self.as_raw(),
+            create_info.as_raw(),
+            allocator.map(AllocationCallbacks::as_raw),
+        )
+        .map(Image::from_raw)
+    }
+
+    #[inline]
+    pub fn destroy_image(&self, image: Image, allocator: Option<&AllocationCallbacks>) {
+        destroy_image(
+            self.dispatch().vkDestroyImage,
+            self.as_raw(),
+            image.as_raw(),
+            allocator.map(AllocationCallbacks::as_raw),
+        )
+    }
+
+    #[inline]
+    pub fn get_image_memory_requirements(&self, image: Image) -> MemoryRequirements {
+        MemoryRequirements::from_raw(get_image_memory_requirements(
+            self.dispatch().vkGetImageMemoryRequirements,
+            self.as_raw(),
+            image.as_raw(),
+        ))
+    }
+
+    #[inline]
+    pub fn allocate_memory(
+        &self,
+        allocate_info: &MemoryAllocateInfo,
+        allocator: Option<&AllocationCallbacks>,
+    ) -> vulkan_sys::wrapper::Result<DeviceMemory> {
+        allocate_memory(
+            self.dispatch().vkAllocateMemory,
+            self.as_raw(),
+            allocate_info.as_raw(),
+            allocator.map(AllocationCallbacks::as_raw),
+        )
+        .map(DeviceMemory::from_raw)
+    }
+
+    #[inline]
+    pub fn free_memory(&self, memory: DeviceMemory, allocator: Option<&AllocationCallbacks>) {
+        free_memory(
+            self.dispatch().vkFreeMemory,
+            self.as_raw(),
+            memory.as_raw(),
+            allocator.map(AllocationCallbacks::as_raw),
+        )
+    }
+
+    #[inline]
+    pub fn bind_buffer_memory(
+        &self,
+        buffer: Buffer,
+        memory: DeviceMemory,
+        memory_offset: DeviceSize,
+    ) -> vulkan_sys::wrapper::Result<()> {
+        bind_buffer_memory(
+            self.dispatch().vkBindBufferMemory,
+            self.as_raw(),
+            buffer.as_raw(),
+            memory.as_raw(),
+            memory_offset,
+        )
+    }
+
+    #[inline]
+    pub fn bind_image_memory(
+        &self,
+        image: Image,
+        memory: DeviceMemory,
+        memory_offset: DeviceSize,
+    ) -> vulkan_sys::wrapper::Result<()> {
+        bind_image_memory(
+            self.dispatch().vkBindImageMemory,
+            self.as_raw(),
+            image.as_raw(),
+            memory.as_raw(),
+            memory_offset,
+        )
+    }
+
+    /// Maps a range of `memory` into host address space.
+    ///
+    /// The returned pointer is valid until [`Device::unmap_memory`] is called on `memory`.
+    #[inline]
+    pub fn map_memory(
+        &self,
+        memory: DeviceMemory,
+        offset: DeviceSize,
+        size: DeviceSize,
+    ) -> vulkan_sys::wrapper::Result<*mut std::ffi::c_void> {
+        map_memory(
+            self.dispatch().vkMapMemory,
+            self.as_raw(),
+            memory.as_raw(),
+            offset,
+            size,
+            0,
+        )
+    }
+
+    #[inline]
+    pub fn unmap_memory(&self, memory: DeviceMemory) {
+        unmap_memory(
+            self.dispatch().vkUnmapMemory,
+            self.as_raw(),
+            memory.as_raw(),
+        )
+    }
+
+    #[inline]
+    pub fn flush_mapped_memory_ranges(
+        &self,
+        memory_ranges: &[MappedMemoryRange],
+    ) -> vulkan_sys::wrapper::Result<()> {
+        unsafe {
+            flush_mapped_memory_ranges(
+                self.dispatch().vkFlushMappedMemoryRanges,
+                self.as_raw(),
+                transmute(memory_ranges),
+            )
+        }
+    }
+
+    #[inline]
+    pub fn invalidate_mapped_memory_ranges(
+        &self,
+        memory_ranges: &[MappedMemoryRange],
+    ) -> vulkan_sys::wrapper::Result<()> {
+        unsafe {
+            invalidate_mapped_memory_ranges(
+                self.dispatch().vkInvalidateMappedMemoryRanges,
+                self.as_raw(),
+                transmute(memory_ranges),
+            )
+        }
+    }
+
     #[inline]
     pub fn create_image_view(
         &self,
@@ -169,7 +465,7 @@ impl Device {
         allocator: Option<&AllocationCallbacks>,
     ) -> vulkan_sys::wrapper::Result<ImageView> {
         create_image_view(
-            vkCreateImageView,
+            self.dispatch().vkCreateImageView,
             self.as_raw(),
             create_info.as_raw(),
             allocator.map(AllocationCallbacks::as_raw),
@@ -184,7 +480,7 @@ impl Device {
         allocator: Option<&AllocationCallbacks>,
     ) {
         destroy_image_view(
-            vkDestroyImageView,
+            self.dispatch().vkDestroyImageView,
             self.as_raw(),
             image_view.as_raw(),
             allocator.map(AllocationCallbacks::as_raw),
@@ -198,7 +494,7 @@ impl Device {
         allocator: Option<&AllocationCallbacks>,
     ) -> vulkan_sys::wrapper::Result<Sampler> {
         create_sampler(
-            vkCreateSampler,
+            self.dispatch().vkCreateSampler,
             self.as_raw(),
             create_info.as_raw(),
             allocator.map(AllocationCallbacks::as_raw),
@@ -209,7 +505,7 @@ impl Device {
     #[inline]
     pub fn destroy_sampler(&self, sampler: Sampler, allocator: Option<&AllocationCallbacks>) {
         destroy_sampler(
-            vkDestroySampler,
+            self.dispatch().vkDestroySampler,
             self.as_raw(),
             sampler.as_raw(),
             allocator.map(AllocationCallbacks::as_raw),
@@ -223,7 +519,7 @@ impl Device {
         allocator: Option<&AllocationCallbacks>,
     ) -> vulkan_sys::wrapper::Result<RenderPass> {
         create_render_pass(
-            vkCreateRenderPass,
+            self.dispatch().vkCreateRenderPass,
             self.as_raw(),
             create_info.as_raw(),
             allocator.map(AllocationCallbacks::as_raw),
@@ -238,7 +534,7 @@ impl Device {
         allocator: Option<&AllocationCallbacks>,
     ) {
         destroy_render_pass(
-            vkDestroyRenderPass,
+            self.dispatch().vkDestroyRenderPass,
             self.as_raw(),
             render_pass.as_raw(),
             allocator.map(AllocationCallbacks::as_raw),
@@ -252,7 +548,7 @@ impl Device {
         allocator: Option<&AllocationCallbacks>,
     ) -> vulkan_sys::wrapper::Result<Framebuffer> {
         create_framebuffer(
-            vkCreateFramebuffer,
+            self.dispatch().vkCreateFramebuffer,
             self.as_raw(),
             create_info.as_raw(),
             allocator.map(AllocationCallbacks::as_raw),
@@ -267,13 +563,142 @@ impl Device {
         allocator: Option<&AllocationCallbacks>,
     ) {
         destroy_framebuffer(
-            vkDestroyFramebuffer,
+            self.dispatch().vkDestroyFramebuffer,
             self.as_raw(),
             framebuffer.as_raw(),
             allocator.map(AllocationCallbacks::as_raw),
         )
     }
 
+    #[inline]
+    pub fn create_query_pool(
+        &self,
+        create_info: &QueryPoolCreateInfo,
+        allocator: Option<&AllocationCallbacks>,
+    ) -> vulkan_sys::wrapper::Result<QueryPool> {
+        create_query_pool(
+            self.dispatch().vkCreateQueryPool,
+            self.as_raw(),
+            create_info.as_raw(),
+            allocator.map(AllocationCallbacks::as_raw),
+        )
+        .map(QueryPool::from_raw)
+    }
+
+    #[inline]
+    pub fn destroy_query_pool(
+        &self,
+        query_pool: QueryPool,
+        allocator: Option<&AllocationCallbacks>,
+    ) {
+        destroy_query_pool(
+            self.dispatch().vkDestroyQueryPool,
+            self.as_raw(),
+            query_pool.as_raw(),
+            allocator.map(AllocationCallbacks::as_raw),
+        )
+    }
+
+    /// Resets queries from the host. Requires Vulkan 1.2 or `VK_EXT_host_query_reset`, and the
+    /// `host_query_reset` feature.
+    pub fn reset_query_pool(&self, query_pool: QueryPool, first_query: u32, query_count: u32) {
+        let dispatch = self.dispatch();
+        reset_query_pool(
+            dispatch
+                .vkResetQueryPool
+                .or(dispatch.vkResetQueryPoolEXT)
+                .expect("vkResetQueryPool is not available"),
+            self.as_raw(),
+            query_pool.as_raw(),
+            first_query,
+            query_count,
+        )
+    }
+
+    /// Reads `query_count` queries of `values_per_query` values each, e.g. one for occlusion and
+    /// timestamp queries or `pipeline_statistics.bits().count_ones()` for statistics queries.
+    ///
+    /// `RESULT_64`, `WITH_AVAILABILITY`, `WAIT` and `PARTIAL` in `flags` are honoured; 32-bit
+    /// results are widened. Partial results are read with
+    /// [`QueryResults::values_with_availability`].
+    ///
+    /// Without `WAIT` or `PARTIAL`, Vulkan skips the values of queries that are not yet available
+    /// and returns `VK_NOT_READY`. That is a success code, so the results of the available queries
+    /// are still returned; the skipped values read as zero. Add `WITH_AVAILABILITY` to tell the
+    /// two apart, [`QueryResults::values`] is then `None` for the skipped queries.
+    pub fn get_query_pool_results(
+        &self,
+        query_pool: QueryPool,
+        first_query: u32,
+        query_count: u32,
+        values_per_query: u32,
+        flags: QueryResultFlags,
+    ) -> vulkan_sys::wrapper::Result<QueryResults> {
+        let values_per_query = values_per_query as usize;
+        let with_availability = flags.contains(QueryResultFlags::WITH_AVAILABILITY);
+        let stride = values_per_query + with_availability as usize;
+        let len = stride * query_count as usize;
+
+        if flags.contains(QueryResultFlags::RESULT_64) {
+            let mut data = vec![0u64; len];
+            self.get_query_pool_results_raw(
+                query_pool,
+                first_query,
+                query_count,
+                unsafe {
+                    std::slice::from_raw_parts_mut(
+                        data.as_mut_ptr().cast(),
+                        std::mem::size_of_val(data.as_slice()),
+                    )
+                },
+                std::mem::size_of::<u64>() * stride,
+                flags,
+            )?;
+            Ok(QueryResults::new(data, values_per_query, with_availability))
+        } else {
+            let mut data = vec![0u32; len];
+            self.get_query_pool_results_raw(
+                query_pool,
+                first_query,
+                query_count,
+                unsafe {
+                    std::slice::from_raw_parts_mut(
+                        data.as_mut_ptr().cast(),
+                        std::mem::size_of_val(data.as_slice()),
+                    )
+                },
+                std::mem::size_of::<u32>() * stride,
+                flags,
+            )?;
+            Ok(QueryResults::from_u32(
+                data,
+                values_per_query,
+                with_availability,
+            ))
+        }
+    }
+
+    fn get_query_pool_results_raw(
+        &self,
+        query_pool: QueryPool,
+        first_query: u32,
+        query_count: u32,
+        data: &mut [u8],
+        stride: usize,
+        flags: QueryResultFlags,
+    ) -> vulkan_sys::wrapper::Result<()> {
+        get_query_pool_results(
+            self.dispatch().vkGetQueryPoolResults,
+            self.as_raw(),
+            query_pool.as_raw(),
+            first_query,
+            query_count,
+            data,
+            stride as DeviceSize,
+            flags.bits(),
+        )
+    }
+
     #[inline]
     pub fn create_descriptor_set_layout(
         &self,
@@ -281,7 +706,7 @@ impl Device {
         allocator: Option<&AllocationCallbacks>,
     ) -> vulkan_sys::wrapper::Result<DescriptorSetLayout> {
         create_descriptor_set_layout(
-            vkCreateDescriptorSetLayout,
+            self.dispatch().vkCreateDescriptorSetLayout,
             self.as_raw(),
             create_info.as_raw(),
             allocator.map(AllocationCallbacks::as_raw),
@@ -296,7 +721,7 @@ impl Device {
         allocator: Option<&AllocationCallbacks>,
     ) {
         destroy_descriptor_set_layout(
-            vkDestroyDescriptorSetLayout,
+            self.dispatch().vkDestroyDescriptorSetLayout,
             self.as_raw(),
             descriptor_set_layout.as_raw(),
             allocator.map(AllocationCallbacks::as_raw),
@@ -310,7 +735,7 @@ impl Device {
         allocator: Option<&AllocationCallbacks>,
     ) -> vulkan_sys::wrapper::Result<DescriptorPool> {
         create_descriptor_pool(
-            vkCreateDescriptorPool,
+            self.dispatch().vkCreateDescriptorPool,
             self.as_raw(),
             create_info.as_raw(),
             allocator.map(AllocationCallbacks::as_raw),
@@ -325,7 +750,7 @@ impl Device {
         allocator: Option<&AllocationCallbacks>,
     ) {
         destroy_descriptor_pool(
-            vkDestroyDescriptorPool,
+            self.dispatch().vkDestroyDescriptorPool,
             self.as_raw(),
             descriptor_pool.as_raw(),
             allocator.map(AllocationCallbacks::as_raw),
@@ -338,7 +763,7 @@ impl Device {
         allocate_info: &DescriptorSetAllocateInfo,
     ) -> vulkan_sys::wrapper::Result<Vec<DescriptorSet>> {
         allocate_descriptor_sets(
-            vkAllocateDescriptorSets,
+            self.dispatch().vkAllocateDescriptorSets,
             self.as_raw(),
             allocate_info.as_raw(),
         )
@@ -353,7 +778,7 @@ impl Device {
     ) -> vulkan_sys::wrapper::Result<()> {
         unsafe {
             free_descriptor_sets(
-                vkFreeDescriptorSets,
+                self.dispatch().vkFreeDescriptorSets,
                 self.as_raw(),
                 descriptor_pool.as_raw(),
                 transmute(descriptor_sets),
@@ -369,7 +794,7 @@ impl Device {
     ) {
         unsafe {
             update_descriptor_sets(
-                vkUpdateDescriptorSets,
+                self.dispatch().vkUpdateDescriptorSets,
                 self.as_raw(),
                 transmute(descriptor_writes),
                 transmute(descriptor_copies),
@@ -377,6 +802,67 @@ impl Device {
         }
     }
 
+    /// Requires Vulkan 1.1 or `VK_KHR_descriptor_update_template`.
+    pub fn create_descriptor_update_template(
+        &self,
+        create_info: &DescriptorUpdateTemplateCreateInfo,
+        allocator: Option<&AllocationCallbacks>,
+    ) -> vulkan_sys::wrapper::Result<DescriptorUpdateTemplate> {
+        let dispatch = self.dispatch();
+        create_descriptor_update_template(
+            dispatch
+                .vkCreateDescriptorUpdateTemplate
+                .or(dispatch.vkCreateDescriptorUpdateTemplateKHR)
+                .expect("vkCreateDescriptorUpdateTemplate is not available"),
+            self.as_raw(),
+            create_info.as_raw(),
+            allocator.map(AllocationCallbacks::as_raw),
+        )
+        .map(DescriptorUpdateTemplate::from_raw)
+    }
+
+    pub fn destroy_descriptor_update_template(
+        &self,
+        descriptor_update_template: DescriptorUpdateTemplate,
+        allocator: Option<&AllocationCallbacks>,
+    ) {
+        let dispatch = self.dispatch();
+        destroy_descriptor_update_template(
+            dispatch
+                .vkDestroyDescriptorUpdateTemplate
+                .or(dispatch.vkDestroyDescriptorUpdateTemplateKHR)
+                .expect("vkDestroyDescriptorUpdateTemplate is not available"),
+            self.as_raw(),
+            descriptor_update_template.as_raw(),
+            allocator.map(AllocationCallbacks::as_raw),
+        )
+    }
+
+    /// Updates `descriptor_set` from `data`.
+    ///
+    /// # Safety
+    /// `descriptor_update_template` must have been created with
+    /// [`DescriptorUpdateTemplateCreateInfo::with_update_data`] for `T`, nothing else ties the
+    /// template's entries to the layout of `data`.
+    pub unsafe fn update_descriptor_set_with_template<T: DescriptorUpdateData>(
+        &self,
+        descriptor_set: DescriptorSet,
+        descriptor_update_template: DescriptorUpdateTemplate,
+        data: &T,
+    ) {
+        let dispatch = self.dispatch();
+        update_descriptor_set_with_template(
+            dispatch
+                .vkUpdateDescriptorSetWithTemplate
+                .or(dispatch.vkUpdateDescriptorSetWithTemplateKHR)
+                .expect("vkUpdateDescriptorSetWithTemplate is not available"),
+            self.as_raw(),
+            descriptor_set.as_raw(),
+            descriptor_update_template.as_raw(),
+            (data as *const T).cast(),
+        )
+    }
+
     #[inline]
     pub fn create_pipeline_layout(
         &self,
@@ -384,7 +870,7 @@ impl Device {
         allocator: Option<&AllocationCallbacks>,
     ) -> vulkan_sys::wrapper::Result<PipelineLayout> {
         create_pipeline_layout(
-            vkCreatePipelineLayout,
+            self.dispatch().vkCreatePipelineLayout,
             self.as_raw(),
             create_info.as_raw(),
             allocator.map(AllocationCallbacks::as_raw),
@@ -399,7 +885,7 @@ impl Device {
         allocator: Option<&AllocationCallbacks>,
     ) {
         destroy_pipeline_layout(
-            vkDestroyPipelineLayout,
+            self.dispatch().vkDestroyPipelineLayout,
             self.as_raw(),
             pipeline_layout.as_raw(),
             allocator.map(AllocationCallbacks::as_raw),
@@ -413,7 +899,7 @@ impl Device {
         allocator: Option<&AllocationCallbacks>,
     ) -> vulkan_sys::wrapper::Result<ShaderModule> {
         create_shader_module(
-            vkCreateShaderModule,
+            self.dispatch().vkCreateShaderModule,
             self.as_raw(),
             create_info.as_raw(),
             allocator.map(AllocationCallbacks::as_raw),
@@ -428,13 +914,70 @@ impl Device {
         allocator: Option<&AllocationCallbacks>,
     ) {
         destroy_shader_module(
-            vkDestroyShaderModule,
+            self.dispatch().vkDestroyShaderModule,
             self.as_raw(),
             shader_module.as_raw(),
             allocator.map(AllocationCallbacks::as_raw),
         )
     }
 
+    #[inline]
+    pub fn create_pipeline_cache(
+        &self,
+        create_info: &PipelineCacheCreateInfo,
+        allocator: Option<&AllocationCallbacks>,
+    ) -> vulkan_sys::wrapper::Result<PipelineCache> {
+        create_pipeline_cache(
+            self.dispatch().vkCreatePipelineCache,
+            self.as_raw(),
+            create_info.as_raw(),
+            allocator.map(AllocationCallbacks::as_raw),
+        )
+        .map(PipelineCache::from_raw)
+    }
+
+    #[inline]
+    pub fn destroy_pipeline_cache(
+        &self,
+        pipeline_cache: PipelineCache,
+        allocator: Option<&AllocationCallbacks>,
+    ) {
+        destroy_pipeline_cache(
+            self.dispatch().vkDestroyPipelineCache,
+            self.as_raw(),
+            pipeline_cache.as_raw(),
+            allocator.map(AllocationCallbacks::as_raw),
+        )
+    }
+
+    #[inline]
+    pub fn get_pipeline_cache_data(
+        &self,
+        pipeline_cache: PipelineCache,
+    ) -> vulkan_sys::wrapper::Result<Vec<u8>> {
+        get_pipeline_cache_data(
+            self.dispatch().vkGetPipelineCacheData,
+            self.as_raw(),
+            pipeline_cache.as_raw(),
+        )
+    }
+
+    #[inline]
+    pub fn merge_pipeline_caches(
+        &self,
+        dst_cache: PipelineCache,
+        src_caches: &[PipelineCache],
+    ) -> vulkan_sys::wrapper::Result<()> {
+        unsafe {
+            merge_pipeline_caches(
+                self.dispatch().vkMergePipelineCaches,
+                self.as_raw(),
+                dst_cache.as_raw(),
+                transmute(src_caches),
+            )
+        }
+    }
+
     #[inline]
     pub fn create_graphics_pipelines(
         &self,
@@ -444,7 +987,27 @@ impl Device {
     ) -> vulkan_sys::wrapper::Result<Vec<Pipeline>> {
         unsafe {
             transmute(create_graphics_pipelines(
-                vkCreateGraphicsPipelines,
+                self.dispatch().vkCreateGraphicsPipelines,
+                self.as_raw(),
+                pipeline_cache
+                    .map(|c| c.as_raw())
+                    .unwrap_or(std::ptr::null_mut()),
+                transmute(create_infos),
+                transmute(allocator),
+            ))
+        }
+    }
+
+    #[inline]
+    pub fn create_compute_pipelines(
+        &self,
+        pipeline_cache: Option<PipelineCache>,
+        create_infos: &[ComputePipelineCreateInfo],
+        allocator: Option<&AllocationCallbacks>,
+    ) -> vulkan_sys::wrapper::Result<Vec<Pipeline>> {
+        unsafe {
+            transmute(create_compute_pipelines(
+                self.dispatch().vkCreateComputePipelines,
                 self.as_raw(),
                 pipeline_cache
                     .map(|c| c.as_raw())
@@ -459,7 +1022,7 @@ impl Device {
     pub fn destroy_pipeline(&self, pipeline: Pipeline, allocator: Option<&AllocationCallbacks>) {
         unsafe {
             transmute(destroy_pipeline(
-                vkDestroyPipeline,
+                self.dispatch().vkDestroyPipeline,
                 self.as_raw(),
                 pipeline.as_raw(),
                 transmute(allocator),
@@ -475,7 +1038,7 @@ impl Device {
     ) -> vulkan_sys::wrapper::Result<CommandPool> {
         unsafe {
             transmute(create_command_pool(
-                vkCreateCommandPool,
+                self.dispatch().vkCreateCommandPool,
                 self.as_raw(),
                 create_info.as_raw(),
                 transmute(allocator),
@@ -490,7 +1053,7 @@ impl Device {
         allocator: Option<&AllocationCallbacks>,
     ) {
         destroy_command_pool(
-            vkDestroyCommandPool,
+            self.dispatch().vkDestroyCommandPool,
             self.as_raw(),
             command_pool.as_raw(),
             allocator.map(AllocationCallbacks::as_raw),
@@ -503,11 +1066,16 @@ impl Device {
         allocate_info: &CommandBufferAllocateInfo,
     ) -> vulkan_sys::wrapper::Result<Vec<CommandBuffer>> {
         allocate_command_buffers(
-            vkAllocateCommandBuffers,
+            self.dispatch().vkAllocateCommandBuffers,
             self.as_raw(),
             allocate_info.as_raw(),
         )
-        .map(|buffers| buffers.into_iter().map(CommandBuffer::from_raw).collect())
+        .map(|buffers| {
+            buffers
+                .into_iter()
+                .map(|buffer| CommandBuffer::from_raw(buffer, self.dispatch().clone()))
+                .collect()
+        })
     }
 
     #[inline]
@@ -517,7 +1085,7 @@ impl Device {
         allocator: Option<&AllocationCallbacks>,
     ) -> vulkan_sys::wrapper::Result<Fence> {
         create_fence(
-            vkCreateFence,
+            self.dispatch().vkCreateFence,
             self.as_raw(),
             create_info.as_raw(),
             allocator.map(AllocationCallbacks::as_raw),
@@ -528,7 +1096,7 @@ impl Device {
     #[inline]
     pub fn destroy_fence(&self, fence: Fence, allocator: Option<&AllocationCallbacks>) {
         destroy_fence(
-            vkDestroyFence,
+            self.dispatch().vkDestroyFence,
             self.as_raw(),
             fence.as_raw(),
             allocator.map(AllocationCallbacks::as_raw),
@@ -543,7 +1111,7 @@ impl Device {
         timeout: u64,
     ) -> vulkan_sys::wrapper::Result<()> {
         wait_for_fences(
-            vkWaitForFences,
+            self.dispatch().vkWaitForFences,
             self.as_raw(),
             unsafe { transmute(fences) },
             wait_all,
@@ -553,7 +1121,9 @@ impl Device {
 
     #[inline]
     pub fn reset_fences(&self, fences: &[Fence]) -> vulkan_sys::wrapper::Result<()> {
-        reset_fences(vkResetFences, self.as_raw(), unsafe { transmute(fences) })
+        reset_fences(self.dispatch().vkResetFences, self.as_raw(), unsafe {
+            transmute(fences)
+        })
     }
 
     #[inline]
@@ -563,7 +1133,7 @@ impl Device {
         allocator: Option<&AllocationCallbacks>,
     ) -> vulkan_sys::wrapper::Result<Semaphore> {
         create_semaphore(
-            vkCreateSemaphore,
+            self.dispatch().vkCreateSemaphore,
             self.as_raw(),
             create_info.as_raw(),
             allocator.map(AllocationCallbacks::as_raw),
@@ -574,10 +1144,110 @@ impl Device {
     #[inline]
     pub fn destroy_semaphore(&self, semaphore: Semaphore, allocator: Option<&AllocationCallbacks>) {
         destroy_semaphore(
-            vkDestroySemaphore,
+            self.dispatch().vkDestroySemaphore,
             self.as_raw(),
             semaphore.as_raw(),
             allocator.map(AllocationCallbacks::as_raw),
         )
     }
+
+    #[inline]
+    pub fn create_event(
+        &self,
+        create_info: &EventCreateInfo,
+        allocator: Option<&AllocationCallbacks>,
+    ) -> vulkan_sys::wrapper::Result<Event> {
+        create_event(
+            self.dispatch().vkCreateEvent,
+            self.as_raw(),
+            create_info.as_raw(),
+            allocator.map(AllocationCallbacks::as_raw),
+        )
+        .map(Event::from_raw)
+    }
+
+    #[inline]
+    pub fn destroy_event(&self, event: Event, allocator: Option<&AllocationCallbacks>) {
+        destroy_event(
+            self.dispatch().vkDestroyEvent,
+            self.as_raw(),
+            event.as_raw(),
+            allocator.map(AllocationCallbacks::as_raw),
+        )
+    }
+
+    pub fn get_event_status(&self, event: Event) -> vulkan_sys::wrapper::Result<EventStatus> {
+        get_event_status(
+            self.dispatch().vkGetEventStatus,
+            self.as_raw(),
+            event.as_raw(),
+        )
+        .map(|set| {
+            if set {
+                EventStatus::Set
+            } else {
+                EventStatus::Reset
+            }
+        })
+    }
+
+    #[inline]
+    pub fn set_event(&self, event: Event) -> vulkan_sys::wrapper::Result<()> {
+        set_event(self.dispatch().vkSetEvent, self.as_raw(), event.as_raw())
+    }
+
+    #[inline]
+    pub fn reset_event(&self, event: Event) -> vulkan_sys::wrapper::Result<()> {
+        reset_event(self.dispatch().vkResetEvent, self.as_raw(), event.as_raw())
+    }
+
+    /// Requires Vulkan 1.2 or `VK_KHR_timeline_semaphore`.
+    pub fn get_semaphore_counter_value(
+        &self,
+        semaphore: Semaphore,
+    ) -> vulkan_sys::wrapper::Result<u64> {
+        let dispatch = self.dispatch();
+        get_semaphore_counter_value(
+            dispatch
+                .vkGetSemaphoreCounterValue
+                .or(dispatch.vkGetSemaphoreCounterValueKHR)
+                .expect("vkGetSemaphoreCounterValue is not available"),
+            self.as_raw(),
+            semaphore.as_raw(),
+        )
+    }
+
+    /// Requires Vulkan 1.2 or `VK_KHR_timeline_semaphore`.
+    pub fn wait_semaphores(
+        &self,
+        wait_info: &SemaphoreWaitInfo,
+        timeout: u64,
+    ) -> vulkan_sys::wrapper::Result<()> {
+        let dispatch = self.dispatch();
+        wait_semaphores(
+            dispatch
+                .vkWaitSemaphores
+                .or(dispatch.vkWaitSemaphoresKHR)
+                .expect("vkWaitSemaphores is not available"),
+            self.as_raw(),
+            wait_info.as_raw(),
+            timeout,
+        )
+    }
+
+    /// Requires Vulkan 1.2 or `VK_KHR_timeline_semaphore`.
+    pub fn signal_semaphore(
+        &self,
+        signal_info: &SemaphoreSignalInfo,
+    ) -> vulkan_sys::wrapper::Result<()> {
+        let dispatch = self.dispatch();
+        signal_semaphore(
+            dispatch
+                .vkSignalSemaphore
+                .or(dispatch.vkSignalSemaphoreKHR)
+                .expect("vkSignalSemaphore is not available"),
+            self.as_raw(),
+            signal_info.as_raw(),
+        )
+    }
 }
@@ -0,0 +1,319 @@
+// Copyright (c) 2024 Jacob R. Green
+// All rights reserved.
+
+use crate::*;
+use vulkan_sys::*;
+
+/*
+   Proc Addr
+*/
+
+/// Strips the `Option` bindgen wraps around every `PFN_vk*` so required entry points can be
+/// stored as plain function pointers.
+pub trait ProcAddr {
+    type Fn: Copy;
+}
+
+impl<F: Copy> ProcAddr for Option<F> {
+    type Fn = F;
+}
+
+/// Declares a dispatch table. Required entry points fail the load with
+/// [`LoaderError::MissingEntryPoint`], optional ones (extensions, newer core versions) are left
+/// as `None` and checked at the call site. `state` fields start at their initial value and are
+/// filled in by the table's `resolve`.
+macro_rules! dispatch_table {
+    (
+        $(#[$attr:meta])*
+        $vis:vis struct $name:ident {
+            $($(#[$required_attr:meta])* $required:ident: $required_pfn:ty,)*
+        }
+        optional {
+            $($(#[$optional_attr:meta])* $optional:ident: $optional_pfn:ty,)*
+        }
+        $(state {
+            $($(#[$state_attr:meta])* $state:ident: $state_ty:ty = $state_init:expr,)*
+        })?
+    ) => {
+        $(#[$attr])*
+        #[allow(non_snake_case)]
+        $vis struct $name {
+            $($(#[$required_attr])* pub $required: <$required_pfn as ProcAddr>::Fn,)*
+            $($(#[$optional_attr])* pub $optional: $optional_pfn,)*
+            $($($(#[$state_attr])* pub $state: $state_ty,)*)?
+        }
+
+        impl $name {
+            /// # Safety
+            /// `get_proc_addr` must return entry points matching the requested names.
+            unsafe fn load(
+                get_proc_addr: impl Fn(*const c_char) -> PFN_vkVoidFunction,
+            ) -> std::result::Result<Self, LoaderError> {
+                Ok(Self {
+                    $($(#[$required_attr])* $required: transmute::<PFN_vkVoidFunction, $required_pfn>(
+                        get_proc_addr(concat!(stringify!($required), "\0").as_ptr().cast()),
+                    )
+                    .ok_or(LoaderError::MissingEntryPoint(stringify!($required)))?,)*
+                    $($(#[$optional_attr])* $optional: transmute::<PFN_vkVoidFunction, $optional_pfn>(
+                        get_proc_addr(concat!(stringify!($optional), "\0").as_ptr().cast()),
+                    ),)*
+                    $($($state: $state_init,)*)?
+                })
+            }
+        }
+    };
+}
+
+/*
+   Entry Dispatch
+*/
+
+dispatch_table! {
+    /// Global commands, resolved through `vkGetInstanceProcAddr(NULL, ...)`.
+    pub(crate) struct EntryDispatch {
+        vkCreateInstance: PFN_vkCreateInstance,
+        vkEnumerateInstanceExtensionProperties: PFN_vkEnumerateInstanceExtensionProperties,
+        vkEnumerateInstanceLayerProperties: PFN_vkEnumerateInstanceLayerProperties,
+    }
+    optional {
+        vkEnumerateInstanceVersion: PFN_vkEnumerateInstanceVersion,
+    }
+}
+
+impl EntryDispatch {
+    pub(crate) fn resolve(
+        get_instance_proc_addr: <PFN_vkGetInstanceProcAddr as ProcAddr>::Fn,
+    ) -> std::result::Result<Self, LoaderError> {
+        unsafe { Self::load(|name| get_instance_proc_addr(std::ptr::null_mut(), name)) }
+    }
+}
+
+/*
+   Instance Dispatch
+*/
+
+dispatch_table! {
+    pub(crate) struct InstanceDispatch {
+        vkDestroyInstance: PFN_vkDestroyInstance,
+        vkEnumeratePhysicalDevices: PFN_vkEnumeratePhysicalDevices,
+        vkGetPhysicalDeviceProperties: PFN_vkGetPhysicalDeviceProperties,
+        vkGetPhysicalDeviceFeatures: PFN_vkGetPhysicalDeviceFeatures,
+        vkGetPhysicalDeviceQueueFamilyProperties: PFN_vkGetPhysicalDeviceQueueFamilyProperties,
+        vkGetPhysicalDeviceMemoryProperties: PFN_vkGetPhysicalDeviceMemoryProperties,
+        vkGetPhysicalDeviceFormatProperties: PFN_vkGetPhysicalDeviceFormatProperties,
+        vkGetPhysicalDeviceImageFormatProperties: PFN_vkGetPhysicalDeviceImageFormatProperties,
+        vkEnumerateDeviceExtensionProperties: PFN_vkEnumerateDeviceExtensionProperties,
+        vkEnumerateDeviceLayerProperties: PFN_vkEnumerateDeviceLayerProperties,
+        vkCreateDevice: PFN_vkCreateDevice,
+        vkGetDeviceProcAddr: PFN_vkGetDeviceProcAddr,
+    }
+    optional {
+        vkGetPhysicalDeviceFeatures2: PFN_vkGetPhysicalDeviceFeatures2,
+        vkGetPhysicalDeviceFeatures2KHR: PFN_vkGetPhysicalDeviceFeatures2KHR,
+        vkGetPhysicalDeviceProperties2: PFN_vkGetPhysicalDeviceProperties2,
+        vkGetPhysicalDeviceProperties2KHR: PFN_vkGetPhysicalDeviceProperties2KHR,
+        vkDestroySurfaceKHR: PFN_vkDestroySurfaceKHR,
+        vkGetPhysicalDeviceSurfaceSupportKHR: PFN_vkGetPhysicalDeviceSurfaceSupportKHR,
+        vkGetPhysicalDeviceSurfaceCapabilitiesKHR: PFN_vkGetPhysicalDeviceSurfaceCapabilitiesKHR,
+        vkGetPhysicalDeviceSurfaceFormatsKHR: PFN_vkGetPhysicalDeviceSurfaceFormatsKHR,
+        vkGetPhysicalDeviceSurfacePresentModesKHR: PFN_vkGetPhysicalDeviceSurfacePresentModesKHR,
+        #[cfg(target_os = "windows")]
+        vkCreateWin32SurfaceKHR: PFN_vkCreateWin32SurfaceKHR,
+        #[cfg(feature = "xlib")]
+        vkCreateXlibSurfaceKHR: PFN_vkCreateXlibSurfaceKHR,
+        #[cfg(feature = "xcb")]
+        vkCreateXcbSurfaceKHR: PFN_vkCreateXcbSurfaceKHR,
+        #[cfg(feature = "wayland")]
+        vkCreateWaylandSurfaceKHR: PFN_vkCreateWaylandSurfaceKHR,
+        vkCreateHeadlessSurfaceEXT: PFN_vkCreateHeadlessSurfaceEXT,
+        vkCreateDebugUtilsMessengerEXT: PFN_vkCreateDebugUtilsMessengerEXT,
+        vkDestroyDebugUtilsMessengerEXT: PFN_vkDestroyDebugUtilsMessengerEXT,
+    }
+    state {
+        /// The `apiVersion` the instance was created with, the highest version its physical
+        /// devices may be used at.
+        api_version: ApiVersion = ApiVersion::VERSION_1_0,
+    }
+}
+
+impl InstanceDispatch {
+    /// Resolves the instance level entry points of `instance`, created for `api_version`.
+    pub(crate) fn resolve(
+        get_instance_proc_addr: <PFN_vkGetInstanceProcAddr as ProcAddr>::Fn,
+        instance: VkInstance,
+        api_version: ApiVersion,
+    ) -> std::result::Result<Self, LoaderError> {
+        let mut dispatch = unsafe { Self::load(|name| get_instance_proc_addr(instance, name))? };
+        dispatch.api_version = api_version;
+        Ok(dispatch)
+    }
+}
+
+/*
+   Device Dispatch
+*/
+
+dispatch_table! {
+    pub(crate) struct DeviceDispatch {
+        vkDestroyDevice: PFN_vkDestroyDevice,
+        vkGetDeviceQueue: PFN_vkGetDeviceQueue,
+        vkQueueSubmit: PFN_vkQueueSubmit,
+        vkQueueWaitIdle: PFN_vkQueueWaitIdle,
+        vkAllocateMemory: PFN_vkAllocateMemory,
+        vkFreeMemory: PFN_vkFreeMemory,
+        vkMapMemory: PFN_vkMapMemory,
+        vkUnmapMemory: PFN_vkUnmapMemory,
+        vkFlushMappedMemoryRanges: PFN_vkFlushMappedMemoryRanges,
+        vkInvalidateMappedMemoryRanges: PFN_vkInvalidateMappedMemoryRanges,
+        vkBindBufferMemory: PFN_vkBindBufferMemory,
+        vkBindImageMemory: PFN_vkBindImageMemory,
+        vkGetBufferMemoryRequirements: PFN_vkGetBufferMemoryRequirements,
+        vkGetImageMemoryRequirements: PFN_vkGetImageMemoryRequirements,
+        vkCreateFence: PFN_vkCreateFence,
+        vkDestroyFence: PFN_vkDestroyFence,
+        vkResetFences: PFN_vkResetFences,
+        vkWaitForFences: PFN_vkWaitForFences,
+        vkCreateSemaphore: PFN_vkCreateSemaphore,
+        vkDestroySemaphore: PFN_vkDestroySemaphore,
+        vkCreateEvent: PFN_vkCreateEvent,
+        vkDestroyEvent: PFN_vkDestroyEvent,
+        vkGetEventStatus: PFN_vkGetEventStatus,
+        vkSetEvent: PFN_vkSetEvent,
+        vkResetEvent: PFN_vkResetEvent,
+        vkCreateBuffer: PFN_vkCreateBuffer,
+        vkDestroyBuffer: PFN_vkDestroyBuffer,
+        vkCreateBufferView: PFN_vkCreateBufferView,
+        vkDestroyBufferView: PFN_vkDestroyBufferView,
+        vkCreateImage: PFN_vkCreateImage,
+        vkDestroyImage: PFN_vkDestroyImage,
+        vkCreateImageView: PFN_vkCreateImageView,
+        vkDestroyImageView: PFN_vkDestroyImageView,
+        vkCreateShaderModule: PFN_vkCreateShaderModule,
+        vkDestroyShaderModule: PFN_vkDestroyShaderModule,
+        vkCreatePipelineCache: PFN_vkCreatePipelineCache,
+        vkDestroyPipelineCache: PFN_vkDestroyPipelineCache,
+        vkGetPipelineCacheData: PFN_vkGetPipelineCacheData,
+        vkMergePipelineCaches: PFN_vkMergePipelineCaches,
+        vkCreateGraphicsPipelines: PFN_vkCreateGraphicsPipelines,
+        vkCreateComputePipelines: PFN_vkCreateComputePipelines,
+        vkDestroyPipeline: PFN_vkDestroyPipeline,
+        vkCreatePipelineLayout: PFN_vkCreatePipelineLayout,
+        vkDestroyPipelineLayout: PFN_vkDestroyPipelineLayout,
+        vkCreateSampler: PFN_vkCreateSampler,
+        vkDestroySampler: PFN_vkDestroySampler,
+        vkCreateDescriptorSetLayout: PFN_vkCreateDescriptorSetLayout,
+        vkDestroyDescriptorSetLayout: PFN_vkDestroyDescriptorSetLayout,
+        vkCreateDescriptorPool: PFN_vkCreateDescriptorPool,
+        vkDestroyDescriptorPool: PFN_vkDestroyDescriptorPool,
+        vkAllocateDescriptorSets: PFN_vkAllocateDescriptorSets,
+        vkFreeDescriptorSets: PFN_vkFreeDescriptorSets,
+        vkUpdateDescriptorSets: PFN_vkUpdateDescriptorSets,
+        vkCreateQueryPool: PFN_vkCreateQueryPool,
+        vkDestroyQueryPool: PFN_vkDestroyQueryPool,
+        vkGetQueryPoolResults: PFN_vkGetQueryPoolResults,
+        vkCreateFramebuffer: PFN_vkCreateFramebuffer,
+        vkDestroyFramebuffer: PFN_vkDestroyFramebuffer,
+        vkCreateRenderPass: PFN_vkCreateRenderPass,
+        vkDestroyRenderPass: PFN_vkDestroyRenderPass,
+        vkCreateCommandPool: PFN_vkCreateCommandPool,
+        vkDestroyCommandPool: PFN_vkDestroyCommandPool,
+        vkAllocateCommandBuffers: PFN_vkAllocateCommandBuffers,
+        vkBeginCommandBuffer: PFN_vkBeginCommandBuffer,
+        vkEndCommandBuffer: PFN_vkEndCommandBuffer,
+        vkResetCommandBuffer: PFN_vkResetCommandBuffer,
+        vkCmdBindPipeline: PFN_vkCmdBindPipeline,
+        vkCmdSetViewport: PFN_vkCmdSetViewport,
+        vkCmdSetScissor: PFN_vkCmdSetScissor,
+        vkCmdSetLineWidth: PFN_vkCmdSetLineWidth,
+        vkCmdSetDepthBias: PFN_vkCmdSetDepthBias,
+        vkCmdSetBlendConstants: PFN_vkCmdSetBlendConstants,
+        vkCmdSetDepthBounds: PFN_vkCmdSetDepthBounds,
+        vkCmdSetStencilCompareMask: PFN_vkCmdSetStencilCompareMask,
+        vkCmdSetStencilWriteMask: PFN_vkCmdSetStencilWriteMask,
+        vkCmdSetStencilReference: PFN_vkCmdSetStencilReference,
+        vkCmdBindDescriptorSets: PFN_vkCmdBindDescriptorSets,
+        vkCmdBindIndexBuffer: PFN_vkCmdBindIndexBuffer,
+        vkCmdBindVertexBuffers: PFN_vkCmdBindVertexBuffers,
+        vkCmdDraw: PFN_vkCmdDraw,
+        vkCmdDrawIndexed: PFN_vkCmdDrawIndexed,
+        vkCmdDrawIndirect: PFN_vkCmdDrawIndirect,
+        vkCmdDrawIndexedIndirect: PFN_vkCmdDrawIndexedIndirect,
+        vkCmdDispatch: PFN_vkCmdDispatch,
+        vkCmdDispatchIndirect: PFN_vkCmdDispatchIndirect,
+        vkCmdCopyBuffer: PFN_vkCmdCopyBuffer,
+        vkCmdCopyImage: PFN_vkCmdCopyImage,
+        vkCmdBlitImage: PFN_vkCmdBlitImage,
+        vkCmdCopyBufferToImage: PFN_vkCmdCopyBufferToImage,
+        vkCmdCopyImageToBuffer: PFN_vkCmdCopyImageToBuffer,
+        vkCmdUpdateBuffer: PFN_vkCmdUpdateBuffer,
+        vkCmdFillBuffer: PFN_vkCmdFillBuffer,
+        vkCmdClearColorImage: PFN_vkCmdClearColorImage,
+        vkCmdClearDepthStencilImage: PFN_vkCmdClearDepthStencilImage,
+        vkCmdClearAttachments: PFN_vkCmdClearAttachments,
+        vkCmdResolveImage: PFN_vkCmdResolveImage,
+        vkCmdSetEvent: PFN_vkCmdSetEvent,
+        vkCmdResetEvent: PFN_vkCmdResetEvent,
+        vkCmdWaitEvents: PFN_vkCmdWaitEvents,
+        vkCmdPipelineBarrier: PFN_vkCmdPipelineBarrier,
+        vkCmdBeginQuery: PFN_vkCmdBeginQuery,
+        vkCmdEndQuery: PFN_vkCmdEndQuery,
+        vkCmdResetQueryPool: PFN_vkCmdResetQueryPool,
+        vkCmdWriteTimestamp: PFN_vkCmdWriteTimestamp,
+        vkCmdPushConstants: PFN_vkCmdPushConstants,
+        vkCmdBeginRenderPass: PFN_vkCmdBeginRenderPass,
+        vkCmdNextSubpass: PFN_vkCmdNextSubpass,
+        vkCmdEndRenderPass: PFN_vkCmdEndRenderPass,
+        vkCmdExecuteCommands: PFN_vkCmdExecuteCommands,
+    }
+    optional {
+        vkCreateSwapchainKHR: PFN_vkCreateSwapchainKHR,
+        vkDestroySwapchainKHR: PFN_vkDestroySwapchainKHR,
+        vkGetSwapchainImagesKHR: PFN_vkGetSwapchainImagesKHR,
+        vkAcquireNextImageKHR: PFN_vkAcquireNextImageKHR,
+        vkQueuePresentKHR: PFN_vkQueuePresentKHR,
+        vkSetDebugUtilsObjectNameEXT: PFN_vkSetDebugUtilsObjectNameEXT,
+        vkCmdBeginDebugUtilsLabelEXT: PFN_vkCmdBeginDebugUtilsLabelEXT,
+        vkCmdEndDebugUtilsLabelEXT: PFN_vkCmdEndDebugUtilsLabelEXT,
+        vkCmdInsertDebugUtilsLabelEXT: PFN_vkCmdInsertDebugUtilsLabelEXT,
+        vkCmdBeginRendering: PFN_vkCmdBeginRendering,
+        vkCmdBeginRenderingKHR: PFN_vkCmdBeginRenderingKHR,
+        vkCmdEndRendering: PFN_vkCmdEndRendering,
+        vkCmdEndRenderingKHR: PFN_vkCmdEndRenderingKHR,
+        vkCmdPipelineBarrier2: PFN_vkCmdPipelineBarrier2,
+        vkCmdPipelineBarrier2KHR: PFN_vkCmdPipelineBarrier2KHR,
+        vkCmdSetEvent2: PFN_vkCmdSetEvent2,
+        vkCmdSetEvent2KHR: PFN_vkCmdSetEvent2KHR,
+        vkCmdWaitEvents2: PFN_vkCmdWaitEvents2,
+        vkCmdWaitEvents2KHR: PFN_vkCmdWaitEvents2KHR,
+        vkCmdWriteTimestamp2: PFN_vkCmdWriteTimestamp2,
+        vkCmdWriteTimestamp2KHR: PFN_vkCmdWriteTimestamp2KHR,
+        vkQueueSubmit2: PFN_vkQueueSubmit2,
+        vkQueueSubmit2KHR: PFN_vkQueueSubmit2KHR,
+        vkGetSemaphoreCounterValue: PFN_vkGetSemaphoreCounterValue,
+        vkGetSemaphoreCounterValueKHR: PFN_vkGetSemaphoreCounterValueKHR,
+        vkWaitSemaphores: PFN_vkWaitSemaphores,
+        vkWaitSemaphoresKHR: PFN_vkWaitSemaphoresKHR,
+        vkSignalSemaphore: PFN_vkSignalSemaphore,
+        vkSignalSemaphoreKHR: PFN_vkSignalSemaphoreKHR,
+        vkResetQueryPool: PFN_vkResetQueryPool,
+        vkResetQueryPoolEXT: PFN_vkResetQueryPoolEXT,
+        vkCreateDescriptorUpdateTemplate: PFN_vkCreateDescriptorUpdateTemplate,
+        vkCreateDescriptorUpdateTemplateKHR: PFN_vkCreateDescriptorUpdateTemplateKHR,
+        vkDestroyDescriptorUpdateTemplate: PFN_vkDestroyDescriptorUpdateTemplate,
+        vkDestroyDescriptorUpdateTemplateKHR: PFN_vkDestroyDescriptorUpdateTemplateKHR,
+        vkUpdateDescriptorSetWithTemplate: PFN_vkUpdateDescriptorSetWithTemplate,
+        vkUpdateDescriptorSetWithTemplateKHR: PFN_vkUpdateDescriptorSetWithTemplateKHR,
+        vkCmdPushDescriptorSetKHR: PFN_vkCmdPushDescriptorSetKHR,
+        vkCmdPushDescriptorSetWithTemplateKHR: PFN_vkCmdPushDescriptorSetWithTemplateKHR,
+    }
+}
+
+impl DeviceDispatch {
+    /// Resolves the device level entry points of `device`.
+    pub(crate) fn resolve(
+        get_device_proc_addr: <PFN_vkGetDeviceProcAddr as ProcAddr>::Fn,
+        device: VkDevice,
+    ) -> std::result::Result<Self, LoaderError> {
+        unsafe { Self::load(|name| get_device_proc_addr(device, name)) }
+    }
+}
@@ -8,3 +8,4 @@ use bitflags::bitflags;
 use vulkan_sys::*;
 
 include!(concat!(env!("OUT_DIR"), "/enums.rs"));
+include!(concat!(env!("OUT_DIR"), "/flags64.rs"));
@@ -0,0 +1,29 @@
+// Copyright (c) 2024 Jacob R. Green
+// All rights reserved.
+
+use crate::macros::*;
+use crate::*;
+use vulkan_sys::*;
+
+vulkan_handle!(Event, VkEvent, EVENT);
+
+vulkan_create_info_lifetime!(
+    EventCreateInfo,
+    VkEventCreateInfo,
+    VK_STRUCTURE_TYPE_EVENT_CREATE_INFO
+);
+
+impl<'a> EventCreateInfo<'a> {
+    /// `DEVICE_ONLY` events cannot be set, reset or queried from the host.
+    pub const fn with_flags(mut self, flags: EventCreateFlags) -> Self {
+        self.inner.flags = flags.bits();
+        self
+    }
+}
+
+/// `VK_EVENT_SET` or `VK_EVENT_RESET`, as reported by [`Device::get_event_status`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EventStatus {
+    Set,
+    Reset,
+}
@@ -0,0 +1,178 @@
+// Copyright (c) 2024 Jacob R. Green
+// All rights reserved.
+
+use crate::macros::*;
+use crate::*;
+use vulkan_sys::*;
+
+use std::ffi::CString;
+
+/*
+   Extension Names
+*/
+
+/// `&'static CStr` names of every KHR and EXT extension known to the headers,
+/// e.g. [`extension_names::KHR_SWAPCHAIN`].
+pub mod extension_names {
+    use std::ffi::CStr;
+
+    include!(concat!(env!("OUT_DIR"), "/extension_names.rs"));
+}
+
+/*
+   Extension Properties
+*/
+
+vulkan_struct!(ExtensionProperties, VkExtensionProperties);
+
+impl ExtensionProperties {
+    pub fn extension_name(&self) -> &CStr {
+        unsafe { CStr::from_ptr(self.inner.extensionName.as_ptr()) }
+    }
+
+    pub const fn spec_version(&self) -> u32 {
+        self.inner.specVersion
+    }
+}
+
+/*
+   Layer Properties
+*/
+
+vulkan_struct!(LayerProperties, VkLayerProperties);
+
+impl LayerProperties {
+    pub fn layer_name(&self) -> &CStr {
+        unsafe { CStr::from_ptr(self.inner.layerName.as_ptr()) }
+    }
+
+    pub const fn spec_version(&self) -> ApiVersion {
+        ApiVersion::from_raw(self.inner.specVersion)
+    }
+
+    pub const fn implementation_version(&self) -> u32 {
+        self.inner.implementationVersion
+    }
+
+    pub fn description(&self) -> &CStr {
+        unsafe { CStr::from_ptr(self.inner.description.as_ptr()) }
+    }
+}
+
+/*
+   CStr Array
+*/
+
+/// Names borrowed as an owned array of pointers, the layout `ppEnabled*Names` expects.
+#[derive(Debug, Clone, Default)]
+pub struct CStrArray<'a> {
+    pointers: Vec<*const c_char>,
+    phantom: std::marker::PhantomData<&'a CStr>,
+}
+
+impl<'a> CStrArray<'a> {
+    pub fn new(names: &[&'a CStr]) -> Self {
+        names.iter().copied().collect()
+    }
+
+    pub fn as_ptrs(&self) -> &[*const c_char] {
+        &self.pointers
+    }
+
+    pub fn len(&self) -> usize {
+        self.pointers.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.pointers.is_empty()
+    }
+}
+
+impl<'a> FromIterator<&'a CStr> for CStrArray<'a> {
+    fn from_iter<I: IntoIterator<Item = &'a CStr>>(names: I) -> Self {
+        Self {
+            pointers: names.into_iter().map(CStr::as_ptr).collect(),
+            phantom: std::marker::PhantomData,
+        }
+    }
+}
+
+unsafe impl Send for CStrArray<'_> {}
+unsafe impl Sync for CStrArray<'_> {}
+
+/*
+   Unavailable Error
+*/
+
+/// Requested layers, extensions and features that the implementation does not provide.
+#[derive(Debug, Clone, Default, PartialEq, Eq, thiserror::Error)]
+#[error(
+    "unavailable layers: {layers:?}, unavailable extensions: {extensions:?}, unsupported features: {features:?}"
+)]
+pub struct UnavailableError {
+    pub layers: Vec<CString>,
+    pub extensions: Vec<CString>,
+    pub features: Vec<&'static str>,
+}
+
+impl UnavailableError {
+    /// Collects every name in `requested` that is missing from `available`.
+    pub(crate) fn missing<'a>(
+        requested: &[&CStr],
+        available: impl Iterator<Item = &'a CStr> + Clone,
+    ) -> Vec<CString> {
+        requested
+            .iter()
+            .filter(|name| !available.clone().any(|available| available == **name))
+            .map(|name| (*name).to_owned())
+            .collect()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.layers.is_empty() && self.extensions.is_empty() && self.features.is_empty()
+    }
+}
+
+/// Error of the `check_*_support` and `create*_checked` functions.
+#[derive(Debug, thiserror::Error)]
+pub enum SupportError {
+    #[error(transparent)]
+    Unavailable(UnavailableError),
+    #[error(transparent)]
+    Loader(LoaderError),
+    #[error("vulkan error: {0:?}")]
+    Vulkan(Error),
+}
+
+impl From<UnavailableError> for SupportError {
+    fn from(error: UnavailableError) -> Self {
+        Self::Unavailable(error)
+    }
+}
+
+impl From<LoaderError> for SupportError {
+    fn from(error: LoaderError) -> Self {
+        Self::Loader(error)
+    }
+}
+
+impl From<Error> for SupportError {
+    fn from(error: Error) -> Self {
+        Self::Vulkan(error)
+    }
+}
+
+/// Reads a `ppEnabled*Names` array back into `CStr`s.
+///
+/// # Safety
+/// `names` must point to `count` valid nul terminated strings, or be null when `count` is zero.
+pub(crate) unsafe fn enabled_names<'a>(names: *const *const c_char, count: u32) -> Vec<&'a CStr> {
+    if names.is_null() || count == 0 {
+        return Vec::new();
+    }
+
+    std::slice::from_raw_parts(names, count as usize)
+        .iter()
+        .map(|name| CStr::from_ptr(*name))
+        .collect()
+}
@@ -0,0 +1,1065 @@
+// Copyright (c) 2024 Jacob R. Green
+// All rights reserved.
+
+use crate::macros::*;
+use crate::*;
+use vulkan_sys::*;
+
+/*
+   Feature Set
+*/
+
+/// A struct of `VkBool32` feature flags that can be compared against what a device supports.
+pub trait FeatureSet {
+    /// Every feature by its Vulkan name, along with whether it is enabled.
+    fn features(&self) -> Vec<(&'static str, bool)>;
+
+    /// Names of the features enabled in `self` that are not enabled in `supported`.
+    fn missing_from(&self, supported: &Self) -> Vec<&'static str> {
+        self.features()
+            .into_iter()
+            .zip(supported.features())
+            .filter(|((_, required), (_, supported))| *required && !*supported)
+            .map(|((name, _), _)| name)
+            .collect()
+    }
+}
+
+/// Lists every feature enabled by `create_info`, directly or through a chained
+/// [`PhysicalDeviceFeatures2`] or Vulkan 1.1-1.3 feature struct, that `physical_device` lacks.
+///
+/// Feature structs newer than the lower of the instance's and the device's API versions are not
+/// queried, every feature they enable is reported as missing.
+pub(crate) fn missing_features(
+    physical_device: &PhysicalDevice,
+    create_info: &DeviceCreateInfo,
+) -> Vec<&'static str> {
+    let api_version = physical_device
+        .get_properties()
+        .api_version()
+        .min(physical_device.dispatch().api_version);
+    let create_info = create_info.as_raw();
+    let mut missing = Vec::new();
+
+    if !create_info.pEnabledFeatures.is_null() {
+        let enabled: &PhysicalDeviceFeatures = unsafe { transmute(create_info.pEnabledFeatures) };
+        missing.extend(enabled.missing_from(&physical_device.get_features()));
+    }
+
+    let mut next = create_info.pNext as *const VkBaseInStructure;
+    while let Some(base) = unsafe { next.as_ref() } {
+        match base.sType {
+            VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_FEATURES_2 => {
+                let enabled: &PhysicalDeviceFeatures2 = unsafe { transmute(next) };
+                missing.extend(
+                    enabled
+                        .features()
+                        .missing_from(&physical_device.get_features()),
+                );
+            }
+            // VkPhysicalDeviceVulkan11Features was only added in Vulkan 1.2
+            VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_VULKAN_1_1_FEATURES => {
+                let enabled: &PhysicalDeviceVulkan11Features = unsafe { transmute(next) };
+                let mut supported = PhysicalDeviceVulkan11Features::new();
+                if api_version >= ApiVersion::VERSION_1_2 {
+                    physical_device.get_features2(
+                        &mut PhysicalDeviceFeatures2::new().push_next(&mut supported),
+                    );
+                }
+                missing.extend(enabled.missing_from(&supported));
+            }
+            VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_VULKAN_1_2_FEATURES => {
+                let enabled: &PhysicalDeviceVulkan12Features = unsafe { transmute(next) };
+                let mut supported = PhysicalDeviceVulkan12Features::new();
+                if api_version >= ApiVersion::VERSION_1_2 {
+                    physical_device.get_features2(
+                        &mut PhysicalDeviceFeatures2::new().push_next(&mut supported),
+                    );
+                }
+                missing.extend(enabled.missing_from(&supported));
+            }
+            VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_VULKAN_1_3_FEATURES => {
+                let enabled: &PhysicalDeviceVulkan13Features = unsafe { transmute(next) };
+                let mut supported = PhysicalDeviceVulkan13Features::new();
+                if api_version >= ApiVersion::VERSION_1_3 {
+                    physical_device.get_features2(
+                        &mut PhysicalDeviceFeatures2::new().push_next(&mut supported),
+                    );
+                }
+                missing.extend(enabled.missing_from(&supported));
+            }
+            _ => {}
+        }
+        next = base.pNext;
+    }
+
+    missing
+}
+
+/*
+   Physical Device Features 2
+*/
+
+vulkan_create_info_lifetime!(
+    PhysicalDeviceFeatures2,
+    VkPhysicalDeviceFeatures2,
+    VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_FEATURES_2
+);
+
+impl<'a> PhysicalDeviceFeatures2<'a> {
+    pub const fn features(&self) -> &PhysicalDeviceFeatures {
+        unsafe { transmute(&self.inner.features) }
+    }
+
+    pub const fn with_features(mut self, features: PhysicalDeviceFeatures) -> Self {
+        self.inner.features = *features.as_raw();
+        self
+    }
+}
+
+/*
+   Physical Device Vulkan 1.1 Features
+*/
+
+vulkan_create_info_lifetime!(
+    PhysicalDeviceVulkan11Features,
+    VkPhysicalDeviceVulkan11Features,
+    VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_VULKAN_1_1_FEATURES
+);
+
+impl<'a> PhysicalDeviceVulkan11Features<'a> {
+    pub const fn storage_buffer_16_bit_access(&self) -> bool {
+        self.inner.storageBuffer16BitAccess != 0
+    }
+
+    pub const fn uniform_and_storage_buffer_16_bit_access(&self) -> bool {
+        self.inner.uniformAndStorageBuffer16BitAccess != 0
+    }
+
+    pub const fn storage_push_constant16(&self) -> bool {
+        self.inner.storagePushConstant16 != 0
+    }
+
+    pub const fn storage_input_output16(&self) -> bool {
+        self.inner.storageInputOutput16 != 0
+    }
+
+    pub const fn multiview(&self) -> bool {
+        self.inner.multiview != 0
+    }
+
+    pub const fn multiview_geometry_shader(&self) -> bool {
+        self.inner.multiviewGeometryShader != 0
+    }
+
+    pub const fn multiview_tessellation_shader(&self) -> bool {
+        self.inner.multiviewTessellationShader != 0
+    }
+
+    pub const fn variable_pointers_storage_buffer(&self) -> bool {
+        self.inner.variablePointersStorageBuffer != 0
+    }
+
+    pub const fn variable_pointers(&self) -> bool {
+        self.inner.variablePointers != 0
+    }
+
+    pub const fn protected_memory(&self) -> bool {
+        self.inner.protectedMemory != 0
+    }
+
+    pub const fn sampler_ycbcr_conversion(&self) -> bool {
+        self.inner.samplerYcbcrConversion != 0
+    }
+
+    pub const fn shader_draw_parameters(&self) -> bool {
+        self.inner.shaderDrawParameters != 0
+    }
+
+    pub const fn with_storage_buffer_16_bit_access(
+        mut self,
+        storage_buffer_16_bit_access: bool,
+    ) -> Self {
+        self.inner.storageBuffer16BitAccess = storage_buffer_16_bit_access as VkBool32;
+        self
+    }
+
+    pub const fn with_uniform_and_storage_buffer_16_bit_access(
+        mut self,
+        uniform_and_storage_buffer_16_bit_access: bool,
+    ) -> Self {
+        self.inner.uniformAndStorageBuffer16BitAccess =
+            uniform_and_storage_buffer_16_bit_access as VkBool32;
+        self
+    }
+
+    pub const fn with_storage_push_constant16(mut self, storage_push_constant16: bool) -> Self {
+        self.inner.storagePushConstant16 = storage_push_constant16 as VkBool32;
+        self
+    }
+
+    pub const fn with_storage_input_output16(mut self, storage_input_output16: bool) -> Self {
+        self.inner.storageInputOutput16 = storage_input_output16 as VkBool32;
+        self
+    }
+
+    pub const fn with_multiview(mut self, multiview: bool) -> Self {
+        self.inner.multiview = multiview as VkBool32;
+        self
+    }
+
+    pub const fn with_multiview_geometry_shader(mut self, multiview_geometry_shader: bool) -> Self {
+        self.inner.multiviewGeometryShader = multiview_geometry_shader as VkBool32;
+        self
+    }
+
+    pub const fn with_multiview_tessellation_shader(
+        mut self,
+        multiview_tessellation_shader: bool,
+    ) -> Self {
+        self.inner.multiviewTessellationShader = multiview_tessellation_shader as VkBool32;
+        self
+    }
+
+    pub const fn with_variable_pointers_storage_buffer(
+        mut self,
+        variable_pointers_storage_buffer: bool,
+    ) -> Self {
+        self.inner.variablePointersStorageBuffer = variable_pointers_storage_buffer as VkBool32;
+        self
+    }
+
+    pub const fn with_variable_pointers(mut self, variable_pointers: bool) -> Self {
+        self.inner.variablePointers = variable_pointers as VkBool32;
+        self
+    }
+
+    pub const fn with_protected_memory(mut self, protected_memory: bool) -> Self {
+        self.inner.protectedMemory = protected_memory as VkBool32;
+        self
+    }
+
+    pub const fn with_sampler_ycbcr_conversion(mut self, sampler_ycbcr_conversion: bool) -> Self {
+        self.inner.samplerYcbcrConversion = sampler_ycbcr_conversion as VkBool32;
+        self
+    }
+
+    pub const fn with_shader_draw_parameters(mut self, shader_draw_parameters: bool) -> Self {
+        self.inner.shaderDrawParameters = shader_draw_parameters as VkBool32;
+        self
+    }
+}
+
+vulkan_feature_set!(PhysicalDeviceVulkan11Features<'_> {
+    storageBuffer16BitAccess,
+    uniformAndStorageBuffer16BitAccess,
+    storagePushConstant16,
+    storageInputOutput16,
+    multiview,
+    multiviewGeometryShader,
+    multiviewTessellationShader,
+    variablePointersStorageBuffer,
+    variablePointers,
+    protectedMemory,
+    samplerYcbcrConversion,
+    shaderDrawParameters,
+});
+
+/*
+   Physical Device Vulkan 1.2 Features
+*/
+
+vulkan_create_info_lifetime!(
+    PhysicalDeviceVulkan12Features,
+    VkPhysicalDeviceVulkan12Features,
+    VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_VULKAN_1_2_FEATURES
+);
+
+impl<'a> PhysicalDeviceVulkan12Features<'a> {
+    pub const fn sampler_mirror_clamp_to_edge(&self) -> bool {
+        self.inner.samplerMirrorClampToEdge != 0
+    }
+
+    pub const fn draw_indirect_count(&self) -> bool {
+        self.inner.drawIndirectCount != 0
+    }
+
+    pub const fn storage_buffer_8_bit_access(&self) -> bool {
+        self.inner.storageBuffer8BitAccess != 0
+    }
+
+    pub const fn uniform_and_storage_buffer_8_bit_access(&self) -> bool {
+        self.inner.uniformAndStorageBuffer8BitAccess != 0
+    }
+
+    pub const fn storage_push_constant8(&self) -> bool {
+        self.inner.storagePushConstant8 != 0
+    }
+
+    pub const fn shader_buffer_int64_atomics(&self) -> bool {
+        self.inner.shaderBufferInt64Atomics != 0
+    }
+
+    pub const fn shader_shared_int64_atomics(&self) -> bool {
+        self.inner.shaderSharedInt64Atomics != 0
+    }
+
+    pub const fn shader_float16(&self) -> bool {
+        self.inner.shaderFloat16 != 0
+    }
+
+    pub const fn shader_int8(&self) -> bool {
+        self.inner.shaderInt8 != 0
+    }
+
+    pub const fn descriptor_indexing(&self) -> bool {
+        self.inner.descriptorIndexing != 0
+    }
+
+    pub const fn shader_input_attachment_array_dynamic_indexing(&self) -> bool {
+        self.inner.shaderInputAttachmentArrayDynamicIndexing != 0
+    }
+
+    pub const fn shader_uniform_texel_buffer_array_dynamic_indexing(&self) -> bool {
+        self.inner.shaderUniformTexelBufferArrayDynamicIndexing != 0
+    }
+
+    pub const fn shader_storage_texel_buffer_array_dynamic_indexing(&self) -> bool {
+        self.inner.shaderStorageTexelBufferArrayDynamicIndexing != 0
+    }
+
+    pub const fn shader_uniform_buffer_array_non_uniform_indexing(&self) -> bool {
+        self.inner.shaderUniformBufferArrayNonUniformIndexing != 0
+    }
+
+    pub const fn shader_sampled_image_array_non_uniform_indexing(&self) -> bool {
+        self.inner.shaderSampledImageArrayNonUniformIndexing != 0
+    }
+
+    pub const fn shader_storage_buffer_array_non_uniform_indexing(&self) -> bool {
+        self.inner.shaderStorageBufferArrayNonUniformIndexing != 0
+    }
+
+    pub const fn shader_storage_image_array_non_uniform_indexing(&self) -> bool {
+        self.inner.shaderStorageImageArrayNonUniformIndexing != 0
+    }
+
+    pub const fn shader_input_attachment_array_non_uniform_indexing(&self) -> bool {
+        self.inner.shaderInputAttachmentArrayNonUniformIndexing != 0
+    }
+
+    pub const fn shader_uniform_texel_buffer_array_non_uniform_indexing(&self) -> bool {
+        self.inner.shaderUniformTexelBufferArrayNonUniformIndexing != 0
+    }
+
+    pub const fn shader_storage_texel_buffer_array_non_uniform_indexing(&self) -> bool {
+        self.inner.shaderStorageTexelBufferArrayNonUniformIndexing != 0
+    }
+
+    pub const fn descriptor_binding_uniform_buffer_update_after_bind(&self) -> bool {
+        self.inner.descriptorBindingUniformBufferUpdateAfterBind != 0
+    }
+
+    pub const fn descriptor_binding_sampled_image_update_after_bind(&self) -> bool {
+        self.inner.descriptorBindingSampledImageUpdateAfterBind != 0
+    }
+
+    pub const fn descriptor_binding_storage_image_update_after_bind(&self) -> bool {
+        self.inner.descriptorBindingStorageImageUpdateAfterBind != 0
+    }
+
+    pub const fn descriptor_binding_storage_buffer_update_after_bind(&self) -> bool {
+        self.inner.descriptorBindingStorageBufferUpdateAfterBind != 0
+    }
+
+    pub const fn descriptor_binding_uniform_texel_buffer_update_after_bind(&self) -> bool {
+        self.inner
+            .descriptorBindingUniformTexelBufferUpdateAfterBind
+            != 0
+    }
+
+    pub const fn descriptor_binding_storage_texel_buffer_update_after_bind(&self) -> bool {
+        self.inner
+            .descriptorBindingStorageTexelBufferUpdateAfterBind
+            != 0
+    }
+
+    pub const fn descriptor_binding_update_unused_while_pending(&self) -> bool {
+        self.inner.descriptorBindingUpdateUnusedWhilePending != 0
+    }
+
+    pub const fn descriptor_binding_partially_bound(&self) -> bool {
+        self.inner.descriptorBindingPartiallyBound != 0
+    }
+
+    pub const fn descriptor_binding_variable_descriptor_count(&self) -> bool {
+        self.inner.descriptorBindingVariableDescriptorCount != 0
+    }
+
+    pub const fn runtime_descriptor_array(&self) -> bool {
+        self.inner.runtimeDescriptorArray != 0
+    }
+
+    pub const fn sampler_filter_minmax(&self) -> bool {
+        self.inner.samplerFilterMinmax != 0
+    }
+
+    pub const fn scalar_block_layout(&self) -> bool {
+        self.inner.scalarBlockLayout != 0
+    }
+
+    pub const fn imageless_framebuffer(&self) -> bool {
+        self.inner.imagelessFramebuffer != 0
+    }
+
+    pub const fn uniform_buffer_standard_layout(&self) -> bool {
+        self.inner.uniformBufferStandardLayout != 0
+    }
+
+    pub const fn shader_subgroup_extended_types(&self) -> bool {
+        self.inner.shaderSubgroupExtendedTypes != 0
+    }
+
+    pub const fn separate_depth_stencil_layouts(&self) -> bool {
+        self.inner.separateDepthStencilLayouts != 0
+    }
+
+    pub const fn host_query_reset(&self) -> bool {
+        self.inner.hostQueryReset != 0
+    }
+
+    pub const fn timeline_semaphore(&self) -> bool {
+        self.inner.timelineSemaphore != 0
+    }
+
+    pub const fn buffer_device_address(&self) -> bool {
+        self.inner.bufferDeviceAddress != 0
+    }
+
+    pub const fn buffer_device_address_capture_replay(&self) -> bool {
+        self.inner.bufferDeviceAddressCaptureReplay != 0
+    }
+
+    pub const fn buffer_device_address_multi_device(&self) -> bool {
+        self.inner.bufferDeviceAddressMultiDevice != 0
+    }
+
+    pub const fn vulkan_memory_model(&self) -> bool {
+        self.inner.vulkanMemoryModel != 0
+    }
+
+    pub const fn vulkan_memory_model_device_scope(&self) -> bool {
+        self.inner.vulkanMemoryModelDeviceScope != 0
+    }
+
+    pub const fn vulkan_memory_model_availability_visibility_chains(&self) -> bool {
+        self.inner.vulkanMemoryModelAvailabilityVisibilityChains != 0
+    }
+
+    pub const fn shader_output_viewport_index(&self) -> bool {
+        self.inner.shaderOutputViewportIndex != 0
+    }
+
+    pub const fn shader_output_layer(&self) -> bool {
+        self.inner.shaderOutputLayer != 0
+    }
+
+    pub const fn subgroup_broadcast_dynamic_id(&self) -> bool {
+        self.inner.subgroupBroadcastDynamicId != 0
+    }
+
+    pub const fn with_sampler_mirror_clamp_to_edge(
+        mut self,
+        sampler_mirror_clamp_to_edge: bool,
+    ) -> Self {
+        self.inner.samplerMirrorClampToEdge = sampler_mirror_clamp_to_edge as VkBool32;
+        self
+    }
+
+    pub const fn with_draw_indirect_count(mut self, draw_indirect_count: bool) -> Self {
+        self.inner.drawIndirectCount = draw_indirect_count as VkBool32;
+        self
+    }
+
+    pub const fn with_storage_buffer_8_bit_access(
+        mut self,
+        storage_buffer_8_bit_access: bool,
+    ) -> Self {
+        self.inner.storageBuffer8BitAccess = storage_buffer_8_bit_access as VkBool32;
+        self
+    }
+
+    pub const fn with_uniform_and_storage_buffer_8_bit_access(
+        mut self,
+        uniform_and_storage_buffer_8_bit_access: bool,
+    ) -> Self {
+        self.inner.uniformAndStorageBuffer8BitAccess =
+            uniform_and_storage_buffer_8_bit_access as VkBool32;
+        self
+    }
+
+    pub const fn with_storage_push_constant8(mut self, storage_push_constant8: bool) -> Self {
+        self.inner.storagePushConstant8 = storage_push_constant8 as VkBool32;
+        self
+    }
+
+    pub const fn with_shader_buffer_int64_atomics(
+        mut self,
+        shader_buffer_int64_atomics: bool,
+    ) -> Self {
+        self.inner.shaderBufferInt64Atomics = shader_buffer_int64_atomics as VkBool32;
+        self
+    }
+
+    pub const fn with_shader_shared_int64_atomics(
+        mut self,
+        shader_shared_int64_atomics: bool,
+    ) -> Self {
+        self.inner.shaderSharedInt64Atomics = shader_shared_int64_atomics as VkBool32;
+        self
+    }
+
+    pub const fn with_shader_float16(mut self, shader_float16: bool) -> Self {
+        self.inner.shaderFloat16 = shader_float16 as VkBool32;
+        self
+    }
+
+    pub const fn with_shader_int8(mut self, shader_int8: bool) -> Self {
+        self.inner.shaderInt8 = shader_int8 as VkBool32;
+        self
+    }
+
+    pub const fn with_descriptor_indexing(mut self, descriptor_indexing: bool) -> Self {
+        self.inner.descriptorIndexing = descriptor_indexing as VkBool32;
+        self
+    }
+
+    pub const fn with_shader_input_attachment_array_dynamic_indexing(
+        mut self,
+        shader_input_attachment_array_dynamic_indexing: bool,
+    ) -> Self {
+        self.inner.shaderInputAttachmentArrayDynamicIndexing =
+            shader_input_attachment_array_dynamic_indexing as VkBool32;
+        self
+    }
+
+    pub const fn with_shader_uniform_texel_buffer_array_dynamic_indexing(
+        mut self,
+        shader_uniform_texel_buffer_array_dynamic_indexing: bool,
+    ) -> Self {
+        self.inner.shaderUniformTexelBufferArrayDynamicIndexing =
+            shader_uniform_texel_buffer_array_dynamic_indexing as VkBool32;
+        self
+    }
+
+    pub const fn with_shader_storage_texel_buffer_array_dynamic_indexing(
+        mut self,
+        shader_storage_texel_buffer_array_dynamic_indexing: bool,
+    ) -> Self {
+        self.inner.shaderStorageTexelBufferArrayDynamicIndexing =
+            shader_storage_texel_buffer_array_dynamic_indexing as VkBool32;
+        self
+    }
+
+    pub const fn with_shader_uniform_buffer_array_non_uniform_indexing(
+        mut self,
+        shader_uniform_buffer_array_non_uniform_indexing: bool,
+    ) -> Self {
+        self.inner.shaderUniformBufferArrayNonUniformIndexing =
+            shader_uniform_buffer_array_non_uniform_indexing as VkBool32;
+        self
+    }
+
+    pub const fn with_shader_sampled_image_array_non_uniform_indexing(
+        mut self,
+        shader_sampled_image_array_non_uniform_indexing: bool,
+    ) -> Self {
+        self.inner.shaderSampledImageArrayNonUniformIndexing =
+            shader_sampled_image_array_non_uniform_indexing as VkBool32;
+        self
+    }
+
+    pub const fn with_shader_storage_buffer_array_non_uniform_indexing(
+        mut self,
+        shader_storage_buffer_array_non_uniform_indexing: bool,
+    ) -> Self {
+        self.inner.shaderStorageBufferArrayNonUniformIndexing =
+            shader_storage_buffer_array_non_uniform_indexing as VkBool32;
+        self
+    }
+
+    pub const fn with_shader_storage_image_array_non_uniform_indexing(
+        mut self,
+        shader_storage_image_array_non_uniform_indexing: bool,
+    ) -> Self {
+        self.inner.shaderStorageImageArrayNonUniformIndexing =
+            shader_storage_image_array_non_uniform_indexing as VkBool32;
+        self
+    }
+
+    pub const fn with_shader_input_attachment_array_non_uniform_indexing(
+        mut self,
+        shader_input_attachment_array_non_uniform_indexing: bool,
+    ) -> Self {
+        self.inner.shaderInputAttachmentArrayNonUniformIndexing =
+            shader_input_attachment_array_non_uniform_indexing as VkBool32;
+        self
+    }
+
+    pub const fn with_shader_uniform_texel_buffer_array_non_uniform_indexing(
+        mut self,
+        shader_uniform_texel_buffer_array_non_uniform_indexing: bool,
+    ) -> Self {
+        self.inner.shaderUniformTexelBufferArrayNonUniformIndexing =
+            shader_uniform_texel_buffer_array_non_uniform_indexing as VkBool32;
+        self
+    }
+
+    pub const fn with_shader_storage_texel_buffer_array_non_uniform_indexing(
+        mut self,
+        shader_storage_texel_buffer_array_non_uniform_indexing: bool,
+    ) -> Self {
+        self.inner.shaderStorageTexelBufferArrayNonUniformIndexing =
+            shader_storage_texel_buffer_array_non_uniform_indexing as VkBool32;
+        self
+    }
+
+    pub const fn with_descriptor_binding_uniform_buffer_update_after_bind(
+        mut self,
+        descriptor_binding_uniform_buffer_update_after_bind: bool,
+    ) -> Self {
+        self.inner.descriptorBindingUniformBufferUpdateAfterBind =
+            descriptor_binding_uniform_buffer_update_after_bind as VkBool32;
+        self
+    }
+
+    pub const fn with_descriptor_binding_sampled_image_update_after_bind(
+        mut self,
+        descriptor_binding_sampled_image_update_after_bind: bool,
+    ) -> Self {
+        self.inner.descriptorBindingSampledImageUpdateAfterBind =
+            descriptor_binding_sampled_image_update_after_bind as VkBool32;
+        self
+    }
+
+    pub const fn with_descriptor_binding_storage_image_update_after_bind(
+        mut self,
+        descriptor_binding_storage_image_update_after_bind: bool,
+    ) -> Self {
+        self.inner.descriptorBindingStorageImageUpdateAfterBind =
+            descriptor_binding_storage_image_update_after_bind as VkBool32;
+        self
+    }
+
+    pub const fn with_descriptor_binding_storage_buffer_update_after_bind(
+        mut self,
+        descriptor_binding_storage_buffer_update_after_bind: bool,
+    ) -> Self {
+        self.inner.descriptorBindingStorageBufferUpdateAfterBind =
+            descriptor_binding_storage_buffer_update_after_bind as VkBool32;
+        self
+    }
+
+    pub const fn with_descriptor_binding_uniform_texel_buffer_update_after_bind(
+        mut self,
+        descriptor_binding_uniform_texel_buffer_update_after_bind: bool,
+    ) -> Self {
+        self.inner
+            .descriptorBindingUniformTexelBufferUpdateAfterBind =
+            descriptor_binding_uniform_texel_buffer_update_after_bind as VkBool32;
+        self
+    }
+
+    pub const fn with_descriptor_binding_storage_texel_buffer_update_after_bind(
+        mut self,
+        descriptor_binding_storage_texel_buffer_update_after_bind: bool,
+    ) -> Self {
+        self.inner
+            .descriptorBindingStorageTexelBufferUpdateAfterBind =
+            descriptor_binding_storage_texel_buffer_update_after_bind as VkBool32;
+        self
+    }
+
+    pub const fn with_descriptor_binding_update_unused_while_pending(
+        mut self,
+        descriptor_binding_update_unused_while_pending: bool,
+    ) -> Self {
+        self.inner.descriptorBindingUpdateUnusedWhilePending =
+            descriptor_binding_update_unused_while_pending as VkBool32;
+        self
+    }
+
+    pub const fn with_descriptor_binding_partially_bound(
+        mut self,
+        descriptor_binding_partially_bound: bool,
+    ) -> Self {
+        self.inner.descriptorBindingPartiallyBound = descriptor_binding_partially_bound as VkBool32;
+        self
+    }
+
+    pub const fn with_descriptor_binding_variable_descriptor_count(
+        mut self,
+        descriptor_binding_variable_descriptor_count: bool,
+    ) -> Self {
+        self.inner.descriptorBindingVariableDescriptorCount =
+            descriptor_binding_variable_descriptor_count as VkBool32;
+        self
+    }
+
+    pub const fn with_runtime_descriptor_array(mut self, runtime_descriptor_array: bool) -> Self {
+        self.inner.runtimeDescriptorArray = runtime_descriptor_array as VkBool32;
+        self
+    }
+
+    pub const fn with_sampler_filter_minmax(mut self, sampler_filter_minmax: bool) -> Self {
+        self.inner.samplerFilterMinmax = sampler_filter_minmax as VkBool32;
+        self
+    }
+
+    pub const fn with_scalar_block_layout(mut self, scalar_block_layout: bool) -> Self {
+        self.inner.scalarBlockLayout = scalar_block_layout as VkBool32;
+        self
+    }
+
+    pub const fn with_imageless_framebuffer(mut self, imageless_framebuffer: bool) -> Self {
+        self.inner.imagelessFramebuffer = imageless_framebuffer as VkBool32;
+        self
+    }
+
+    pub const fn with_uniform_buffer_standard_layout(
+        mut self,
+        uniform_buffer_standard_layout: bool,
+    ) -> Self {
+        self.inner.uniformBufferStandardLayout = uniform_buffer_standard_layout as VkBool32;
+        self
+    }
+
+    pub const fn with_shader_subgroup_extended_types(
+        mut self,
+        shader_subgroup_extended_types: bool,
+    ) -> Self {
+        self.inner.shaderSubgroupExtendedTypes = shader_subgroup_extended_types as VkBool32;
+        self
+    }
+
+    pub const fn with_separate_depth_stencil_layouts(
+        mut self,
+        separate_depth_stencil_layouts: bool,
+    ) -> Self {
+        self.inner.separateDepthStencilLayouts = separate_depth_stencil_layouts as VkBool32;
+        self
+    }
+
+    pub const fn with_host_query_reset(mut self, host_query_reset: bool) -> Self {
+        self.inner.hostQueryReset = host_query_reset as VkBool32;
+        self
+    }
+
+    pub const fn with_timeline_semaphore(mut self, timeline_semaphore: bool) -> Self {
+        self.inner.timelineSemaphore = timeline_semaphore as VkBool32;
+        self
+    }
+
+    pub const fn with_buffer_device_address(mut self, buffer_device_address: bool) -> Self {
+        self.inner.bufferDeviceAddress = buffer_device_address as VkBool32;
+        self
+    }
+
+    pub const fn with_buffer_device_address_capture_replay(
+        mut self,
+        buffer_device_address_capture_replay: bool,
+    ) -> Self {
+        self.inner.bufferDeviceAddressCaptureReplay =
+            buffer_device_address_capture_replay as VkBool32;
+        self
+    }
+
+    pub const fn with_buffer_device_address_multi_device(
+        mut self,
+        buffer_device_address_multi_device: bool,
+    ) -> Self {
+        self.inner.bufferDeviceAddressMultiDevice = buffer_device_address_multi_device as VkBool32;
+        self
+    }
+
+    pub const fn with_vulkan_memory_model(mut self, vulkan_memory_model: bool) -> Self {
+        self.inner.vulkanMemoryModel = vulkan_memory_model as VkBool32;
+        self
+    }
+
+    pub const fn with_vulkan_memory_model_device_scope(
+        mut self,
+        vulkan_memory_model_device_scope: bool,
+    ) -> Self {
+        self.inner.vulkanMemoryModelDeviceScope = vulkan_memory_model_device_scope as VkBool32;
+        self
+    }
+
+    pub const fn with_vulkan_memory_model_availability_visibility_chains(
+        mut self,
+        vulkan_memory_model_availability_visibility_chains: bool,
+    ) -> Self {
+        self.inner.vulkanMemoryModelAvailabilityVisibilityChains =
+            vulkan_memory_model_availability_visibility_chains as VkBool32;
+        self
+    }
+
+    pub const fn with_shader_output_viewport_index(
+        mut self,
+        shader_output_viewport_index: bool,
+    ) -> Self {
+        self.inner.shaderOutputViewportIndex = shader_output_viewport_index as VkBool32;
+        self
+    }
+
+    pub const fn with_shader_output_layer(mut self, shader_output_layer: bool) -> Self {
+        self.inner.shaderOutputLayer = shader_output_layer as VkBool32;
+        self
+    }
+
+    pub const fn with_subgroup_broadcast_dynamic_id(
+        mut self,
+        subgroup_broadcast_dynamic_id: bool,
+    ) -> Self {
+        self.inner.subgroupBroadcastDynamicId = subgroup_broadcast_dynamic_id as VkBool32;
+        self
+    }
+}
+
+vulkan_feature_set!(PhysicalDeviceVulkan12Features<'_> {
+    samplerMirrorClampToEdge,
+    drawIndirectCount,
+    storageBuffer8BitAccess,
+    uniformAndStorageBuffer8BitAccess,
+    storagePushConstant8,
+    shaderBufferInt64Atomics,
+    shaderSharedInt64Atomics,
+    shaderFloat16,
+    shaderInt8,
+    descriptorIndexing,
+    shaderInputAttachmentArrayDynamicIndexing,
+    shaderUniformTexelBufferArrayDynamicIndexing,
+    shaderStorageTexelBufferArrayDynamicIndexing,
+    shaderUniformBufferArrayNonUniformIndexing,
+    shaderSampledImageArrayNonUniformIndexing,
+    shaderStorageBufferArrayNonUniformIndexing,
+    shaderStorageImageArrayNonUniformIndexing,
+    shaderInputAttachmentArrayNonUniformIndexing,
+    shaderUniformTexelBufferArrayNonUniformIndexing,
+    shaderStorageTexelBufferArrayNonUniformIndexing,
+    descriptorBindingUniformBufferUpdateAfterBind,
+    descriptorBindingSampledImageUpdateAfterBind,
+    descriptorBindingStorageImageUpdateAfterBind,
+    descriptorBindingStorageBufferUpdateAfterBind,
+    descriptorBindingUpdateUnusedWhilePending,
+    descriptorBindingPartiallyBound,
+    descriptorBindingVariableDescriptorCount,
+    runtimeDescriptorArray,
+    samplerFilterMinmax,
+    scalarBlockLayout,
+    imagelessFramebuffer,
+    uniformBufferStandardLayout,
+    shaderSubgroupExtendedTypes,
+    separateDepthStencilLayouts,
+    hostQueryReset,
+    timelineSemaphore,
+    bufferDeviceAddress,
+    bufferDeviceAddressCaptureReplay,
+    bufferDeviceAddressMultiDevice,
+    vulkanMemoryModel,
+    vulkanMemoryModelDeviceScope,
+    vulkanMemoryModelAvailabilityVisibilityChains,
+    shaderOutputViewportIndex,
+    shaderOutputLayer,
+    subgroupBroadcastDynamicId,
+});
+
+/*
+   Physical Device Vulkan 1.3 Features
+*/
+
+vulkan_create_info_lifetime!(
+    PhysicalDeviceVulkan13Features,
+    VkPhysicalDeviceVulkan13Features,
+    VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_VULKAN_1_3_FEATURES
+);
+
+impl<'a> PhysicalDeviceVulkan13Features<'a> {
+    pub const fn robust_image_access(&self) -> bool {
+        self.inner.robustImageAccess != 0
+    }
+
+    pub const fn inline_uniform_block(&self) -> bool {
+        self.inner.inlineUniformBlock != 0
+    }
+
+    pub const fn descriptor_binding_inline_uniform_block_update_after_bind(&self) -> bool {
+        self.inner
+            .descriptorBindingInlineUniformBlockUpdateAfterBind
+            != 0
+    }
+
+    pub const fn pipeline_creation_cache_control(&self) -> bool {
+        self.inner.pipelineCreationCacheControl != 0
+    }
+
+    pub const fn private_data(&self) -> bool {
+        self.inner.privateData != 0
+    }
+
+    pub const fn shader_demote_to_helper_invocation(&self) -> bool {
+        self.inner.shaderDemoteToHelperInvocation != 0
+    }
+
+    pub const fn shader_terminate_invocation(&self) -> bool {
+        self.inner.shaderTerminateInvocation != 0
+    }
+
+    pub const fn subgroup_size_control(&self) -> bool {
+        self.inner.subgroupSizeControl != 0
+    }
+
+    pub const fn compute_full_subgroups(&self) -> bool {
+        self.inner.computeFullSubgroups != 0
+    }
+
+    pub const fn synchronization2(&self) -> bool {
+        self.inner.synchronization2 != 0
+    }
+
+    pub const fn texture_compression_astc_hdr(&self) -> bool {
+        self.inner.textureCompressionASTC_HDR != 0
+    }
+
+    pub const fn shader_zero_initialize_workgroup_memory(&self) -> bool {
+        self.inner.shaderZeroInitializeWorkgroupMemory != 0
+    }
+
+    pub const fn dynamic_rendering(&self) -> bool {
+        self.inner.dynamicRendering != 0
+    }
+
+    pub const fn shader_integer_dot_product(&self) -> bool {
+        self.inner.shaderIntegerDotProduct != 0
+    }
+
+    pub const fn maintenance4(&self) -> bool {
+        self.inner.maintenance4 != 0
+    }
+
+    pub const fn with_robust_image_access(mut self, robust_image_access: bool) -> Self {
+        self.inner.robustImageAccess = robust_image_access as VkBool32;
+        self
+    }
+
+    pub const fn with_inline_uniform_block(mut self, inline_uniform_block: bool) -> Self {
+        self.inner.inlineUniformBlock = inline_uniform_block as VkBool32;
+        self
+    }
+
+    pub const fn with_descriptor_binding_inline_uniform_block_update_after_bind(
+        mut self,
+        descriptor_binding_inline_uniform_block_update_after_bind: bool,
+    ) -> Self {
+        self.inner
+            .descriptorBindingInlineUniformBlockUpdateAfterBind =
+            descriptor_binding_inline_uniform_block_update_after_bind as VkBool32;
+        self
+    }
+
+    pub const fn with_pipeline_creation_cache_control(
+        mut self,
+        pipeline_creation_cache_control: bool,
+    ) -> Self {
+        self.inner.pipelineCreationCacheControl = pipeline_creation_cache_control as VkBool32;
+        self
+    }
+
+    pub const fn with_private_data(mut self, private_data: bool) -> Self {
+        self.inner.privateData = private_data as VkBool32;
+        self
+    }
+
+    pub const fn with_shader_demote_to_helper_invocation(
+        mut self,
+        shader_demote_to_helper_invocation: bool,
+    ) -> Self {
+        self.inner.shaderDemoteToHelperInvocation = shader_demote_to_helper_invocation as VkBool32;
+        self
+    }
+
+    pub const fn with_shader_terminate_invocation(
+        mut self,
+        shader_terminate_invocation: bool,
+    ) -> Self {
+        self.inner.shaderTerminateInvocation = shader_terminate_invocation as VkBool32;
+        self
+    }
+
+    pub const fn with_subgroup_size_control(mut self, subgroup_size_control: bool) -> Self {
+        self.inner.subgroupSizeControl = subgroup_size_control as VkBool32;
+        self
+    }
+
+    pub const fn with_compute_full_subgroups(mut self, compute_full_subgroups: bool) -> Self {
+        self.inner.computeFullSubgroups = compute_full_subgroups as VkBool32;
+        self
+    }
+
+    pub const fn with_synchronization2(mut self, synchronization2: bool) -> Self {
+        self.inner.synchronization2 = synchronization2 as VkBool32;
+        self
+    }
+
+    pub const fn with_texture_compression_astc_hdr(
+        mut self,
+        texture_compression_astc_hdr: bool,
+    ) -> Self {
+        self.inner.textureCompressionASTC_HDR = texture_compression_astc_hdr as VkBool32;
+        self
+    }
+
+    pub const fn with_shader_zero_initialize_workgroup_memory(
+        mut self,
+        shader_zero_initialize_workgroup_memory: bool,
+    ) -> Self {
+        self.inner.shaderZeroInitializeWorkgroupMemory =
+            shader_zero_initialize_workgroup_memory as VkBool32;
+        self
+    }
+
+    pub const fn with_dynamic_rendering(mut self, dynamic_rendering: bool) -> Self {
+        self.inner.dynamicRendering = dynamic_rendering as VkBool32;
+        self
+    }
+
+    pub const fn with_shader_integer_dot_product(
+        mut self,
+        shader_integer_dot_product: bool,
+    ) -> Self {
+        self.inner.shaderIntegerDotProduct = shader_integer_dot_product as VkBool32;
+        self
+    }
+
+    pub const fn with_maintenance4(mut self, maintenance4: bool) -> Self {
+        self.inner.maintenance4 = maintenance4 as VkBool32;
+        self
+    }
+}
+
+vulkan_feature_set!(PhysicalDeviceVulkan13Features<'_> {
+    robustImageAccess,
+    inlineUniformBlock,
+    pipelineCreationCacheControl,
+    privateData,
+    shaderDemoteToHelperInvocation,
+    shaderTerminateInvocation,
+    subgroupSizeControl,
+    computeFullSubgroups,
+    synchronization2,
+    textureCompressionASTC_HDR,
+    shaderZeroInitializeWorkgroupMemory,
+    dynamicRendering,
+    shaderIntegerDotProduct,
+    maintenance4,
+});
@@ -5,13 +5,13 @@ use crate::macros::*;
 use crate::*;
 use vulkan_sys::*;
 
-vulkan_create_info!(
+vulkan_create_info_lifetime!(
     FenceCreateInfo,
     VkFenceCreateInfo,
     VK_STRUCTURE_TYPE_FENCE_CREATE_INFO
 );
 
-impl FenceCreateInfo {
+impl<'a> FenceCreateInfo<'a> {
     pub const fn with_flags(mut self, flags: FenceCreateFlags) -> Self {
         self.inner.flags = flags.bits();
         self
@@ -20,4 +20,4 @@ impl FenceCreateInfo {
     pub const SIGNALED: Self = Self::new().with_flags(FenceCreateFlags::SIGNALED);
 }
 
-vulkan_handle!(Fence, VkFence);
+vulkan_handle!(Fence, VkFence, FENCE);
@@ -0,0 +1,250 @@
+// Copyright (c) 2024 Jacob R. Green
+// All rights reserved.
+
+use crate::macros::*;
+use crate::*;
+use vulkan_sys::*;
+
+/*
+   Format Properties
+*/
+
+vulkan_struct!(FormatProperties, VkFormatProperties);
+
+impl FormatProperties {
+    pub const fn linear_tiling_features(&self) -> FormatFeatureFlags {
+        FormatFeatureFlags::from_bits_truncate(self.inner.linearTilingFeatures)
+    }
+
+    pub const fn optimal_tiling_features(&self) -> FormatFeatureFlags {
+        FormatFeatureFlags::from_bits_truncate(self.inner.optimalTilingFeatures)
+    }
+
+    pub const fn buffer_features(&self) -> FormatFeatureFlags {
+        FormatFeatureFlags::from_bits_truncate(self.inner.bufferFeatures)
+    }
+
+    /// The features supported by images created with `tiling`.
+    ///
+    /// Tilings other than `LINEAR` and `OPTIMAL` report no features.
+    pub const fn tiling_features(&self, tiling: ImageTiling) -> FormatFeatureFlags {
+        match tiling {
+            ImageTiling::LINEAR => self.linear_tiling_features(),
+            ImageTiling::OPTIMAL => self.optimal_tiling_features(),
+            _ => FormatFeatureFlags::empty(),
+        }
+    }
+}
+
+/*
+   Image Format Properties
+*/
+
+vulkan_struct!(ImageFormatProperties, VkImageFormatProperties);
+
+impl ImageFormatProperties {
+    pub const fn max_extent(&self) -> Extent3D {
+        self.inner.maxExtent
+    }
+
+    pub const fn max_mip_levels(&self) -> u32 {
+        self.inner.maxMipLevels
+    }
+
+    pub const fn max_array_layers(&self) -> u32 {
+        self.inner.maxArrayLayers
+    }
+
+    pub const fn sample_counts(&self) -> SampleCountFlags {
+        SampleCountFlags::from_bits_truncate(self.inner.sampleCounts)
+    }
+
+    pub const fn max_resource_size(&self) -> DeviceSize {
+        self.inner.maxResourceSize
+    }
+}
+
+/*
+   Format Info
+*/
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ComponentName {
+    R,
+    G,
+    B,
+    A,
+    D,
+    S,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NumericFormat {
+    Unorm,
+    Snorm,
+    Uscaled,
+    Sscaled,
+    Uint,
+    Sint,
+    Ufloat,
+    Sfloat,
+    Srgb,
+    Sfixed5,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FormatComponent {
+    name: ComponentName,
+    bits: Option<u8>,
+    numeric_format: NumericFormat,
+}
+
+impl FormatComponent {
+    pub const fn name(&self) -> ComponentName {
+        self.name
+    }
+
+    /// `None` for components of block compressed formats.
+    pub const fn bits(&self) -> Option<u8> {
+        self.bits
+    }
+
+    pub const fn numeric_format(&self) -> NumericFormat {
+        self.numeric_format
+    }
+}
+
+/// Static description of a format's texel blocks, generated from the registry.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FormatInfo {
+    class: &'static str,
+    block_size: u32,
+    block_extent: [u32; 3],
+    texels_per_block: u32,
+    compression: Option<&'static str>,
+    packed: Option<u32>,
+    plane_count: u32,
+    components: &'static [FormatComponent],
+}
+
+impl FormatInfo {
+    /// The compatibility class, e.g. `"32-bit"` or `"BC1_RGB"`.
+    pub const fn class(&self) -> &'static str {
+        self.class
+    }
+
+    /// Size of one texel block in bytes.
+    pub const fn block_size(&self) -> u32 {
+        self.block_size
+    }
+
+    /// Texels covered by one block, `1x1x1` for uncompressed formats.
+    pub const fn block_extent(&self) -> Extent3D {
+        Extent3D {
+            width: self.block_extent[0],
+            height: self.block_extent[1],
+            depth: self.block_extent[2],
+        }
+    }
+
+    pub const fn texels_per_block(&self) -> u32 {
+        self.texels_per_block
+    }
+
+    /// The compression scheme, e.g. `"BC"`, `"ETC2"` or `"ASTC LDR"`.
+    pub const fn compression(&self) -> Option<&'static str> {
+        self.compression
+    }
+
+    pub const fn is_compressed(&self) -> bool {
+        self.compression.is_some()
+    }
+
+    /// Bit width of the packed element for `_PACKn` formats.
+    pub const fn packed(&self) -> Option<u32> {
+        self.packed
+    }
+
+    pub const fn plane_count(&self) -> u32 {
+        self.plane_count
+    }
+
+    /// Components in memory order.
+    pub const fn components(&self) -> &'static [FormatComponent] {
+        self.components
+    }
+
+    pub fn component(&self, name: ComponentName) -> Option<&'static FormatComponent> {
+        self.components
+            .iter()
+            .find(|component| component.name == name)
+    }
+
+    pub fn component_bits(&self, name: ComponentName) -> Option<u8> {
+        self.component(name).and_then(FormatComponent::bits)
+    }
+
+    /// The numeric format shared by every component, `None` for mixed formats such as
+    /// `D24_UNORM_S8_UINT`.
+    pub fn numeric_format(&self) -> Option<NumericFormat> {
+        let first = self.components.first()?.numeric_format;
+        self.components
+            .iter()
+            .all(|component| component.numeric_format == first)
+            .then_some(first)
+    }
+
+    pub fn is_srgb(&self) -> bool {
+        self.components
+            .iter()
+            .any(|component| component.numeric_format == NumericFormat::Srgb)
+    }
+
+    pub fn has_depth(&self) -> bool {
+        self.component(ComponentName::D).is_some()
+    }
+
+    pub fn has_stencil(&self) -> bool {
+        self.component(ComponentName::S).is_some()
+    }
+
+    /// The aspects an image view of the whole format covers.
+    pub fn aspects(&self) -> ImageAspectFlags {
+        let mut aspects = ImageAspectFlags::empty();
+        if self.has_depth() {
+            aspects |= ImageAspectFlags::DEPTH;
+        }
+        if self.has_stencil() {
+            aspects |= ImageAspectFlags::STENCIL;
+        }
+        if aspects.is_empty() {
+            ImageAspectFlags::COLOR
+        } else {
+            aspects
+        }
+    }
+
+    /// Bytes needed to tightly pack `extent` texels, rounding partial blocks up.
+    ///
+    /// Multi-planar formats must be sized per plane instead.
+    pub const fn size_for_extent(&self, extent: Extent3D) -> DeviceSize {
+        let blocks_wide = extent.width.div_ceil(self.block_extent[0]) as DeviceSize;
+        let blocks_high = extent.height.div_ceil(self.block_extent[1]) as DeviceSize;
+        let blocks_deep = extent.depth.div_ceil(self.block_extent[2]) as DeviceSize;
+        blocks_wide * blocks_high * blocks_deep * self.block_size as DeviceSize
+    }
+}
+
+include!(concat!(env!("OUT_DIR"), "/format_info.rs"));
+
+impl Format {
+    /// Shorthand for [`FormatInfo::aspects`], empty for `UNDEFINED`.
+    pub fn aspects(&self) -> ImageAspectFlags {
+        self.info()
+            .map_or(ImageAspectFlags::empty(), FormatInfo::aspects)
+    }
+
+    pub fn is_srgb(&self) -> bool {
+        self.info().is_some_and(FormatInfo::is_srgb)
+    }
+}
@@ -5,7 +5,7 @@ use crate::macros::*;
 use crate::*;
 use vulkan_sys::*;
 
-vulkan_handle!(Framebuffer, VkFramebuffer);
+vulkan_handle!(Framebuffer, VkFramebuffer, FRAMEBUFFER);
 
 vulkan_create_info_lifetime!(
     FramebufferCreateInfo,
@@ -5,7 +5,7 @@ use crate::macros::*;
 use crate::*;
 use vulkan_sys::*;
 
-vulkan_handle!(Image, VkImage);
+vulkan_handle!(Image, VkImage, IMAGE);
 
 vulkan_create_info_lifetime!(
     ImageCreateInfo,
@@ -14,6 +14,11 @@ vulkan_create_info_lifetime!(
 );
 
 impl<'a> ImageCreateInfo<'a> {
+    pub fn with_flags(mut self, flags: ImageCreateFlags) -> Self {
+        self.inner.flags = flags.bits();
+        self
+    }
+
     pub fn with_image_type(mut self, type_: ImageType) -> Self {
         self.inner.imageType = type_.as_raw();
         self
@@ -54,6 +59,18 @@ impl<'a> ImageCreateInfo<'a> {
         self
     }
 
+    pub fn with_exclusive(mut self) -> Self {
+        self.inner.sharingMode = SharingMode::EXCLUSIVE.as_raw();
+        self
+    }
+
+    pub fn with_sharing(mut self, queue_family_indices: &'a [u32]) -> Self {
+        self.inner.sharingMode = SharingMode::CONCURRENT.as_raw();
+        self.inner.queueFamilyIndexCount = queue_family_indices.len() as u32;
+        self.inner.pQueueFamilyIndices = queue_family_indices.as_ptr();
+        self
+    }
+
     pub fn with_initial_layout(mut self, layout: ImageLayout) -> Self {
         self.inner.initialLayout = layout.as_raw();
         self
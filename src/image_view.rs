@@ -5,15 +5,15 @@ use crate::macros::*;
 use crate::*;
 use vulkan_sys::*;
 
-vulkan_handle!(ImageView, VkImageView);
+vulkan_handle!(ImageView, VkImageView, IMAGE_VIEW);
 
-vulkan_create_info!(
+vulkan_create_info_lifetime!(
     ImageViewCreateInfo,
     VkImageViewCreateInfo,
     VK_STRUCTURE_TYPE_IMAGE_VIEW_CREATE_INFO
 );
 
-impl ImageViewCreateInfo {
+impl<'a> ImageViewCreateInfo<'a> {
     pub const fn with_image(mut self, image: Image) -> Self {
         self.inner.image = image.as_raw();
         self
@@ -3,7 +3,6 @@
 
 use crate::*;
 use std::ffi::{c_char, CStr};
-use std::intrinsics::transmute;
 use vulkan_sys::wrapper::*;
 use vulkan_sys::*;
 
@@ -63,56 +62,106 @@ impl<'a> InstanceCreateInfo<'a> {
         self.inner.ppEnabledExtensionNames = extension_names.as_ptr();
         self
     }
+
+    pub fn with_enabled_layer_names(self, layer_names: &'a CStrArray<'a>) -> Self {
+        self.with_enabled_layers(layer_names.as_ptrs())
+    }
+
+    pub fn with_enabled_extension_names(self, extension_names: &'a CStrArray<'a>) -> Self {
+        self.with_enabled_extensions(extension_names.as_ptrs())
+    }
+
+    /// The `apiVersion` of the application info, `VERSION_1_0` without one or when it is zero.
+    pub fn api_version(&self) -> ApiVersion {
+        unsafe { self.inner.pApplicationInfo.as_ref() }
+            .map_or(ApiVersion::VERSION_1_0, |app_info| {
+                ApiVersion::from_raw(app_info.apiVersion)
+            })
+            .max(ApiVersion::VERSION_1_0)
+    }
+
+    pub fn enabled_layer_names(&self) -> Vec<&'a CStr> {
+        unsafe { enabled_names(self.inner.ppEnabledLayerNames, self.inner.enabledLayerCount) }
+    }
+
+    pub fn enabled_extension_names(&self) -> Vec<&'a CStr> {
+        unsafe {
+            enabled_names(
+                self.inner.ppEnabledExtensionNames,
+                self.inner.enabledExtensionCount,
+            )
+        }
+    }
+
+    /// Chains a messenger that captures messages from `vkCreateInstance` and `vkDestroyInstance`.
+    ///
+    /// Replaces the `pNext` chain, use [`InstanceCreateInfo::push_next`] to combine it with other
+    /// structs.
+    pub const fn with_debug_utils_messenger(
+        mut self,
+        messenger_info: &'a DebugUtilsMessengerCreateInfoEXT<'a>,
+    ) -> Self {
+        self.inner.pNext = messenger_info.as_raw() as *const _ as *const _;
+        self
+    }
 }
 
 /*
    Instance
 */
 
-vulkan_handle!(Instance, VkInstance);
+vulkan_dispatchable_handle!(Instance, VkInstance, INSTANCE, dispatch::InstanceDispatch);
 
 impl Instance {
+    /// Creates an instance through [`Entry::global`].
     pub fn create(
         create_info: &InstanceCreateInfo,
         allocator: Option<&AllocationCallbacks>,
-    ) -> vulkan_sys::wrapper::Result<Self> {
-        create_instance(
-            vkCreateInstance,
-            create_info.as_raw(),
-            allocator.map(AllocationCallbacks::as_raw),
-        )
-        .map(Self::from_raw)
+    ) -> std::result::Result<Self, LoaderError> {
+        Entry::global()?.create_instance(create_info, allocator)
+    }
+
+    /// Creates an instance through [`Entry::global`], see [`Entry::create_instance_checked`].
+    pub fn create_checked(
+        create_info: &InstanceCreateInfo,
+        allocator: Option<&AllocationCallbacks>,
+    ) -> std::result::Result<Self, SupportError> {
+        Entry::global()?.create_instance_checked(create_info, allocator)
     }
 
     pub fn destroy(&self, allocator: Option<&AllocationCallbacks>) {
         destroy_instance(
-            vkDestroyInstance,
+            self.dispatch().vkDestroyInstance,
             self.as_raw(),
             allocator.map(AllocationCallbacks::as_raw),
         )
     }
 
     pub fn enumerate_physical_devices(&self) -> vulkan_sys::wrapper::Result<Vec<PhysicalDevice>> {
-        enumerate_physical_devices(vkEnumeratePhysicalDevices, self.as_raw())
-            .map(|devices| unsafe { transmute(devices) })
+        let devices =
+            enumerate_physical_devices(self.dispatch().vkEnumeratePhysicalDevices, self.as_raw())?;
+        Ok(devices
+            .into_iter()
+            .map(|device| PhysicalDevice::from_raw(device, self.dispatch().clone()))
+            .collect())
     }
 
     pub fn get_physical_device_properties(
         &self,
-        physical_device: PhysicalDevice,
+        physical_device: &PhysicalDevice,
     ) -> PhysicalDeviceProperties {
         PhysicalDeviceProperties::from_raw(get_physical_device_properties(
-            vkGetPhysicalDeviceProperties,
+            self.dispatch().vkGetPhysicalDeviceProperties,
             physical_device.as_raw(),
         ))
     }
 
     pub fn get_physical_device_features(
         &self,
-        physical_device: PhysicalDevice,
+        physical_device: &PhysicalDevice,
     ) -> PhysicalDeviceFeatures {
         PhysicalDeviceFeatures::from_raw(get_physical_device_features(
-            vkGetPhysicalDeviceFeatures,
+            self.dispatch().vkGetPhysicalDeviceFeatures,
             physical_device.as_raw(),
         ))
     }
@@ -124,7 +173,9 @@ impl Instance {
         allocator: Option<&AllocationCallbacks>,
     ) -> vulkan_sys::wrapper::Result<SurfaceKHR> {
         create_win32_surface_khr(
-            vkCreateWin32SurfaceKHR,
+            self.dispatch()
+                .vkCreateWin32SurfaceKHR
+                .expect("vkCreateWin32SurfaceKHR is not available"),
             self.as_raw(),
             create_info.as_raw(),
             allocator.map(AllocationCallbacks::as_raw),
@@ -132,13 +183,136 @@ impl Instance {
         .map(SurfaceKHR::from_raw)
     }
 
+    #[cfg(feature = "xlib")]
+    pub fn create_xlib_surface_khr(
+        &self,
+        create_info: &XlibSurfaceCreateInfoKHR,
+        allocator: Option<&AllocationCallbacks>,
+    ) -> vulkan_sys::wrapper::Result<SurfaceKHR> {
+        create_xlib_surface_khr(
+            self.dispatch()
+                .vkCreateXlibSurfaceKHR
+                .expect("vkCreateXlibSurfaceKHR is not available"),
+            self.as_raw(),
+            create_info.as_raw(),
+            allocator.map(AllocationCallbacks::as_raw),
+        )
+        .map(SurfaceKHR::from_raw)
+    }
+
+    #[cfg(feature = "xcb")]
+    pub fn create_xcb_surface_khr(
+        &self,
+        create_info: &XcbSurfaceCreateInfoKHR,
+        allocator: Option<&AllocationCallbacks>,
+    ) -> vulkan_sys::wrapper::Result<SurfaceKHR> {
+        create_xcb_surface_khr(
+            self.dispatch()
+                .vkCreateXcbSurfaceKHR
+                .expect("vkCreateXcbSurfaceKHR is not available"),
+            self.as_raw(),
+            create_info.as_raw(),
+            allocator.map(AllocationCallbacks::as_raw),
+        )
+        .map(SurfaceKHR::from_raw)
+    }
+
+    #[cfg(feature = "wayland")]
+    pub fn create_wayland_surface_khr(
+        &self,
+        create_info: &WaylandSurfaceCreateInfoKHR,
+        allocator: Option<&AllocationCallbacks>,
+    ) -> vulkan_sys::wrapper::Result<SurfaceKHR> {
+        create_wayland_surface_khr(
+            self.dispatch()
+                .vkCreateWaylandSurfaceKHR
+                .expect("vkCreateWaylandSurfaceKHR is not available"),
+            self.as_raw(),
+            create_info.as_raw(),
+            allocator.map(AllocationCallbacks::as_raw),
+        )
+        .map(SurfaceKHR::from_raw)
+    }
+
+    pub fn create_headless_surface_ext(
+        &self,
+        create_info: &HeadlessSurfaceCreateInfoEXT,
+        allocator: Option<&AllocationCallbacks>,
+    ) -> vulkan_sys::wrapper::Result<SurfaceKHR> {
+        create_headless_surface_ext(
+            self.dispatch()
+                .vkCreateHeadlessSurfaceEXT
+                .expect("vkCreateHeadlessSurfaceEXT is not available"),
+            self.as_raw(),
+            create_info.as_raw(),
+            allocator.map(AllocationCallbacks::as_raw),
+        )
+        .map(SurfaceKHR::from_raw)
+    }
+
+    /// Creates a messenger that calls `callback` until it is destroyed with
+    /// [`Instance::destroy_debug_utils_messenger_ext`].
+    ///
+    /// Replaces any callback set on `create_info`.
+    pub fn create_debug_utils_messenger_ext(
+        &self,
+        create_info: &DebugUtilsMessengerCreateInfoEXT,
+        callback: DebugUtilsMessengerCallbackEXT,
+        allocator: Option<&AllocationCallbacks>,
+    ) -> vulkan_sys::wrapper::Result<OwnedDebugUtilsMessengerEXT> {
+        let callback = Box::into_raw(Box::new(callback));
+        let create_info = create_info
+            .clone()
+            .with_user_callback(unsafe { &*callback });
+
+        let result = create_debug_utils_messenger_ext(
+            self.dispatch()
+                .vkCreateDebugUtilsMessengerEXT
+                .expect("vkCreateDebugUtilsMessengerEXT is not available"),
+            self.as_raw(),
+            create_info.as_raw(),
+            allocator.map(AllocationCallbacks::as_raw),
+        );
+
+        match result {
+            Ok(handle) => Ok(OwnedDebugUtilsMessengerEXT::new(
+                DebugUtilsMessengerEXT::from_raw(handle),
+                callback,
+            )),
+            Err(error) => {
+                drop(unsafe { Box::from_raw(callback) });
+                Err(error)
+            }
+        }
+    }
+
+    /// Destroys the messenger, then frees its callback.
+    pub fn destroy_debug_utils_messenger_ext(
+        &self,
+        messenger: OwnedDebugUtilsMessengerEXT,
+        allocator: Option<&AllocationCallbacks>,
+    ) {
+        destroy_debug_utils_messenger_ext(
+            self.dispatch()
+                .vkDestroyDebugUtilsMessengerEXT
+                .expect("vkDestroyDebugUtilsMessengerEXT is not available"),
+            self.as_raw(),
+            messenger.handle().as_raw(),
+            allocator.map(AllocationCallbacks::as_raw),
+        );
+
+        unsafe { messenger.free_callback() }
+    }
+
     pub fn destroy_surface_khr(
         &self,
         surface: SurfaceKHR,
         allocator: Option<&AllocationCallbacks>,
     ) {
         destroy_surface_khr(
-            vkDestroySurfaceKHR,
+            self.dispatch()
+                .vkDestroySurfaceKHR
+                .expect("vkDestroySurfaceKHR is not available"),
             self.as_raw(),
             surface.as_raw(),
             allocator.map(AllocationCallbacks::as_raw),
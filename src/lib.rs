@@ -6,18 +6,42 @@ extern crate static_assertions;
 
 mod macros;
 
+mod dispatch;
+pub use dispatch::ProcAddr;
+
+mod loader;
+pub use loader::*;
+
 mod enums;
 pub use enums::*;
 
 mod instance;
 pub use instance::*;
 
+mod chain;
+pub use chain::*;
+
+mod extension;
+pub use extension::*;
+
 mod physical_device;
 pub use physical_device::*;
 
+mod features;
+pub use features::*;
+
+mod properties;
+pub use properties::*;
+
+mod format;
+pub use format::*;
+
 mod surface;
 pub use surface::*;
 
+mod debug_utils;
+pub use debug_utils::*;
+
 mod device;
 pub use device::*;
 
@@ -27,12 +51,18 @@ pub use queue::*;
 mod swapchain;
 pub use swapchain::*;
 
+mod memory;
+pub use memory::*;
+
 mod buffer;
 pub use buffer::*;
 
 mod image;
 pub use image::*;
 
+mod allocator;
+pub use allocator::*;
+
 mod image_view;
 pub use image_view::*;
 
@@ -45,6 +75,9 @@ pub use render_pass::*;
 mod framebuffer;
 pub use framebuffer::*;
 
+mod query_pool;
+pub use query_pool::*;
+
 mod pipeline;
 pub use pipeline::*;
 
@@ -57,6 +90,9 @@ pub use fence::*;
 mod semaphore;
 pub use semaphore::*;
 
+mod event;
+pub use event::*;
+
 use sys::*;
 pub use vulkan_sys as sys;
 
@@ -92,6 +128,18 @@ pub type ClearDepthStencilValue = VkClearDepthStencilValue;
 
 pub const WHOLE_SIZE: DeviceSize = VK_WHOLE_SIZE as DeviceSize;
 
+/*
+   Handle
+*/
+
+/// Implemented by every Vulkan handle wrapper.
+pub trait Handle {
+    const OBJECT_TYPE: ObjectType;
+
+    /// The raw handle widened to the `uint64_t` used by debug utils and private data.
+    fn object_handle(&self) -> u64;
+}
+
 /*
    Allocation Callbacks
 */
@@ -0,0 +1,428 @@
+// Copyright (c) 2024 Jacob R. Green
+// All rights reserved.
+
+use crate::dispatch::*;
+use crate::*;
+use vulkan_sys::*;
+
+use std::ffi::{OsStr, OsString};
+use std::sync::{Arc, OnceLock};
+
+/*
+   Loader Error
+*/
+
+#[derive(Debug, thiserror::Error)]
+pub enum LoaderError {
+    #[error("failed to load vulkan library {path:?}: {message}")]
+    LibraryNotFound { path: OsString, message: String },
+    #[error("vulkan library does not provide {0}")]
+    MissingEntryPoint(&'static str),
+    #[error("vulkan error: {0:?}")]
+    Vulkan(Error),
+}
+
+impl From<Error> for LoaderError {
+    fn from(error: Error) -> Self {
+        Self::Vulkan(error)
+    }
+}
+
+/*
+   Entry
+*/
+
+/// Environment variable that overrides the library loaded by [`Entry::load`].
+pub const LIBRARY_PATH_ENV: &str = "VULKAN_LIBRARY_PATH";
+
+#[cfg(target_os = "windows")]
+const DEFAULT_LIBRARY_NAMES: &[&str] = &["vulkan-1.dll"];
+#[cfg(target_os = "macos")]
+const DEFAULT_LIBRARY_NAMES: &[&str] = &["libvulkan.1.dylib", "libMoltenVK.dylib"];
+#[cfg(not(any(target_os = "windows", target_os = "macos")))]
+const DEFAULT_LIBRARY_NAMES: &[&str] = &["libvulkan.so.1", "libvulkan.so"];
+
+/// A loaded Vulkan library and its global entry points.
+///
+/// Instances call into the library of the entry they were created from, so entries are
+/// `'static`. The library is never unloaded.
+pub struct Entry {
+    _library: Option<libloading::Library>,
+    pub(crate) get_instance_proc_addr: <PFN_vkGetInstanceProcAddr as ProcAddr>::Fn,
+    pub(crate) dispatch: EntryDispatch,
+}
+
+impl Entry {
+    /// Loads the library named by `VULKAN_LIBRARY_PATH`, falling back to the platform loader.
+    pub fn load() -> std::result::Result<Self, LoaderError> {
+        Self::load_or_default(std::env::var_os(LIBRARY_PATH_ENV))
+    }
+
+    /// Loads `path` when set, otherwise the first platform loader that can be opened.
+    fn load_or_default(path: Option<OsString>) -> std::result::Result<Self, LoaderError> {
+        if let Some(path) = path {
+            return Self::load_from(path);
+        }
+
+        let mut error = None;
+        for name in DEFAULT_LIBRARY_NAMES {
+            match Self::load_from(name) {
+                Ok(entry) => return Ok(entry),
+                Err(err) => error = error.or(Some(err)),
+            }
+        }
+        Err(error.unwrap())
+    }
+
+    /// Loads the library at `path`, e.g. a stub implementation for testing.
+    pub fn load_from(path: impl AsRef<OsStr>) -> std::result::Result<Self, LoaderError> {
+        let path = path.as_ref();
+        let library = unsafe { libloading::Library::new(path) }.map_err(|error| {
+            LoaderError::LibraryNotFound {
+                path: path.to_owned(),
+                message: error.to_string(),
+            }
+        })?;
+
+        let get_instance_proc_addr =
+            unsafe { library.get::<PFN_vkGetInstanceProcAddr>(b"vkGetInstanceProcAddr\0") }
+                .ok()
+                .and_then(|symbol| *symbol)
+                .ok_or(LoaderError::MissingEntryPoint("vkGetInstanceProcAddr"))?;
+
+        let dispatch = EntryDispatch::resolve(get_instance_proc_addr)?;
+
+        Ok(Self {
+            _library: Some(library),
+            get_instance_proc_addr,
+            dispatch,
+        })
+    }
+
+    /// Uses an implementation that is already loaded, e.g. linked statically or provided by a
+    /// test.
+    ///
+    /// # Safety
+    /// `get_instance_proc_addr` must behave like `vkGetInstanceProcAddr` for as long as the entry
+    /// and any object created from it are in use.
+    pub unsafe fn from_get_instance_proc_addr(
+        get_instance_proc_addr: <PFN_vkGetInstanceProcAddr as ProcAddr>::Fn,
+    ) -> std::result::Result<Self, LoaderError> {
+        let dispatch = EntryDispatch::resolve(get_instance_proc_addr)?;
+
+        Ok(Self {
+            _library: None,
+            get_instance_proc_addr,
+            dispatch,
+        })
+    }
+
+    /// The process wide entry used by [`Instance::create`], loaded on first use.
+    pub fn global() -> std::result::Result<&'static Self, LoaderError> {
+        static ENTRY: OnceLock<Entry> = OnceLock::new();
+
+        if let Some(entry) = ENTRY.get() {
+            return Ok(entry);
+        }
+
+        let entry = Self::load()?;
+        Ok(ENTRY.get_or_init(|| entry))
+    }
+
+    /// Leaks `self` so instances can be created from it.
+    pub fn leak(self) -> &'static Self {
+        Box::leak(Box::new(self))
+    }
+
+    pub fn create_instance(
+        &'static self,
+        create_info: &InstanceCreateInfo,
+        allocator: Option<&AllocationCallbacks>,
+    ) -> std::result::Result<Instance, LoaderError> {
+        let instance = create_instance(
+            self.dispatch.vkCreateInstance,
+            create_info.as_raw(),
+            allocator.map(AllocationCallbacks::as_raw),
+        )?;
+
+        let dispatch = match InstanceDispatch::resolve(
+            self.get_instance_proc_addr,
+            instance,
+            create_info.api_version(),
+        ) {
+            Ok(dispatch) => dispatch,
+            Err(error) => {
+                // the instance is unusable without its dispatch table
+                let destroy: PFN_vkDestroyInstance = unsafe {
+                    transmute((self.get_instance_proc_addr)(
+                        instance,
+                        c"vkDestroyInstance".as_ptr(),
+                    ))
+                };
+                if let Some(destroy) = destroy {
+                    destroy_instance(
+                        destroy,
+                        instance,
+                        allocator.map(AllocationCallbacks::as_raw),
+                    );
+                }
+                return Err(error);
+            }
+        };
+
+        Ok(Instance::from_raw(instance, Arc::new(dispatch)))
+    }
+
+    pub fn enumerate_instance_extension_properties(
+        &self,
+        layer_name: Option<&CStr>,
+    ) -> vulkan_sys::wrapper::Result<Vec<ExtensionProperties>> {
+        enumerate_instance_extension_properties(
+            self.dispatch.vkEnumerateInstanceExtensionProperties,
+            layer_name,
+        )
+        .map(|properties| unsafe { transmute(properties) })
+    }
+
+    pub fn enumerate_instance_layer_properties(
+        &self,
+    ) -> vulkan_sys::wrapper::Result<Vec<LayerProperties>> {
+        enumerate_instance_layer_properties(self.dispatch.vkEnumerateInstanceLayerProperties)
+            .map(|properties| unsafe { transmute(properties) })
+    }
+
+    /// Vulkan 1.0 loaders do not export `vkEnumerateInstanceVersion` and report 1.0.
+    pub fn enumerate_instance_version(&self) -> vulkan_sys::wrapper::Result<ApiVersion> {
+        match self.dispatch.vkEnumerateInstanceVersion {
+            Some(function) => enumerate_instance_version(function).map(ApiVersion::from_raw),
+            None => Ok(ApiVersion::VERSION_1_0),
+        }
+    }
+
+    /// Reports every requested layer and extension that is unavailable.
+    ///
+    /// Extensions provided by one of the requested layers count as available.
+    pub fn check_instance_support(
+        &self,
+        layer_names: &[&CStr],
+        extension_names: &[&CStr],
+    ) -> std::result::Result<(), SupportError> {
+        let layers = self.enumerate_instance_layer_properties()?;
+
+        let mut extensions = self.enumerate_instance_extension_properties(None)?;
+        for layer_name in layer_names {
+            if layers.iter().any(|layer| layer.layer_name() == *layer_name) {
+                extensions.extend(self.enumerate_instance_extension_properties(Some(layer_name))?);
+            }
+        }
+
+        let error = UnavailableError {
+            layers: UnavailableError::missing(
+                layer_names,
+                layers.iter().map(LayerProperties::layer_name),
+            ),
+            extensions: UnavailableError::missing(
+                extension_names,
+                extensions.iter().map(ExtensionProperties::extension_name),
+            ),
+            features: Vec::new(),
+        };
+
+        if error.is_empty() {
+            Ok(())
+        } else {
+            Err(error.into())
+        }
+    }
+
+    /// Like [`Entry::create_instance`], but fails with [`SupportError::Unavailable`] listing every
+    /// requested layer and extension the implementation does not provide.
+    pub fn create_instance_checked(
+        &'static self,
+        create_info: &InstanceCreateInfo,
+        allocator: Option<&AllocationCallbacks>,
+    ) -> std::result::Result<Instance, SupportError> {
+        self.check_instance_support(
+            &create_info.enabled_layer_names(),
+            &create_info.enabled_extension_names(),
+        )?;
+        Ok(self.create_instance(create_info, allocator)?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    /*
+       Stub Implementation
+    */
+
+    // Handles are deliberately not pointers, so the loader must never read through them.
+    const INSTANCE: usize = 0x1000;
+    const PHYSICAL_DEVICE: usize = 0x2000;
+    const DEVICE: usize = 0x3000;
+    const QUEUE: usize = 0x4000;
+
+    static DESTROYED_INSTANCES: AtomicUsize = AtomicUsize::new(0);
+    static DESTROYED_DEVICES: AtomicUsize = AtomicUsize::new(0);
+
+    /// Stands in for every entry point the tests do not call.
+    unsafe extern "C" fn not_implemented() {
+        std::process::abort()
+    }
+
+    unsafe extern "C" fn create_instance(
+        _create_info: *const VkInstanceCreateInfo,
+        _allocator: *const VkAllocationCallbacks,
+        instance: *mut VkInstance,
+    ) -> VkResult {
+        *instance = INSTANCE as VkInstance;
+        VK_SUCCESS
+    }
+
+    unsafe extern "C" fn destroy_instance(
+        instance: VkInstance,
+        _allocator: *const VkAllocationCallbacks,
+    ) {
+        assert_eq!(instance as usize, INSTANCE);
+        DESTROYED_INSTANCES.fetch_add(1, Ordering::SeqCst);
+    }
+
+    unsafe extern "C" fn enumerate_physical_devices(
+        instance: VkInstance,
+        count: *mut u32,
+        physical_devices: *mut VkPhysicalDevice,
+    ) -> VkResult {
+        assert_eq!(instance as usize, INSTANCE);
+        if !physical_devices.is_null() && *count > 0 {
+            *physical_devices = PHYSICAL_DEVICE as VkPhysicalDevice;
+        }
+        *count = 1;
+        VK_SUCCESS
+    }
+
+    unsafe extern "C" fn create_device(
+        physical_device: VkPhysicalDevice,
+        _create_info: *const VkDeviceCreateInfo,
+        _allocator: *const VkAllocationCallbacks,
+        device: *mut VkDevice,
+    ) -> VkResult {
+        assert_eq!(physical_device as usize, PHYSICAL_DEVICE);
+        *device = DEVICE as VkDevice;
+        VK_SUCCESS
+    }
+
+    unsafe extern "C" fn destroy_device(
+        device: VkDevice,
+        _allocator: *const VkAllocationCallbacks,
+    ) {
+        assert_eq!(device as usize, DEVICE);
+        DESTROYED_DEVICES.fetch_add(1, Ordering::SeqCst);
+    }
+
+    unsafe extern "C" fn get_device_queue(
+        device: VkDevice,
+        _queue_family_index: u32,
+        _queue_index: u32,
+        queue: *mut VkQueue,
+    ) {
+        assert_eq!(device as usize, DEVICE);
+        *queue = QUEUE as VkQueue;
+    }
+
+    unsafe extern "C" fn get_device_proc_addr(
+        device: VkDevice,
+        name: *const c_char,
+    ) -> PFN_vkVoidFunction {
+        assert_eq!(device as usize, DEVICE);
+        let function = match CStr::from_ptr(name).to_bytes() {
+            b"vkDestroyDevice" => destroy_device as *const (),
+            b"vkGetDeviceQueue" => get_device_queue as *const (),
+            _ => not_implemented as *const (),
+        };
+        transmute(function)
+    }
+
+    unsafe extern "C" fn get_instance_proc_addr(
+        instance: VkInstance,
+        name: *const c_char,
+    ) -> PFN_vkVoidFunction {
+        assert!(instance.is_null() || instance as usize == INSTANCE);
+        let function = match CStr::from_ptr(name).to_bytes() {
+            b"vkCreateInstance" => create_instance as *const (),
+            b"vkDestroyInstance" => destroy_instance as *const (),
+            b"vkEnumeratePhysicalDevices" => enumerate_physical_devices as *const (),
+            b"vkCreateDevice" => create_device as *const (),
+            b"vkGetDeviceProcAddr" => get_device_proc_addr as *const (),
+            // reported as a Vulkan 1.0 loader
+            b"vkEnumerateInstanceVersion" => return None,
+            _ => not_implemented as *const (),
+        };
+        transmute(function)
+    }
+
+    /*
+       Tests
+    */
+
+    #[test]
+    fn missing_library_is_reported() {
+        let result = Entry::load_from("/nonexistent/libvulkan.so.1");
+        assert!(matches!(
+            result,
+            Err(LoaderError::LibraryNotFound { path, .. }) if path == "/nonexistent/libvulkan.so.1"
+        ));
+    }
+
+    #[test]
+    fn library_path_override_replaces_the_defaults() {
+        let result = Entry::load_or_default(Some("/nonexistent/libvulkan-stub.so".into()));
+        assert!(matches!(
+            result,
+            Err(LoaderError::LibraryNotFound { path, .. }) if path == "/nonexistent/libvulkan-stub.so"
+        ));
+    }
+
+    #[test]
+    fn resolves_entry_points_from_get_instance_proc_addr() {
+        let entry = unsafe { Entry::from_get_instance_proc_addr(get_instance_proc_addr) }
+            .unwrap()
+            .leak();
+        assert!(entry.enumerate_instance_version().unwrap() == ApiVersion::VERSION_1_0);
+
+        let instance = entry
+            .create_instance(&InstanceCreateInfo::new(), None)
+            .unwrap();
+        assert_eq!(instance.as_raw() as usize, INSTANCE);
+        assert!(instance.dispatch().api_version == ApiVersion::VERSION_1_0);
+
+        let physical_devices = instance.enumerate_physical_devices().unwrap();
+        assert_eq!(physical_devices.len(), 1);
+        assert_eq!(physical_devices[0].as_raw() as usize, PHYSICAL_DEVICE);
+
+        let device = Device::create(&physical_devices[0], &DeviceCreateInfo::new(), None).unwrap();
+        assert_eq!(device.as_raw() as usize, DEVICE);
+
+        let queue = device.get_device_queue(0, 0);
+        assert_eq!(queue.as_raw() as usize, QUEUE);
+
+        device.destroy(None);
+        assert_eq!(DESTROYED_DEVICES.load(Ordering::SeqCst), 1);
+
+        instance.destroy(None);
+        assert_eq!(DESTROYED_INSTANCES.load(Ordering::SeqCst), 1);
+    }
+
+    #[test]
+    fn instance_api_version_defaults_to_1_0() {
+        let app_info = ApplicationInfo::new();
+        let create_info = InstanceCreateInfo::new().with_application_info(&app_info);
+        assert!(create_info.api_version() == ApiVersion::VERSION_1_0);
+
+        let app_info = ApplicationInfo::new().with_api_version(ApiVersion::VERSION_1_3);
+        let create_info = InstanceCreateInfo::new().with_application_info(&app_info);
+        assert!(create_info.api_version() == ApiVersion::VERSION_1_3);
+    }
+}
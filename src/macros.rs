@@ -2,7 +2,7 @@
 // All rights reserved.
 
 macro_rules! vulkan_handle {
-    ($name:tt, $ty:tt) => {
+    ($name:tt, $ty:tt, $object_type:ident) => {
         #[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
         pub struct $name {
             handle: $ty,
@@ -50,6 +50,14 @@ macro_rules! vulkan_handle {
             }
         }
 
+        impl crate::Handle for $name {
+            const OBJECT_TYPE: crate::ObjectType = crate::ObjectType::$object_type;
+
+            fn object_handle(&self) -> u64 {
+                self.handle as u64
+            }
+        }
+
         unsafe impl Sync for $name {}
         unsafe impl Send for $name {}
 
@@ -57,6 +65,71 @@ macro_rules! vulkan_handle {
     };
 }
 
+/// Like `vulkan_handle!`, for dispatchable handles that carry the dispatch table of the instance
+/// or device they belong to.
+macro_rules! vulkan_dispatchable_handle {
+    ($name:tt, $ty:tt, $object_type:ident, $dispatch:ty) => {
+        #[derive(Clone)]
+        pub struct $name {
+            handle: $ty,
+            dispatch: std::sync::Arc<$dispatch>,
+        }
+
+        impl $name {
+            pub(crate) fn from_raw(handle: $ty, dispatch: std::sync::Arc<$dispatch>) -> Self {
+                Self { handle, dispatch }
+            }
+
+            pub const fn as_raw(&self) -> $ty {
+                self.handle
+            }
+
+            pub(crate) fn dispatch(&self) -> &std::sync::Arc<$dispatch> {
+                &self.dispatch
+            }
+        }
+
+        impl PartialEq for $name {
+            fn eq(&self, other: &Self) -> bool {
+                self.handle == other.handle
+            }
+        }
+
+        impl Eq for $name {}
+
+        impl std::hash::Hash for $name {
+            fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+                self.handle.hash(state)
+            }
+        }
+
+        impl std::fmt::Debug for $name {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.debug_struct(stringify!($name))
+                    .field("handle", &self.handle)
+                    .finish()
+            }
+        }
+
+        impl std::fmt::Display for $name {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                write!(f, "{:?}", self)
+            }
+        }
+
+        impl crate::Handle for $name {
+            const OBJECT_TYPE: crate::ObjectType = crate::ObjectType::$object_type;
+
+            fn object_handle(&self) -> u64 {
+                self.handle as u64
+            }
+        }
+
+        unsafe impl Sync for $name {}
+        unsafe impl Send for $name {}
+    };
+}
+
 macro_rules! vulkan_create_info_lifetime {
     ($name:tt, $ty:tt, $stype:expr) => {
         #[derive(Clone)]
@@ -79,6 +152,11 @@ macro_rules! vulkan_create_info_lifetime {
             pub const fn as_raw(&self) -> &$ty {
                 &self.inner
             }
+
+            #[allow(unused)]
+            pub(crate) fn as_raw_mut(&mut self) -> &mut $ty {
+                &mut self.inner
+            }
         }
 
         // impl From<$ty> for $name<'_> {
@@ -96,12 +174,36 @@ macro_rules! vulkan_create_info_lifetime {
         //     }
         // }
 
+        impl<'a> $name<'a> {
+            /// Appends `next`, along with any chain already attached to it, to the `pNext` chain.
+            pub fn push_next<T>(mut self, next: &'a mut T) -> Self
+            where
+                T: crate::RawStruct,
+                T::Raw: crate::Extends<$ty>,
+            {
+                unsafe {
+                    let next = next as *mut T as *mut vulkan_sys::VkBaseOutStructure;
+                    let mut last = next;
+                    while !(*last).pNext.is_null() {
+                        last = (*last).pNext;
+                    }
+                    (*last).pNext = self.inner.pNext as *mut _;
+                    self.inner.pNext = next.cast();
+                }
+                self
+            }
+        }
+
         impl std::fmt::Debug for $name<'_> {
             fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
                 self.inner.fmt(f)
             }
         }
 
+        unsafe impl crate::RawStruct for $name<'_> {
+            type Raw = $ty;
+        }
+
         unsafe impl Send for $name<'_> {}
         unsafe impl Sync for $name<'_> {}
 
@@ -109,50 +211,13 @@ macro_rules! vulkan_create_info_lifetime {
     };
 }
 
-macro_rules! vulkan_create_info {
-    ($name:tt, $ty:tt, $stype:expr) => {
-        #[derive(Clone)]
-        pub struct $name {
-            inner: $ty,
-        }
-
-        impl $name {
-            pub const fn new() -> Self {
-                Self {
-                    inner: $ty {
-                        sType: $stype,
-                        ..unsafe { std::mem::zeroed() }
-                    },
-                }
-            }
-
-            pub const fn as_raw(&self) -> &$ty {
-                &self.inner
-            }
-        }
-
-        // impl From<$ty> for $name {
-        //     fn from(inner: $ty) -> Self {
-        //         Self { inner }
-        //     }
-        // }
-        //
-        // impl AsRef<$ty> for $name {
-        //     fn as_ref(&self) -> &$ty {
-        //         &self.inner
-        //     }
-        // }
-
-        impl std::fmt::Debug for $name {
-            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                self.inner.fmt(f)
+macro_rules! vulkan_feature_set {
+    ($name:ty { $($field:ident,)* }) => {
+        impl crate::FeatureSet for $name {
+            fn features(&self) -> Vec<(&'static str, bool)> {
+                vec![$((stringify!($field), self.inner.$field != 0),)*]
             }
         }
-
-        unsafe impl Send for $name {}
-        unsafe impl Sync for $name {}
-
-        assert_eq_size!($name, $ty);
     };
 }
 
@@ -282,8 +347,9 @@ macro_rules! vulkan_struct_lifetime {
     };
 }
 
-pub(crate) use vulkan_create_info;
 pub(crate) use vulkan_create_info_lifetime;
+pub(crate) use vulkan_dispatchable_handle;
+pub(crate) use vulkan_feature_set;
 pub(crate) use vulkan_handle;
 pub(crate) use vulkan_struct;
 pub(crate) use vulkan_struct_custom;
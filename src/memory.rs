@@ -0,0 +1,98 @@
+// Copyright (c) 2024 Jacob R. Green
+// All rights reserved.
+
+use crate::macros::*;
+use crate::*;
+use vulkan_sys::*;
+
+/*
+   Device Memory
+*/
+
+vulkan_handle!(DeviceMemory, VkDeviceMemory, DEVICE_MEMORY);
+
+/*
+   Memory Allocate Info
+*/
+
+vulkan_create_info_lifetime!(
+    MemoryAllocateInfo,
+    VkMemoryAllocateInfo,
+    VK_STRUCTURE_TYPE_MEMORY_ALLOCATE_INFO
+);
+
+impl<'a> MemoryAllocateInfo<'a> {
+    pub const fn new_init(allocation_size: DeviceSize, memory_type_index: u32) -> Self {
+        Self::new()
+            .with_allocation_size(allocation_size)
+            .with_memory_type_index(memory_type_index)
+    }
+
+    pub const fn with_allocation_size(mut self, allocation_size: DeviceSize) -> Self {
+        self.inner.allocationSize = allocation_size;
+        self
+    }
+
+    pub const fn with_memory_type_index(mut self, memory_type_index: u32) -> Self {
+        self.inner.memoryTypeIndex = memory_type_index;
+        self
+    }
+}
+
+/*
+   Mapped Memory Range
+*/
+
+vulkan_create_info_lifetime!(
+    MappedMemoryRange,
+    VkMappedMemoryRange,
+    VK_STRUCTURE_TYPE_MAPPED_MEMORY_RANGE
+);
+
+impl<'a> MappedMemoryRange<'a> {
+    pub const fn new_init(memory: DeviceMemory, offset: DeviceSize, size: DeviceSize) -> Self {
+        Self::new()
+            .with_memory(memory)
+            .with_offset(offset)
+            .with_size(size)
+    }
+
+    pub const fn with_memory(mut self, memory: DeviceMemory) -> Self {
+        self.inner.memory = memory.as_raw();
+        self
+    }
+
+    pub const fn with_offset(mut self, offset: DeviceSize) -> Self {
+        self.inner.offset = offset;
+        self
+    }
+
+    pub const fn with_size(mut self, size: DeviceSize) -> Self {
+        self.inner.size = size;
+        self
+    }
+}
+
+/*
+   Memory Requirements
+*/
+
+vulkan_struct!(MemoryRequirements, VkMemoryRequirements);
+
+impl MemoryRequirements {
+    pub const fn size(&self) -> DeviceSize {
+        self.inner.size
+    }
+
+    pub const fn alignment(&self) -> DeviceSize {
+        self.inner.alignment
+    }
+
+    pub const fn memory_type_bits(&self) -> u32 {
+        self.inner.memoryTypeBits
+    }
+
+    pub const fn supports_memory_type(&self, memory_type_index: u32) -> bool {
+        memory_type_index < u32::BITS && self.inner.memoryTypeBits & (1 << memory_type_index) != 0
+    }
+}
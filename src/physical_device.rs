@@ -14,13 +14,18 @@ use vulkan_sys::wrapper as vk_wrap;
    Physical Device
 */
 
-vulkan_handle!(PhysicalDevice, VkPhysicalDevice);
+vulkan_dispatchable_handle!(
+    PhysicalDevice,
+    VkPhysicalDevice,
+    PHYSICAL_DEVICE,
+    dispatch::InstanceDispatch
+);
 
 impl PhysicalDevice {
     pub fn get_properties(&self) -> PhysicalDeviceProperties {
         unsafe {
             transmute(vk_wrap::get_physical_device_properties(
-                vkGetPhysicalDeviceProperties,
+                self.dispatch().vkGetPhysicalDeviceProperties,
                 self.as_raw(),
             ))
         }
@@ -29,37 +34,173 @@ impl PhysicalDevice {
     pub fn get_features(&self) -> PhysicalDeviceFeatures {
         unsafe {
             transmute(vk_wrap::get_physical_device_features(
-                vkGetPhysicalDeviceFeatures,
+                self.dispatch().vkGetPhysicalDeviceFeatures,
                 self.as_raw(),
             ))
         }
     }
 
+    /// Fills `features` and every struct chained to it with [`PhysicalDeviceFeatures2::push_next`].
+    ///
+    /// Requires Vulkan 1.1 or `VK_KHR_get_physical_device_properties2`.
+    pub fn get_features2(&self, features: &mut PhysicalDeviceFeatures2) {
+        let dispatch = self.dispatch();
+        vk_wrap::get_physical_device_features2(
+            dispatch
+                .vkGetPhysicalDeviceFeatures2
+                .or(dispatch.vkGetPhysicalDeviceFeatures2KHR)
+                .expect("vkGetPhysicalDeviceFeatures2 is not available"),
+            self.as_raw(),
+            features.as_raw_mut(),
+        )
+    }
+
+    /// Fills `properties` and every struct chained to it with
+    /// [`PhysicalDeviceProperties2::push_next`].
+    ///
+    /// Requires Vulkan 1.1 or `VK_KHR_get_physical_device_properties2`.
+    pub fn get_properties2(&self, properties: &mut PhysicalDeviceProperties2) {
+        let dispatch = self.dispatch();
+        vk_wrap::get_physical_device_properties2(
+            dispatch
+                .vkGetPhysicalDeviceProperties2
+                .or(dispatch.vkGetPhysicalDeviceProperties2KHR)
+                .expect("vkGetPhysicalDeviceProperties2 is not available"),
+            self.as_raw(),
+            properties.as_raw_mut(),
+        )
+    }
+
     pub fn get_queue_family_properties(&self) -> Vec<QueueFamilyProperties> {
         unsafe {
             transmute(vk_wrap::get_physical_device_queue_family_properties(
-                vkGetPhysicalDeviceQueueFamilyProperties,
+                self.dispatch().vkGetPhysicalDeviceQueueFamilyProperties,
+                self.as_raw(),
+            ))
+        }
+    }
+
+    pub fn enumerate_device_extension_properties(
+        &self,
+        layer_name: Option<&CStr>,
+    ) -> vk_wrap::Result<Vec<ExtensionProperties>> {
+        unsafe {
+            transmute(vk_wrap::enumerate_device_extension_properties(
+                self.dispatch().vkEnumerateDeviceExtensionProperties,
+                self.as_raw(),
+                layer_name,
+            ))
+        }
+    }
+
+    pub fn enumerate_device_layer_properties(&self) -> vk_wrap::Result<Vec<LayerProperties>> {
+        unsafe {
+            transmute(vk_wrap::enumerate_device_layer_properties(
+                self.dispatch().vkEnumerateDeviceLayerProperties,
                 self.as_raw(),
             ))
         }
     }
 
+    /// Reports every extension and feature requested by `create_info` that is unavailable.
+    pub fn check_device_support(
+        &self,
+        create_info: &DeviceCreateInfo,
+    ) -> std::result::Result<(), SupportError> {
+        let extensions = self.enumerate_device_extension_properties(None)?;
+
+        let error = UnavailableError {
+            layers: Vec::new(),
+            extensions: UnavailableError::missing(
+                &create_info.enabled_extension_names(),
+                extensions.iter().map(ExtensionProperties::extension_name),
+            ),
+            features: missing_features(self, create_info),
+        };
+
+        if error.is_empty() {
+            Ok(())
+        } else {
+            Err(error.into())
+        }
+    }
+
+    /// Finds a queue family supporting `required`, preferring families with the fewest other
+    /// capabilities so that e.g. compute work lands on a dedicated compute queue when one exists.
+    pub fn find_queue_family_index(&self, required: QueueFlags) -> Option<u32> {
+        self.get_queue_family_properties()
+            .iter()
+            .enumerate()
+            .filter(|(_, family)| family.queue_count() > 0)
+            .filter(|(_, family)| family.queue_flags().contains(required))
+            .min_by_key(|(_, family)| family.queue_flags().bits().count_ones())
+            .map(|(index, _)| index as u32)
+    }
+
     pub fn get_memory_properties(&self) -> PhysicalDeviceMemoryProperties {
         unsafe {
             transmute(vk_wrap::get_physical_device_memory_properties(
-                vkGetPhysicalDeviceMemoryProperties,
+                self.dispatch().vkGetPhysicalDeviceMemoryProperties,
+                self.as_raw(),
+            ))
+        }
+    }
+
+    pub fn get_format_properties(&self, format: Format) -> FormatProperties {
+        unsafe {
+            transmute(vk_wrap::get_physical_device_format_properties(
+                self.dispatch().vkGetPhysicalDeviceFormatProperties,
                 self.as_raw(),
+                format.as_raw(),
             ))
         }
     }
 
+    pub fn get_image_format_properties(
+        &self,
+        format: Format,
+        image_type: ImageType,
+        tiling: ImageTiling,
+        usage: ImageUsageFlags,
+        flags: ImageCreateFlags,
+    ) -> vk_wrap::Result<ImageFormatProperties> {
+        unsafe {
+            transmute(vk_wrap::get_physical_device_image_format_properties(
+                self.dispatch().vkGetPhysicalDeviceImageFormatProperties,
+                self.as_raw(),
+                format.as_raw(),
+                image_type.as_raw(),
+                tiling.as_raw(),
+                usage.bits(),
+                flags.bits(),
+            ))
+        }
+    }
+
+    /// Returns the first of `candidates` supporting `features` with `tiling`, e.g.
+    /// `[Format::D32_SFLOAT, Format::D24_UNORM_S8_UINT]` for a depth attachment.
+    pub fn find_supported_format(
+        &self,
+        candidates: &[Format],
+        tiling: ImageTiling,
+        features: FormatFeatureFlags,
+    ) -> Option<Format> {
+        candidates.iter().copied().find(|format| {
+            self.get_format_properties(*format)
+                .tiling_features(tiling)
+                .contains(features)
+        })
+    }
+
     pub fn get_surface_support(
         &self,
         queue_family_index: u32,
         surface: SurfaceKHR,
     ) -> vk_wrap::Result<bool> {
         vk_wrap::get_physical_device_surface_support_khr(
-            vkGetPhysicalDeviceSurfaceSupportKHR,
+            self.dispatch()
+                .vkGetPhysicalDeviceSurfaceSupportKHR
+                .expect("vkGetPhysicalDeviceSurfaceSupportKHR is not available"),
             self.as_raw(),
             queue_family_index,
             surface.as_raw(),
@@ -72,7 +213,9 @@ impl PhysicalDevice {
     ) -> vk_wrap::Result<SurfaceCapabilitiesKHR> {
         unsafe {
             transmute(vk_wrap::get_physical_device_surface_capabilities_khr(
-                vkGetPhysicalDeviceSurfaceCapabilitiesKHR,
+                self.dispatch()
+                    .vkGetPhysicalDeviceSurfaceCapabilitiesKHR
+                    .expect("vkGetPhysicalDeviceSurfaceCapabilitiesKHR is not available"),
                 self.as_raw(),
                 surface.as_raw(),
             ))
@@ -85,7 +228,9 @@ impl PhysicalDevice {
     ) -> vk_wrap::Result<Vec<SurfaceFormatKHR>> {
         unsafe {
             transmute(vk_wrap::get_physical_device_surface_formats_khr(
-                vkGetPhysicalDeviceSurfaceFormatsKHR,
+                self.dispatch()
+                    .vkGetPhysicalDeviceSurfaceFormatsKHR
+                    .expect("vkGetPhysicalDeviceSurfaceFormatsKHR is not available"),
                 self.as_raw(),
                 surface.as_raw(),
             ))
@@ -98,7 +243,9 @@ impl PhysicalDevice {
     ) -> vk_wrap::Result<Vec<PresentModeKHR>> {
         unsafe {
             transmute(vk_wrap::get_physical_device_surface_present_modes_khr(
-                vkGetPhysicalDeviceSurfacePresentModesKHR,
+                self.dispatch()
+                    .vkGetPhysicalDeviceSurfacePresentModesKHR
+                    .expect("vkGetPhysicalDeviceSurfacePresentModesKHR is not available"),
                 self.as_raw(),
                 surface.as_raw(),
             ))
@@ -137,6 +284,10 @@ impl PhysicalDeviceProperties {
         unsafe { CStr::from_ptr(self.inner.deviceName.as_ptr()) }
     }
 
+    pub const fn pipeline_cache_uuid(&self) -> &[u8; VK_UUID_SIZE as usize] {
+        &self.inner.pipelineCacheUUID
+    }
+
     pub const fn limits(&self) -> &PhysicalDeviceLimits {
         unsafe { transmute(&self.inner.limits) }
     }
@@ -390,8 +541,403 @@ impl PhysicalDeviceFeatures {
     pub const fn inherited_queries(&self) -> bool {
         self.inner.inheritedQueries != 0
     }
+
+    pub const fn with_robust_buffer_access(mut self, robust_buffer_access: bool) -> Self {
+        self.inner.robustBufferAccess = robust_buffer_access as VkBool32;
+        self
+    }
+
+    pub const fn with_full_draw_index_uint32(mut self, full_draw_index_uint32: bool) -> Self {
+        self.inner.fullDrawIndexUint32 = full_draw_index_uint32 as VkBool32;
+        self
+    }
+
+    pub const fn with_image_cube_array(mut self, image_cube_array: bool) -> Self {
+        self.inner.imageCubeArray = image_cube_array as VkBool32;
+        self
+    }
+
+    pub const fn with_independent_blend(mut self, independent_blend: bool) -> Self {
+        self.inner.independentBlend = independent_blend as VkBool32;
+        self
+    }
+
+    pub const fn with_geometry_shader(mut self, geometry_shader: bool) -> Self {
+        self.inner.geometryShader = geometry_shader as VkBool32;
+        self
+    }
+
+    pub const fn with_tessellation_shader(mut self, tessellation_shader: bool) -> Self {
+        self.inner.tessellationShader = tessellation_shader as VkBool32;
+        self
+    }
+
+    pub const fn with_sample_rate_shading(mut self, sample_rate_shading: bool) -> Self {
+        self.inner.sampleRateShading = sample_rate_shading as VkBool32;
+        self
+    }
+
+    pub const fn with_dual_src_blend(mut self, dual_src_blend: bool) -> Self {
+        self.inner.dualSrcBlend = dual_src_blend as VkBool32;
+        self
+    }
+
+    pub const fn with_logic_op(mut self, logic_op: bool) -> Self {
+        self.inner.logicOp = logic_op as VkBool32;
+        self
+    }
+
+    pub const fn with_multi_draw_indirect(mut self, multi_draw_indirect: bool) -> Self {
+        self.inner.multiDrawIndirect = multi_draw_indirect as VkBool32;
+        self
+    }
+
+    pub const fn with_draw_indirect_first_instance(
+        mut self,
+        draw_indirect_first_instance: bool,
+    ) -> Self {
+        self.inner.drawIndirectFirstInstance = draw_indirect_first_instance as VkBool32;
+        self
+    }
+
+    pub const fn with_depth_clamp(mut self, depth_clamp: bool) -> Self {
+        self.inner.depthClamp = depth_clamp as VkBool32;
+        self
+    }
+
+    pub const fn with_depth_bias_clamp(mut self, depth_bias_clamp: bool) -> Self {
+        self.inner.depthBiasClamp = depth_bias_clamp as VkBool32;
+        self
+    }
+
+    pub const fn with_fill_mode_non_solid(mut self, fill_mode_non_solid: bool) -> Self {
+        self.inner.fillModeNonSolid = fill_mode_non_solid as VkBool32;
+        self
+    }
+
+    pub const fn with_depth_bounds(mut self, depth_bounds: bool) -> Self {
+        self.inner.depthBounds = depth_bounds as VkBool32;
+        self
+    }
+
+    pub const fn with_wide_lines(mut self, wide_lines: bool) -> Self {
+        self.inner.wideLines = wide_lines as VkBool32;
+        self
+    }
+
+    pub const fn with_large_points(mut self, large_points: bool) -> Self {
+        self.inner.largePoints = large_points as VkBool32;
+        self
+    }
+
+    pub const fn with_alpha_to_one(mut self, alpha_to_one: bool) -> Self {
+        self.inner.alphaToOne = alpha_to_one as VkBool32;
+        self
+    }
+
+    pub const fn with_multi_viewport(mut self, multi_viewport: bool) -> Self {
+        self.inner.multiViewport = multi_viewport as VkBool32;
+        self
+    }
+
+    pub const fn with_sampler_anisotropy(mut self, sampler_anisotropy: bool) -> Self {
+        self.inner.samplerAnisotropy = sampler_anisotropy as VkBool32;
+        self
+    }
+
+    pub const fn with_texture_compression_etc2(mut self, texture_compression_etc2: bool) -> Self {
+        self.inner.textureCompressionETC2 = texture_compression_etc2 as VkBool32;
+        self
+    }
+
+    pub const fn with_texture_compression_astc_ldr(
+        mut self,
+        texture_compression_astc_ldr: bool,
+    ) -> Self {
+        self.inner.textureCompressionASTC_LDR = texture_compression_astc_ldr as VkBool32;
+        self
+    }
+
+    pub const fn with_texture_compression_bc(mut self, texture_compression_bc: bool) -> Self {
+        self.inner.textureCompressionBC = texture_compression_bc as VkBool32;
+        self
+    }
+
+    pub const fn with_occlusion_query_precise(mut self, occlusion_query_precise: bool) -> Self {
+        self.inner.occlusionQueryPrecise = occlusion_query_precise as VkBool32;
+        self
+    }
+
+    pub const fn with_pipeline_statistics_query(mut self, pipeline_statistics_query: bool) -> Self {
+        self.inner.pipelineStatisticsQuery = pipeline_statistics_query as VkBool32;
+        self
+    }
+
+    pub const fn with_vertex_pipeline_stores_and_atomics(
+        mut self,
+        vertex_pipeline_stores_and_atomics: bool,
+    ) -> Self {
+        self.inner.vertexPipelineStoresAndAtomics = vertex_pipeline_stores_and_atomics as VkBool32;
+        self
+    }
+
+    pub const fn with_fragment_stores_and_atomics(
+        mut self,
+        fragment_stores_and_atomics: bool,
+    ) -> Self {
+        self.inner.fragmentStoresAndAtomics = fragment_stores_and_atomics as VkBool32;
+        self
+    }
+
+    pub const fn with_shader_tessellation_and_geometry_point_size(
+        mut self,
+        shader_tessellation_and_geometry_point_size: bool,
+    ) -> Self {
+        self.inner.shaderTessellationAndGeometryPointSize =
+            shader_tessellation_and_geometry_point_size as VkBool32;
+        self
+    }
+
+    pub const fn with_shader_image_gather_extended(
+        mut self,
+        shader_image_gather_extended: bool,
+    ) -> Self {
+        self.inner.shaderImageGatherExtended = shader_image_gather_extended as VkBool32;
+        self
+    }
+
+    pub const fn with_shader_storage_image_extended_formats(
+        mut self,
+        shader_storage_image_extended_formats: bool,
+    ) -> Self {
+        self.inner.shaderStorageImageExtendedFormats =
+            shader_storage_image_extended_formats as VkBool32;
+        self
+    }
+
+    pub const fn with_shader_storage_image_multisample(
+        mut self,
+        shader_storage_image_multisample: bool,
+    ) -> Self {
+        self.inner.shaderStorageImageMultisample = shader_storage_image_multisample as VkBool32;
+        self
+    }
+
+    pub const fn with_shader_storage_image_read_without_format(
+        mut self,
+        shader_storage_image_read_without_format: bool,
+    ) -> Self {
+        self.inner.shaderStorageImageReadWithoutFormat =
+            shader_storage_image_read_without_format as VkBool32;
+        self
+    }
+
+    pub const fn with_shader_storage_image_write_without_format(
+        mut self,
+        shader_storage_image_write_without_format: bool,
+    ) -> Self {
+        self.inner.shaderStorageImageWriteWithoutFormat =
+            shader_storage_image_write_without_format as VkBool32;
+        self
+    }
+
+    pub const fn with_shader_uniform_buffer_array_dynamic_indexing(
+        mut self,
+        shader_uniform_buffer_array_dynamic_indexing: bool,
+    ) -> Self {
+        self.inner.shaderUniformBufferArrayDynamicIndexing =
+            shader_uniform_buffer_array_dynamic_indexing as VkBool32;
+        self
+    }
+
+    pub const fn with_shader_sampled_image_array_dynamic_indexing(
+        mut self,
+        shader_sampled_image_array_dynamic_indexing: bool,
+    ) -> Self {
+        self.inner.shaderSampledImageArrayDynamicIndexing =
+            shader_sampled_image_array_dynamic_indexing as VkBool32;
+        self
+    }
+
+    pub const fn with_shader_storage_buffer_array_dynamic_indexing(
+        mut self,
+        shader_storage_buffer_array_dynamic_indexing: bool,
+    ) -> Self {
+        self.inner.shaderStorageBufferArrayDynamicIndexing =
+            shader_storage_buffer_array_dynamic_indexing as VkBool32;
+        self
+    }
+
+    pub const fn with_shader_storage_image_array_dynamic_indexing(
+        mut self,
+        shader_storage_image_array_dynamic_indexing: bool,
+    ) -> Self {
+        self.inner.shaderStorageImageArrayDynamicIndexing =
+            shader_storage_image_array_dynamic_indexing as VkBool32;
+        self
+    }
+
+    pub const fn with_shader_clip_distance(mut self, shader_clip_distance: bool) -> Self {
+        self.inner.shaderClipDistance = shader_clip_distance as VkBool32;
+        self
+    }
+
+    pub const fn with_shader_cull_distance(mut self, shader_cull_distance: bool) -> Self {
+        self.inner.shaderCullDistance = shader_cull_distance as VkBool32;
+        self
+    }
+
+    pub const fn with_shader_float64(mut self, shader_float64: bool) -> Self {
+        self.inner.shaderFloat64 = shader_float64 as VkBool32;
+        self
+    }
+
+    pub const fn with_shader_int64(mut self, shader_int64: bool) -> Self {
+        self.inner.shaderInt64 = shader_int64 as VkBool32;
+        self
+    }
+
+    pub const fn with_shader_int16(mut self, shader_int16: bool) -> Self {
+        self.inner.shaderInt16 = shader_int16 as VkBool32;
+        self
+    }
+
+    pub const fn with_shader_resource_residency(mut self, shader_resource_residency: bool) -> Self {
+        self.inner.shaderResourceResidency = shader_resource_residency as VkBool32;
+        self
+    }
+
+    pub const fn with_shader_resource_min_lod(mut self, shader_resource_min_lod: bool) -> Self {
+        self.inner.shaderResourceMinLod = shader_resource_min_lod as VkBool32;
+        self
+    }
+
+    pub const fn with_sparse_binding(mut self, sparse_binding: bool) -> Self {
+        self.inner.sparseBinding = sparse_binding as VkBool32;
+        self
+    }
+
+    pub const fn with_sparse_residency_buffer(mut self, sparse_residency_buffer: bool) -> Self {
+        self.inner.sparseResidencyBuffer = sparse_residency_buffer as VkBool32;
+        self
+    }
+
+    pub const fn with_sparse_residency_image_2d(mut self, sparse_residency_image_2d: bool) -> Self {
+        self.inner.sparseResidencyImage2D = sparse_residency_image_2d as VkBool32;
+        self
+    }
+
+    pub const fn with_sparse_residency_image_3d(mut self, sparse_residency_image_3d: bool) -> Self {
+        self.inner.sparseResidencyImage3D = sparse_residency_image_3d as VkBool32;
+        self
+    }
+
+    pub const fn with_sparse_residency_2_samples(
+        mut self,
+        sparse_residency_2_samples: bool,
+    ) -> Self {
+        self.inner.sparseResidency2Samples = sparse_residency_2_samples as VkBool32;
+        self
+    }
+
+    pub const fn with_sparse_residency_4_samples(
+        mut self,
+        sparse_residency_4_samples: bool,
+    ) -> Self {
+        self.inner.sparseResidency4Samples = sparse_residency_4_samples as VkBool32;
+        self
+    }
+
+    pub const fn with_sparse_residency_8_samples(
+        mut self,
+        sparse_residency_8_samples: bool,
+    ) -> Self {
+        self.inner.sparseResidency8Samples = sparse_residency_8_samples as VkBool32;
+        self
+    }
+
+    pub const fn with_sparse_residency_16_samples(
+        mut self,
+        sparse_residency_16_samples: bool,
+    ) -> Self {
+        self.inner.sparseResidency16Samples = sparse_residency_16_samples as VkBool32;
+        self
+    }
+
+    pub const fn with_sparse_residency_aliased(mut self, sparse_residency_aliased: bool) -> Self {
+        self.inner.sparseResidencyAliased = sparse_residency_aliased as VkBool32;
+        self
+    }
+
+    pub const fn with_variable_multisample_rate(mut self, variable_multisample_rate: bool) -> Self {
+        self.inner.variableMultisampleRate = variable_multisample_rate as VkBool32;
+        self
+    }
+
+    pub const fn with_inherited_queries(mut self, inherited_queries: bool) -> Self {
+        self.inner.inheritedQueries = inherited_queries as VkBool32;
+        self
+    }
 }
 
+vulkan_feature_set!(PhysicalDeviceFeatures {
+    robustBufferAccess,
+    fullDrawIndexUint32,
+    imageCubeArray,
+    independentBlend,
+    geometryShader,
+    tessellationShader,
+    sampleRateShading,
+    dualSrcBlend,
+    logicOp,
+    multiDrawIndirect,
+    drawIndirectFirstInstance,
+    depthClamp,
+    depthBiasClamp,
+    fillModeNonSolid,
+    depthBounds,
+    wideLines,
+    largePoints,
+    alphaToOne,
+    multiViewport,
+    samplerAnisotropy,
+    textureCompressionETC2,
+    textureCompressionASTC_LDR,
+    textureCompressionBC,
+    occlusionQueryPrecise,
+    pipelineStatisticsQuery,
+    vertexPipelineStoresAndAtomics,
+    fragmentStoresAndAtomics,
+    shaderTessellationAndGeometryPointSize,
+    shaderImageGatherExtended,
+    shaderStorageImageExtendedFormats,
+    shaderStorageImageMultisample,
+    shaderStorageImageReadWithoutFormat,
+    shaderStorageImageWriteWithoutFormat,
+    shaderUniformBufferArrayDynamicIndexing,
+    shaderSampledImageArrayDynamicIndexing,
+    shaderStorageBufferArrayDynamicIndexing,
+    shaderStorageImageArrayDynamicIndexing,
+    shaderClipDistance,
+    shaderCullDistance,
+    shaderFloat64,
+    shaderInt64,
+    shaderInt16,
+    shaderResourceResidency,
+    shaderResourceMinLod,
+    sparseBinding,
+    sparseResidencyBuffer,
+    sparseResidencyImage2D,
+    sparseResidencyImage3D,
+    sparseResidency2Samples,
+    sparseResidency4Samples,
+    sparseResidency8Samples,
+    sparseResidency16Samples,
+    sparseResidencyAliased,
+    variableMultisampleRate,
+    inheritedQueries,
+});
+
 /*
    Queue Family Properties
 */
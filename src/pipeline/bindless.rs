@@ -0,0 +1,387 @@
+// Copyright (c) 2024 Jacob R. Green
+// All rights reserved.
+
+use crate::*;
+
+/*
+   Bindless Table
+*/
+
+/// One large, partially bound descriptor array that hands out indices for shaders to use.
+///
+/// The table owns a single-binding layout, an update-after-bind pool and the one set allocated
+/// from it. Requires Vulkan 1.2 or `VK_EXT_descriptor_indexing` with the matching
+/// `descriptor_binding_*_update_after_bind`, `descriptor_binding_update_unused_while_pending`,
+/// `descriptor_binding_partially_bound` and `descriptor_binding_variable_descriptor_count`
+/// features enabled.
+pub struct BindlessTable {
+    device: Device,
+    layout: DescriptorSetLayout,
+    pool: DescriptorPool,
+    descriptor_set: DescriptorSet,
+    descriptor_type: DescriptorType,
+    binding: u32,
+    indices: IndexAllocator,
+}
+
+impl BindlessTable {
+    pub fn new(
+        device: Device,
+        binding: u32,
+        descriptor_type: DescriptorType,
+        capacity: u32,
+        stage_flags: ShaderStageFlags,
+    ) -> Result<Self> {
+        let bindings = [DescriptorSetLayoutBinding::new_init(
+            binding,
+            descriptor_type,
+            capacity,
+            stage_flags,
+        )];
+        let binding_flags = [DescriptorBindingFlags::UPDATE_AFTER_BIND
+            | DescriptorBindingFlags::UPDATE_UNUSED_WHILE_PENDING
+            | DescriptorBindingFlags::PARTIALLY_BOUND
+            | DescriptorBindingFlags::VARIABLE_DESCRIPTOR_COUNT];
+        let mut binding_flags_info =
+            DescriptorSetLayoutBindingFlagsCreateInfo::new().with_binding_flags(&binding_flags);
+
+        let layout = device.create_descriptor_set_layout(
+            &DescriptorSetLayoutCreateInfo::new()
+                .with_flags(DescriptorSetLayoutCreateFlags::UPDATE_AFTER_BIND_POOL)
+                .with_bindings(&bindings)
+                .push_next(&mut binding_flags_info),
+            None,
+        )?;
+
+        let pool_sizes = [DescriptorPoolSize::new(descriptor_type, capacity)];
+        let pool = match device.create_descriptor_pool(
+            &DescriptorPoolCreateInfo::new()
+                .with_flags(DescriptorPoolCreateFlags::UPDATE_AFTER_BIND)
+                .with_max_sets(1)
+                .with_pool_sizes(&pool_sizes),
+            None,
+        ) {
+            Ok(pool) => pool,
+            Err(error) => {
+                device.destroy_descriptor_set_layout(layout, None);
+                return Err(error);
+            }
+        };
+
+        let set_layouts = [layout];
+        let descriptor_counts = [capacity];
+        let mut variable_count_info = DescriptorSetVariableDescriptorCountAllocateInfo::new()
+            .with_descriptor_counts(&descriptor_counts);
+
+        let descriptor_set = match device.allocate_descriptor_sets(
+            &DescriptorSetAllocateInfo::new()
+                .with_descriptor_pool(pool)
+                .with_set_layouts(&set_layouts)
+                .push_next(&mut variable_count_info),
+        ) {
+            Ok(sets) => sets[0],
+            Err(error) => {
+                device.destroy_descriptor_pool(pool, None);
+                device.destroy_descriptor_set_layout(layout, None);
+                return Err(error);
+            }
+        };
+
+        Ok(Self {
+            device,
+            layout,
+            pool,
+            descriptor_set,
+            descriptor_type,
+            binding,
+            indices: IndexAllocator::new(capacity),
+        })
+    }
+
+    /// Destroys the pool and layout; the descriptor set is freed with the pool.
+    pub fn destroy(self) {
+        self.device.destroy_descriptor_pool(self.pool, None);
+        self.device.destroy_descriptor_set_layout(self.layout, None);
+    }
+
+    pub const fn layout(&self) -> DescriptorSetLayout {
+        self.layout
+    }
+
+    pub const fn descriptor_set(&self) -> DescriptorSet {
+        self.descriptor_set
+    }
+
+    pub const fn descriptor_type(&self) -> DescriptorType {
+        self.descriptor_type
+    }
+
+    pub const fn capacity(&self) -> u32 {
+        self.indices.capacity()
+    }
+
+    /// Number of indices currently handed out.
+    pub fn len(&self) -> u32 {
+        self.indices.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Reserves an index without writing a descriptor, recycling removed indices first.
+    pub fn allocate_index(&mut self) -> Option<u32> {
+        self.indices.allocate()
+    }
+
+    /// Returns `index` to the table, `false` if it is not currently handed out.
+    ///
+    /// The descriptor is left in place, so `index` must no longer be used by any pending command
+    /// buffer once it is handed out again.
+    pub fn remove(&mut self, index: u32) -> bool {
+        self.indices.free(index)
+    }
+
+    /// Panics unless the table holds image descriptors and `index` is handed out.
+    pub fn write_image(&self, index: u32, image_info: &DescriptorImageInfo) {
+        self.check_descriptor_data::<DescriptorImageInfo>();
+        self.check_index(index);
+        self.write(
+            WriteDescriptorSet::new()
+                .with_dst_array_element(index)
+                .with_image_infos(std::slice::from_ref(image_info)),
+        )
+    }
+
+    /// Panics unless the table holds buffer descriptors and `index` is handed out.
+    pub fn write_buffer(&self, index: u32, buffer_info: &DescriptorBufferInfo) {
+        self.check_descriptor_data::<DescriptorBufferInfo>();
+        self.check_index(index);
+        self.write(
+            WriteDescriptorSet::new()
+                .with_dst_array_element(index)
+                .with_buffer_infos(std::slice::from_ref(buffer_info)),
+        )
+    }
+
+    /// Panics unless the table holds texel buffer descriptors and `index` is handed out.
+    pub fn write_texel_buffer_view(&self, index: u32, buffer_view: BufferView) {
+        self.check_descriptor_data::<BufferView>();
+        self.check_index(index);
+        self.write(
+            WriteDescriptorSet::new()
+                .with_dst_array_element(index)
+                .with_texel_buffer_views(std::slice::from_ref(&buffer_view)),
+        )
+    }
+
+    /// Allocates an index and writes `image_info` to it, `None` when the table is full.
+    pub fn insert_image(&mut self, image_info: &DescriptorImageInfo) -> Option<u32> {
+        self.check_descriptor_data::<DescriptorImageInfo>();
+        let index = self.allocate_index()?;
+        self.write_image(index, image_info);
+        Some(index)
+    }
+
+    /// Allocates an index and writes `buffer_info` to it, `None` when the table is full.
+    pub fn insert_buffer(&mut self, buffer_info: &DescriptorBufferInfo) -> Option<u32> {
+        self.check_descriptor_data::<DescriptorBufferInfo>();
+        let index = self.allocate_index()?;
+        self.write_buffer(index, buffer_info);
+        Some(index)
+    }
+
+    /// Allocates an index and writes `buffer_view` to it, `None` when the table is full.
+    pub fn insert_texel_buffer_view(&mut self, buffer_view: BufferView) -> Option<u32> {
+        self.check_descriptor_data::<BufferView>();
+        let index = self.allocate_index()?;
+        self.write_texel_buffer_view(index, buffer_view);
+        Some(index)
+    }
+
+    fn check_descriptor_data<T: DescriptorData>(&self) {
+        assert!(
+            T::DESCRIPTOR_TYPES.contains(&self.descriptor_type),
+            "{} cannot be written to a table of {:?} descriptors",
+            std::any::type_name::<T>(),
+            self.descriptor_type
+        );
+    }
+
+    fn check_index(&self, index: u32) {
+        assert!(
+            self.indices.is_allocated(index),
+            "index {} is not handed out by the table",
+            index
+        );
+    }
+
+    fn write(&self, write: WriteDescriptorSet) {
+        let write = write
+            .with_dst_set(self.descriptor_set)
+            .with_dst_binding(self.binding)
+            .with_descriptor_type(self.descriptor_type);
+        self.device
+            .update_descriptor_sets(std::slice::from_ref(&write), &[]);
+    }
+}
+
+/*
+   Index Allocator
+*/
+
+/// Hands out indices below a fixed capacity, reusing the most recently freed index first.
+#[derive(Debug)]
+struct IndexAllocator {
+    capacity: u32,
+    next_index: u32,
+    free_indices: Vec<u32>,
+    allocated: Vec<bool>,
+}
+
+impl IndexAllocator {
+    const fn new(capacity: u32) -> Self {
+        Self {
+            capacity,
+            next_index: 0,
+            free_indices: Vec::new(),
+            allocated: Vec::new(),
+        }
+    }
+
+    const fn capacity(&self) -> u32 {
+        self.capacity
+    }
+
+    fn len(&self) -> u32 {
+        self.next_index - self.free_indices.len() as u32
+    }
+
+    fn is_allocated(&self, index: u32) -> bool {
+        self.allocated.get(index as usize).copied().unwrap_or(false)
+    }
+
+    fn allocate(&mut self) -> Option<u32> {
+        if let Some(index) = self.free_indices.pop() {
+            self.allocated[index as usize] = true;
+            return Some(index);
+        }
+
+        (self.next_index < self.capacity).then(|| {
+            self.allocated.push(true);
+            self.next_index += 1;
+            self.next_index - 1
+        })
+    }
+
+    /// `false` if `index` was never allocated or is already free.
+    fn free(&mut self, index: u32) -> bool {
+        match self.allocated.get_mut(index as usize) {
+            Some(allocated @ true) => {
+                *allocated = false;
+                self.free_indices.push(index);
+                true
+            }
+            _ => false,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn allocates_in_order() {
+        let mut indices = IndexAllocator::new(4);
+        assert_eq!(indices.allocate(), Some(0));
+        assert_eq!(indices.allocate(), Some(1));
+        assert_eq!(indices.allocate(), Some(2));
+        assert_eq!(indices.len(), 3);
+    }
+
+    #[test]
+    fn reuses_most_recently_freed_first() {
+        let mut indices = IndexAllocator::new(8);
+        for _ in 0..4 {
+            indices.allocate();
+        }
+
+        indices.free(1);
+        indices.free(3);
+
+        assert_eq!(indices.allocate(), Some(3));
+        assert_eq!(indices.allocate(), Some(1));
+        assert_eq!(indices.allocate(), Some(4));
+    }
+
+    #[test]
+    fn stops_at_capacity() {
+        let mut indices = IndexAllocator::new(2);
+        assert_eq!(indices.allocate(), Some(0));
+        assert_eq!(indices.allocate(), Some(1));
+        assert_eq!(indices.allocate(), None);
+        assert_eq!(indices.len(), 2);
+
+        indices.free(0);
+        assert_eq!(indices.allocate(), Some(0));
+        assert_eq!(indices.allocate(), None);
+    }
+
+    #[test]
+    fn rejects_unallocated_and_double_free() {
+        let mut indices = IndexAllocator::new(4);
+        indices.allocate();
+        indices.allocate();
+
+        assert!(!indices.free(2));
+        assert!(!indices.free(7));
+        assert!(indices.free(1));
+        assert!(!indices.free(1));
+        assert_eq!(indices.len(), 1);
+
+        assert_eq!(indices.allocate(), Some(1));
+        assert!(indices.free(1));
+    }
+
+    #[test]
+    fn tracks_which_indices_are_handed_out() {
+        let mut indices = IndexAllocator::new(4);
+        assert!(!indices.is_allocated(0));
+
+        indices.allocate();
+        indices.allocate();
+        assert!(indices.is_allocated(0));
+        assert!(indices.is_allocated(1));
+        assert!(!indices.is_allocated(2));
+        assert!(!indices.is_allocated(4));
+
+        indices.free(0);
+        assert!(!indices.is_allocated(0));
+        indices.allocate();
+        assert!(indices.is_allocated(0));
+    }
+
+    #[test]
+    fn zero_capacity_allocates_nothing() {
+        let mut indices = IndexAllocator::new(0);
+        assert_eq!(indices.allocate(), None);
+        assert_eq!(indices.len(), 0);
+    }
+
+    #[test]
+    fn len_counts_removals() {
+        let mut indices = IndexAllocator::new(4);
+        for _ in 0..4 {
+            indices.allocate();
+        }
+
+        indices.free(2);
+        assert_eq!(indices.len(), 3);
+        indices.free(0);
+        assert_eq!(indices.len(), 2);
+
+        indices.allocate();
+        assert_eq!(indices.len(), 3);
+    }
+}
@@ -0,0 +1,273 @@
+// Copyright (c) 2024 Jacob R. Green
+// All rights reserved.
+
+use crate::macros::*;
+use crate::*;
+use vulkan_sys::*;
+
+vulkan_handle!(PipelineCache, VkPipelineCache, PIPELINE_CACHE);
+
+/*
+   Pipeline Cache Create Info
+*/
+
+vulkan_create_info_lifetime!(
+    PipelineCacheCreateInfo,
+    VkPipelineCacheCreateInfo,
+    VK_STRUCTURE_TYPE_PIPELINE_CACHE_CREATE_INFO
+);
+
+impl<'a> PipelineCacheCreateInfo<'a> {
+    pub const fn with_flags(mut self, flags: PipelineCacheCreateFlags) -> Self {
+        self.inner.flags = flags.bits();
+        self
+    }
+
+    pub const fn with_initial_data(mut self, initial_data: &'a [u8]) -> Self {
+        self.inner.initialDataSize = initial_data.len();
+        self.inner.pInitialData = initial_data.as_ptr().cast();
+        self
+    }
+}
+
+/*
+   Pipeline Cache Header
+*/
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, thiserror::Error)]
+pub enum PipelineCacheHeaderError {
+    #[error("pipeline cache data is {0} bytes, shorter than its header")]
+    TooShort(usize),
+    #[error("pipeline cache header size {0} is invalid")]
+    InvalidHeaderSize(u32),
+    #[error("pipeline cache header version {0} is not supported")]
+    UnsupportedVersion(u32),
+    #[error("pipeline cache vendor id {found:#x} does not match device vendor id {expected:#x}")]
+    VendorMismatch { expected: u32, found: u32 },
+    #[error("pipeline cache device id {found:#x} does not match device id {expected:#x}")]
+    DeviceMismatch { expected: u32, found: u32 },
+    #[error("pipeline cache uuid does not match the device pipeline cache uuid")]
+    UuidMismatch,
+}
+
+/// Decoded `VkPipelineCacheHeaderVersionOne` found at the start of pipeline cache data.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PipelineCacheHeader {
+    pub header_size: u32,
+    pub header_version: u32,
+    pub vendor_id: u32,
+    pub device_id: u32,
+    pub pipeline_cache_uuid: [u8; VK_UUID_SIZE as usize],
+}
+
+impl PipelineCacheHeader {
+    pub const SIZE: usize = 16 + VK_UUID_SIZE as usize;
+
+    /// Parses the header from the start of `data`.
+    ///
+    /// Header fields are always stored least significant byte first, regardless of host
+    /// byte order.
+    pub fn parse(data: &[u8]) -> std::result::Result<Self, PipelineCacheHeaderError> {
+        if data.len() < Self::SIZE {
+            return Err(PipelineCacheHeaderError::TooShort(data.len()));
+        }
+
+        let read_u32 =
+            |offset: usize| u32::from_le_bytes(data[offset..offset + 4].try_into().unwrap());
+
+        let header = Self {
+            header_size: read_u32(0),
+            header_version: read_u32(4),
+            vendor_id: read_u32(8),
+            device_id: read_u32(12),
+            pipeline_cache_uuid: data[16..Self::SIZE].try_into().unwrap(),
+        };
+
+        if (header.header_size as usize) < Self::SIZE || header.header_size as usize > data.len() {
+            return Err(PipelineCacheHeaderError::InvalidHeaderSize(
+                header.header_size,
+            ));
+        }
+
+        if header.header_version != PipelineCacheHeaderVersion::ONE.as_raw() as u32 {
+            return Err(PipelineCacheHeaderError::UnsupportedVersion(
+                header.header_version,
+            ));
+        }
+
+        Ok(header)
+    }
+
+    pub fn validate(
+        &self,
+        vendor_id: u32,
+        device_id: u32,
+        pipeline_cache_uuid: &[u8; VK_UUID_SIZE as usize],
+    ) -> std::result::Result<(), PipelineCacheHeaderError> {
+        if self.vendor_id != vendor_id {
+            return Err(PipelineCacheHeaderError::VendorMismatch {
+                expected: vendor_id,
+                found: self.vendor_id,
+            });
+        }
+
+        if self.device_id != device_id {
+            return Err(PipelineCacheHeaderError::DeviceMismatch {
+                expected: device_id,
+                found: self.device_id,
+            });
+        }
+
+        if &self.pipeline_cache_uuid != pipeline_cache_uuid {
+            return Err(PipelineCacheHeaderError::UuidMismatch);
+        }
+
+        Ok(())
+    }
+
+    pub fn validate_properties(
+        &self,
+        properties: &PhysicalDeviceProperties,
+    ) -> std::result::Result<(), PipelineCacheHeaderError> {
+        self.validate(
+            properties.vendor_id(),
+            properties.device_id(),
+            properties.pipeline_cache_uuid(),
+        )
+    }
+}
+
+/// Returns `data` if it is a pipeline cache blob created by a device matching `properties`.
+///
+/// Stale or foreign blobs should be discarded rather than handed to `vkCreatePipelineCache`.
+pub fn validate_pipeline_cache_data<'a>(
+    data: &'a [u8],
+    properties: &PhysicalDeviceProperties,
+) -> std::result::Result<&'a [u8], PipelineCacheHeaderError> {
+    PipelineCacheHeader::parse(data)?.validate_properties(properties)?;
+    Ok(data)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const VENDOR_ID: u32 = 0x10de;
+    const DEVICE_ID: u32 = 0x2684;
+    const UUID: [u8; VK_UUID_SIZE as usize] = [
+        0x00, 0x11, 0x22, 0x33, 0x44, 0x55, 0x66, 0x77, 0x88, 0x99, 0xaa, 0xbb, 0xcc, 0xdd, 0xee,
+        0xff,
+    ];
+
+    /// A version one header followed by four bytes of driver data.
+    const DATA: [u8; 36] = [
+        0x20, 0x00, 0x00, 0x00, // header size
+        0x01, 0x00, 0x00, 0x00, // header version
+        0xde, 0x10, 0x00, 0x00, // vendor id
+        0x84, 0x26, 0x00, 0x00, // device id
+        0x00, 0x11, 0x22, 0x33, 0x44, 0x55, 0x66, 0x77, // pipeline cache uuid
+        0x88, 0x99, 0xaa, 0xbb, 0xcc, 0xdd, 0xee, 0xff, //
+        0xca, 0xfe, 0xf0, 0x0d, // driver data
+    ];
+
+    fn data_with_u32(offset: usize, value: u32) -> [u8; 36] {
+        let mut data = DATA;
+        data[offset..offset + 4].copy_from_slice(&value.to_le_bytes());
+        data
+    }
+
+    fn properties(vendor_id: u32, device_id: u32, uuid: [u8; 16]) -> PhysicalDeviceProperties {
+        let mut raw: VkPhysicalDeviceProperties = unsafe { std::mem::zeroed() };
+        raw.vendorID = vendor_id;
+        raw.deviceID = device_id;
+        raw.pipelineCacheUUID = uuid;
+        PhysicalDeviceProperties::from_raw(raw)
+    }
+
+    #[test]
+    fn parses_a_valid_header() {
+        let header = PipelineCacheHeader::parse(&DATA).unwrap();
+        assert_eq!(
+            header,
+            PipelineCacheHeader {
+                header_size: 32,
+                header_version: 1,
+                vendor_id: VENDOR_ID,
+                device_id: DEVICE_ID,
+                pipeline_cache_uuid: UUID,
+            }
+        );
+
+        let properties = properties(VENDOR_ID, DEVICE_ID, UUID);
+        assert_eq!(header.validate_properties(&properties), Ok(()));
+        assert_eq!(
+            validate_pipeline_cache_data(&DATA, &properties),
+            Ok(&DATA[..])
+        );
+    }
+
+    #[test]
+    fn accepts_a_header_without_driver_data() {
+        assert!(PipelineCacheHeader::parse(&DATA[..PipelineCacheHeader::SIZE]).is_ok());
+    }
+
+    #[test]
+    fn rejects_data_shorter_than_the_header() {
+        assert_eq!(
+            PipelineCacheHeader::parse(&[]),
+            Err(PipelineCacheHeaderError::TooShort(0))
+        );
+        assert_eq!(
+            PipelineCacheHeader::parse(&DATA[..31]),
+            Err(PipelineCacheHeaderError::TooShort(31))
+        );
+    }
+
+    #[test]
+    fn rejects_invalid_header_sizes() {
+        assert_eq!(
+            PipelineCacheHeader::parse(&data_with_u32(0, 16)),
+            Err(PipelineCacheHeaderError::InvalidHeaderSize(16))
+        );
+        assert_eq!(
+            PipelineCacheHeader::parse(&data_with_u32(0, 64)),
+            Err(PipelineCacheHeaderError::InvalidHeaderSize(64))
+        );
+    }
+
+    #[test]
+    fn rejects_unknown_header_versions() {
+        assert_eq!(
+            PipelineCacheHeader::parse(&data_with_u32(4, 2)),
+            Err(PipelineCacheHeaderError::UnsupportedVersion(2))
+        );
+    }
+
+    #[test]
+    fn rejects_headers_from_other_devices() {
+        let header = PipelineCacheHeader::parse(&DATA).unwrap();
+
+        assert_eq!(
+            header.validate_properties(&properties(0x1002, DEVICE_ID, UUID)),
+            Err(PipelineCacheHeaderError::VendorMismatch {
+                expected: 0x1002,
+                found: VENDOR_ID,
+            })
+        );
+        assert_eq!(
+            header.validate_properties(&properties(VENDOR_ID, 0x2704, UUID)),
+            Err(PipelineCacheHeaderError::DeviceMismatch {
+                expected: 0x2704,
+                found: DEVICE_ID,
+            })
+        );
+        assert_eq!(
+            header.validate_properties(&properties(VENDOR_ID, DEVICE_ID, [0; 16])),
+            Err(PipelineCacheHeaderError::UuidMismatch)
+        );
+        assert_eq!(
+            validate_pipeline_cache_data(&DATA, &properties(VENDOR_ID, DEVICE_ID, [0; 16])),
+            Err(PipelineCacheHeaderError::UuidMismatch)
+        );
+    }
+}
@@ -0,0 +1,43 @@
+// Copyright (c) 2024 Jacob R. Green
+// All rights reserved.
+
+use crate::macros::*;
+use crate::*;
+use vulkan_sys::*;
+
+use super::*;
+
+vulkan_create_info_lifetime!(
+    ComputePipelineCreateInfo,
+    VkComputePipelineCreateInfo,
+    VK_STRUCTURE_TYPE_COMPUTE_PIPELINE_CREATE_INFO
+);
+
+impl<'a> ComputePipelineCreateInfo<'a> {
+    pub const fn with_flags(mut self, flags: PipelineCreateFlags) -> Self {
+        self.inner.flags = flags.bits();
+        self
+    }
+
+    pub const fn with_stage(mut self, stage: PipelineShaderStageCreateInfo<'a>) -> Self {
+        self.inner.stage = *stage.as_raw();
+        self
+    }
+
+    pub const fn with_layout(mut self, layout: PipelineLayout) -> Self {
+        self.inner.layout = layout.as_raw();
+        self
+    }
+
+    pub const fn with_base_pipeline(mut self, base_pipeline: Pipeline) -> Self {
+        self.inner.basePipelineHandle = base_pipeline.as_raw();
+        self.inner.basePipelineIndex = -1;
+        self
+    }
+
+    pub const fn with_base_pipeline_index(mut self, base_pipeline_index: i32) -> Self {
+        self.inner.basePipelineIndex = base_pipeline_index;
+        self.inner.basePipelineHandle = std::ptr::null_mut();
+        self
+    }
+}
@@ -5,7 +5,7 @@ use crate::macros::*;
 use crate::*;
 use vulkan_sys::*;
 
-vulkan_handle!(DescriptorPool, VkDescriptorPool);
+vulkan_handle!(DescriptorPool, VkDescriptorPool, DESCRIPTOR_POOL);
 
 pub struct DescriptorPoolSize {
     inner: VkDescriptorPoolSize,
@@ -31,6 +31,11 @@ vulkan_create_info_lifetime!(
 );
 
 impl<'a> DescriptorPoolCreateInfo<'a> {
+    pub const fn with_flags(mut self, flags: DescriptorPoolCreateFlags) -> Self {
+        self.inner.flags = flags.bits();
+        self
+    }
+
     pub const fn with_max_sets(mut self, max_sets: u32) -> Self {
         self.inner.maxSets = max_sets;
         self
@@ -62,7 +67,22 @@ impl<'a> DescriptorSetAllocateInfo<'a> {
     }
 }
 
-vulkan_handle!(DescriptorSet, VkDescriptorSet);
+vulkan_create_info_lifetime!(
+    DescriptorSetVariableDescriptorCountAllocateInfo,
+    VkDescriptorSetVariableDescriptorCountAllocateInfo,
+    VK_STRUCTURE_TYPE_DESCRIPTOR_SET_VARIABLE_DESCRIPTOR_COUNT_ALLOCATE_INFO
+);
+
+impl<'a> DescriptorSetVariableDescriptorCountAllocateInfo<'a> {
+    /// The size of the variable count binding of each set being allocated.
+    pub const fn with_descriptor_counts(mut self, descriptor_counts: &'a [u32]) -> Self {
+        self.inner.descriptorSetCount = descriptor_counts.len() as u32;
+        self.inner.pDescriptorCounts = descriptor_counts.as_ptr();
+        self
+    }
+}
+
+vulkan_handle!(DescriptorSet, VkDescriptorSet, DESCRIPTOR_SET);
 
 vulkan_create_info_lifetime!(
     WriteDescriptorSet,
@@ -103,11 +123,11 @@ impl<'a> WriteDescriptorSet<'a> {
         self
     }
 
-    // pub const fn with_texel_buffer_view(mut self, texel_buffer_view: &'a [VkBufferView]) -> Self {
-    //     self.inner.descriptorCount = texel_buffer_view.len() as u32;
-    //     self.inner.pTexelBufferView = texel_buffer_view.as_ptr();
-    //     self
-    // }
+    pub const fn with_texel_buffer_views(mut self, texel_buffer_views: &'a [BufferView]) -> Self {
+        self.inner.descriptorCount = texel_buffer_views.len() as u32;
+        self.inner.pTexelBufferView = texel_buffer_views.as_ptr().cast();
+        self
+    }
 }
 
 vulkan_struct_no_new!(DescriptorImageInfo, VkDescriptorImageInfo);
@@ -5,7 +5,11 @@ use crate::macros::*;
 use crate::*;
 use vulkan_sys::*;
 
-vulkan_handle!(DescriptorSetLayout, VkDescriptorSetLayout);
+vulkan_handle!(
+    DescriptorSetLayout,
+    VkDescriptorSetLayout,
+    DESCRIPTOR_SET_LAYOUT
+);
 
 vulkan_struct_lifetime!(DescriptorSetLayoutBinding, VkDescriptorSetLayoutBinding);
 
@@ -61,9 +65,31 @@ vulkan_create_info_lifetime!(
 );
 
 impl<'a> DescriptorSetLayoutCreateInfo<'a> {
+    /// Layouts used with [`CommandBuffer::cmd_push_descriptor_set_khr`] need the push descriptor
+    /// flag and cannot be allocated from a pool.
+    pub const fn with_flags(mut self, flags: DescriptorSetLayoutCreateFlags) -> Self {
+        self.inner.flags = flags.bits();
+        self
+    }
+
     pub const fn with_bindings(mut self, bindings: &'a [DescriptorSetLayoutBinding]) -> Self {
         self.inner.bindingCount = bindings.len() as u32;
         self.inner.pBindings = bindings.as_ptr().cast();
         self
     }
 }
+
+vulkan_create_info_lifetime!(
+    DescriptorSetLayoutBindingFlagsCreateInfo,
+    VkDescriptorSetLayoutBindingFlagsCreateInfo,
+    VK_STRUCTURE_TYPE_DESCRIPTOR_SET_LAYOUT_BINDING_FLAGS_CREATE_INFO
+);
+
+impl<'a> DescriptorSetLayoutBindingFlagsCreateInfo<'a> {
+    /// One entry per binding passed to [`DescriptorSetLayoutCreateInfo::with_bindings`].
+    pub const fn with_binding_flags(mut self, binding_flags: &'a [DescriptorBindingFlags]) -> Self {
+        self.inner.bindingCount = binding_flags.len() as u32;
+        self.inner.pBindingFlags = binding_flags.as_ptr().cast();
+        self
+    }
+}
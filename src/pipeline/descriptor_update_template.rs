@@ -0,0 +1,420 @@
+// Copyright (c) 2024 Jacob R. Green
+// All rights reserved.
+
+use crate::macros::*;
+use crate::*;
+use vulkan_sys::*;
+
+vulkan_handle!(
+    DescriptorUpdateTemplate,
+    VkDescriptorUpdateTemplate,
+    DESCRIPTOR_UPDATE_TEMPLATE
+);
+
+/*
+   Descriptor Data
+*/
+
+/// Element types a descriptor update template reads from host memory.
+///
+/// # Safety
+/// `Self` must have the layout Vulkan expects for every type in `DESCRIPTOR_TYPES`.
+pub unsafe trait DescriptorData {
+    const DESCRIPTOR_TYPES: &'static [DescriptorType];
+}
+
+unsafe impl DescriptorData for DescriptorImageInfo {
+    const DESCRIPTOR_TYPES: &'static [DescriptorType] = &[
+        DescriptorType::SAMPLER,
+        DescriptorType::COMBINED_IMAGE_SAMPLER,
+        DescriptorType::SAMPLED_IMAGE,
+        DescriptorType::STORAGE_IMAGE,
+        DescriptorType::INPUT_ATTACHMENT,
+    ];
+}
+
+unsafe impl DescriptorData for DescriptorBufferInfo {
+    const DESCRIPTOR_TYPES: &'static [DescriptorType] = &[
+        DescriptorType::UNIFORM_BUFFER,
+        DescriptorType::STORAGE_BUFFER,
+        DescriptorType::UNIFORM_BUFFER_DYNAMIC,
+        DescriptorType::STORAGE_BUFFER_DYNAMIC,
+    ];
+}
+
+unsafe impl DescriptorData for BufferView {
+    const DESCRIPTOR_TYPES: &'static [DescriptorType] = &[
+        DescriptorType::UNIFORM_TEXEL_BUFFER,
+        DescriptorType::STORAGE_TEXEL_BUFFER,
+    ];
+}
+
+/// Size and alignment of one descriptor of `descriptor_type` in template data.
+const fn descriptor_data_layout(descriptor_type: DescriptorType) -> (usize, usize) {
+    match descriptor_type {
+        DescriptorType::SAMPLER
+        | DescriptorType::COMBINED_IMAGE_SAMPLER
+        | DescriptorType::SAMPLED_IMAGE
+        | DescriptorType::STORAGE_IMAGE
+        | DescriptorType::INPUT_ATTACHMENT => (
+            std::mem::size_of::<DescriptorImageInfo>(),
+            std::mem::align_of::<DescriptorImageInfo>(),
+        ),
+        DescriptorType::UNIFORM_BUFFER
+        | DescriptorType::STORAGE_BUFFER
+        | DescriptorType::UNIFORM_BUFFER_DYNAMIC
+        | DescriptorType::STORAGE_BUFFER_DYNAMIC => (
+            std::mem::size_of::<DescriptorBufferInfo>(),
+            std::mem::align_of::<DescriptorBufferInfo>(),
+        ),
+        DescriptorType::UNIFORM_TEXEL_BUFFER | DescriptorType::STORAGE_TEXEL_BUFFER => (
+            std::mem::size_of::<BufferView>(),
+            std::mem::align_of::<BufferView>(),
+        ),
+        // inline uniform blocks count bytes, everything else is a 64-bit handle or address
+        DescriptorType::INLINE_UNIFORM_BLOCK => (1, 1),
+        _ => (std::mem::size_of::<u64>(), std::mem::align_of::<u64>()),
+    }
+}
+
+/*
+   Descriptor Update Template Entry
+*/
+
+vulkan_struct_no_new!(
+    DescriptorUpdateTemplateEntry,
+    VkDescriptorUpdateTemplateEntry
+);
+
+impl DescriptorUpdateTemplateEntry {
+    /// `offset` and `stride` are in bytes from the start of the update data.
+    pub const fn new(
+        dst_binding: u32,
+        dst_array_element: u32,
+        descriptor_count: u32,
+        descriptor_type: DescriptorType,
+        offset: usize,
+        stride: usize,
+    ) -> Self {
+        Self {
+            inner: VkDescriptorUpdateTemplateEntry {
+                dstBinding: dst_binding,
+                dstArrayElement: dst_array_element,
+                descriptorCount: descriptor_count,
+                descriptorType: descriptor_type.as_raw(),
+                offset,
+                stride,
+            },
+        }
+    }
+
+    /// An entry reading `descriptor_count` consecutive `F`s at `offset`, usually
+    /// `std::mem::offset_of!(Data, field)`.
+    ///
+    /// Panics, at compile time in constants, if `F` cannot hold `descriptor_type`.
+    pub const fn field<F: DescriptorData>(
+        dst_binding: u32,
+        dst_array_element: u32,
+        descriptor_type: DescriptorType,
+        offset: usize,
+        descriptor_count: u32,
+    ) -> Self {
+        let mut index = 0;
+        while index < F::DESCRIPTOR_TYPES.len() {
+            if F::DESCRIPTOR_TYPES[index].as_raw() == descriptor_type.as_raw() {
+                return Self::new(
+                    dst_binding,
+                    dst_array_element,
+                    descriptor_count,
+                    descriptor_type,
+                    offset,
+                    std::mem::size_of::<F>(),
+                );
+            }
+            index += 1;
+        }
+        panic!("descriptor type does not match the field type");
+    }
+
+    /// Like [`DescriptorUpdateTemplateEntry::field`], with `F` inferred from a projection to the
+    /// field. Used by [`descriptor_field!`].
+    pub const fn field_of<S, F: DescriptorData>(
+        _field: fn(&S) -> &F,
+        dst_binding: u32,
+        dst_array_element: u32,
+        descriptor_type: DescriptorType,
+        offset: usize,
+        descriptor_count: u32,
+    ) -> Self {
+        Self::field::<F>(
+            dst_binding,
+            dst_array_element,
+            descriptor_type,
+            offset,
+            descriptor_count,
+        )
+    }
+
+    pub const fn descriptor_type(&self) -> DescriptorType {
+        DescriptorType::from_raw(self.inner.descriptorType)
+    }
+
+    pub const fn offset(&self) -> usize {
+        self.inner.offset
+    }
+
+    pub const fn stride(&self) -> usize {
+        self.inner.stride
+    }
+
+    pub const fn descriptor_count(&self) -> u32 {
+        self.inner.descriptorCount
+    }
+
+    /// The byte range of the update data this entry reads.
+    fn data_range(&self) -> std::ops::Range<usize> {
+        let (size, _) = descriptor_data_layout(self.descriptor_type());
+        let end = match (self.descriptor_type(), self.descriptor_count()) {
+            (_, 0) => self.offset(),
+            (DescriptorType::INLINE_UNIFORM_BLOCK, count) => self.offset() + count as usize,
+            (_, count) => self.offset() + self.stride() * (count as usize - 1) + size,
+        };
+        self.offset()..end
+    }
+}
+
+/// An entry for the single descriptor in field `$field` of `$ty`, written to array element 0 of
+/// `$binding`.
+///
+/// The element type is taken from the field, so it always matches the field's offset. Panics,
+/// at compile time in constants, if the field's type cannot hold `$descriptor_type`.
+#[macro_export]
+macro_rules! descriptor_field {
+    ($ty:ty, $field:ident, $binding:expr, $descriptor_type:expr) => {
+        $crate::DescriptorUpdateTemplateEntry::field_of(
+            |data: &$ty| &data.$field,
+            $binding,
+            0,
+            $descriptor_type,
+            ::std::mem::offset_of!($ty, $field),
+            1,
+        )
+    };
+}
+
+/*
+   Descriptor Update Data
+*/
+
+/// A `#[repr(C)]` struct passed to [`Device::update_descriptor_set_with_template`].
+///
+/// Entries are best built with [`descriptor_field!`], which ties each entry to the type and
+/// offset of the field it reads:
+///
+/// ```ignore
+/// #[repr(C)]
+/// struct Material {
+///     albedo: DescriptorImageInfo,
+///     params: DescriptorBufferInfo,
+/// }
+///
+/// unsafe impl DescriptorUpdateData for Material {
+///     const UPDATE_ENTRIES: &'static [DescriptorUpdateTemplateEntry] = &[
+///         descriptor_field!(Material, albedo, 0, DescriptorType::COMBINED_IMAGE_SAMPLER),
+///         descriptor_field!(Material, params, 1, DescriptorType::UNIFORM_BUFFER),
+///     ];
+/// }
+/// ```
+///
+/// # Safety
+/// Every entry must read fields of `Self` whose types match the entry's descriptor type.
+pub unsafe trait DescriptorUpdateData: Sized {
+    const UPDATE_ENTRIES: &'static [DescriptorUpdateTemplateEntry];
+}
+
+/// Panics if any of `T`'s entries reads outside `T` or from a misaligned offset.
+fn check_update_entries<T: DescriptorUpdateData>() {
+    for (index, entry) in T::UPDATE_ENTRIES.iter().enumerate() {
+        let (_, align) = descriptor_data_layout(entry.descriptor_type());
+        let range = entry.data_range();
+
+        assert!(
+            range.end <= std::mem::size_of::<T>(),
+            "update entry {} of {} reads past the end of the struct",
+            index,
+            std::any::type_name::<T>()
+        );
+        assert!(
+            entry.offset() % align == 0 && entry.stride() % align == 0,
+            "update entry {} of {} is misaligned",
+            index,
+            std::any::type_name::<T>()
+        );
+    }
+}
+
+/*
+   Descriptor Update Template Create Info
+*/
+
+vulkan_create_info_lifetime!(
+    DescriptorUpdateTemplateCreateInfo,
+    VkDescriptorUpdateTemplateCreateInfo,
+    VK_STRUCTURE_TYPE_DESCRIPTOR_UPDATE_TEMPLATE_CREATE_INFO
+);
+
+impl<'a> DescriptorUpdateTemplateCreateInfo<'a> {
+    pub const fn with_update_entries(
+        mut self,
+        update_entries: &'a [DescriptorUpdateTemplateEntry],
+    ) -> Self {
+        self.inner.descriptorUpdateEntryCount = update_entries.len() as u32;
+        self.inner.pDescriptorUpdateEntries = update_entries.as_ptr().cast();
+        self
+    }
+
+    /// Uses the entries of `T`, checking that each of them stays inside `T`.
+    pub fn with_update_data<T: DescriptorUpdateData>(self) -> Self {
+        check_update_entries::<T>();
+        self.with_update_entries(T::UPDATE_ENTRIES)
+    }
+
+    pub const fn with_template_type(mut self, template_type: DescriptorUpdateTemplateType) -> Self {
+        self.inner.templateType = template_type.as_raw();
+        self
+    }
+
+    /// The layout of the sets updated by a `DESCRIPTOR_SET` template.
+    pub const fn with_descriptor_set_layout(
+        mut self,
+        descriptor_set_layout: DescriptorSetLayout,
+    ) -> Self {
+        self.inner.descriptorSetLayout = descriptor_set_layout.as_raw();
+        self
+    }
+
+    /// The bind point, layout and set pushed to by a push descriptor template.
+    pub const fn with_push_descriptor_set(
+        mut self,
+        pipeline_bind_point: PipelineBindPoint,
+        pipeline_layout: PipelineLayout,
+        set: u32,
+    ) -> Self {
+        self.inner.pipelineBindPoint = pipeline_bind_point.as_raw();
+        self.inner.pipelineLayout = pipeline_layout.as_raw();
+        self.inner.set = set;
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::mem::{offset_of, size_of};
+
+    #[allow(dead_code)]
+    #[repr(C)]
+    struct Material {
+        albedo: DescriptorImageInfo,
+        params: DescriptorBufferInfo,
+    }
+
+    unsafe impl DescriptorUpdateData for Material {
+        const UPDATE_ENTRIES: &'static [DescriptorUpdateTemplateEntry] = &[
+            descriptor_field!(Material, albedo, 0, DescriptorType::COMBINED_IMAGE_SAMPLER),
+            descriptor_field!(Material, params, 1, DescriptorType::UNIFORM_BUFFER),
+        ];
+    }
+
+    /// Reads two buffer infos from a struct that holds one.
+    #[allow(dead_code)]
+    #[repr(C)]
+    struct PastTheEnd {
+        params: DescriptorBufferInfo,
+    }
+
+    unsafe impl DescriptorUpdateData for PastTheEnd {
+        const UPDATE_ENTRIES: &'static [DescriptorUpdateTemplateEntry] = &[
+            DescriptorUpdateTemplateEntry::field::<DescriptorBufferInfo>(
+                1,
+                0,
+                DescriptorType::UNIFORM_BUFFER,
+                offset_of!(PastTheEnd, params),
+                2,
+            ),
+        ];
+    }
+
+    #[allow(dead_code)]
+    #[repr(C)]
+    struct Misaligned {
+        data: [u64; 8],
+    }
+
+    unsafe impl DescriptorUpdateData for Misaligned {
+        const UPDATE_ENTRIES: &'static [DescriptorUpdateTemplateEntry] = &[
+            DescriptorUpdateTemplateEntry::field::<DescriptorBufferInfo>(
+                0,
+                0,
+                DescriptorType::UNIFORM_BUFFER,
+                4,
+                1,
+            ),
+        ];
+    }
+
+    #[test]
+    fn descriptor_field_uses_the_field_type_and_offset() {
+        let [albedo, params] = Material::UPDATE_ENTRIES else {
+            unreachable!()
+        };
+
+        assert_eq!(albedo.offset(), offset_of!(Material, albedo));
+        assert_eq!(albedo.stride(), size_of::<DescriptorImageInfo>());
+        assert_eq!(params.offset(), offset_of!(Material, params));
+        assert_eq!(params.stride(), size_of::<DescriptorBufferInfo>());
+        assert_eq!(params.descriptor_count(), 1);
+
+        check_update_entries::<Material>();
+    }
+
+    #[test]
+    fn data_range_covers_every_descriptor() {
+        let stride = size_of::<DescriptorBufferInfo>() + 8;
+        let entry =
+            DescriptorUpdateTemplateEntry::new(0, 0, 3, DescriptorType::UNIFORM_BUFFER, 16, stride);
+        assert_eq!(
+            entry.data_range(),
+            16..16 + stride * 2 + size_of::<DescriptorBufferInfo>()
+        );
+
+        let empty =
+            DescriptorUpdateTemplateEntry::new(0, 0, 0, DescriptorType::UNIFORM_BUFFER, 16, stride);
+        assert_eq!(empty.data_range(), 16..16);
+    }
+
+    #[test]
+    fn data_range_counts_bytes_of_inline_uniform_blocks() {
+        let entry = DescriptorUpdateTemplateEntry::new(
+            0,
+            0,
+            12,
+            DescriptorType::INLINE_UNIFORM_BLOCK,
+            3,
+            0,
+        );
+        assert_eq!(entry.data_range(), 3..15);
+    }
+
+    #[test]
+    #[should_panic(expected = "reads past the end of the struct")]
+    fn rejects_entries_past_the_end() {
+        check_update_entries::<PastTheEnd>();
+    }
+
+    #[test]
+    #[should_panic(expected = "is misaligned")]
+    fn rejects_misaligned_entries() {
+        check_update_entries::<Misaligned>();
+    }
+}
@@ -112,13 +112,13 @@ impl<'a> PipelineVertexInputStateCreateInfo<'a> {
     }
 }
 
-vulkan_create_info!(
+vulkan_create_info_lifetime!(
     PipelineInputAssemblyStateCreateInfo,
     VkPipelineInputAssemblyStateCreateInfo,
     VK_STRUCTURE_TYPE_PIPELINE_INPUT_ASSEMBLY_STATE_CREATE_INFO
 );
 
-impl PipelineInputAssemblyStateCreateInfo {
+impl<'a> PipelineInputAssemblyStateCreateInfo<'a> {
     pub const fn with_topology(mut self, topology: PrimitiveTopology) -> Self {
         self.inner.topology = topology.as_raw();
         self
@@ -168,13 +168,13 @@ impl<'a> PipelineViewportStateCreateInfo<'a> {
     }
 }
 
-vulkan_create_info!(
+vulkan_create_info_lifetime!(
     PipelineRasterizationStateCreateInfo,
     VkPipelineRasterizationStateCreateInfo,
     VK_STRUCTURE_TYPE_PIPELINE_RASTERIZATION_STATE_CREATE_INFO
 );
 
-impl PipelineRasterizationStateCreateInfo {
+impl<'a> PipelineRasterizationStateCreateInfo<'a> {
     pub const fn with_depth_clamp_enable(mut self, depth_clamp_enable: bool) -> Self {
         self.inner.depthClampEnable = depth_clamp_enable as u32;
         self
@@ -276,13 +276,13 @@ impl<'a> PipelineMultisampleStateCreateInfo<'a> {
     }
 }
 
-vulkan_create_info!(
+vulkan_create_info_lifetime!(
     PipelineDepthStencilStateCreateInfo,
     VkPipelineDepthStencilStateCreateInfo,
     VK_STRUCTURE_TYPE_PIPELINE_DEPTH_STENCIL_STATE_CREATE_INFO
 );
 
-impl PipelineDepthStencilStateCreateInfo {
+impl<'a> PipelineDepthStencilStateCreateInfo<'a> {
     pub const fn with_depth_test_enable(mut self, depth_test_enable: bool) -> Self {
         self.inner.depthTestEnable = depth_test_enable as u32;
         self
@@ -440,6 +440,41 @@ impl<'a> PipelineDynamicStateCreateInfo<'a> {
     }
 }
 
+vulkan_create_info_lifetime!(
+    PipelineRenderingCreateInfo,
+    VkPipelineRenderingCreateInfo,
+    VK_STRUCTURE_TYPE_PIPELINE_RENDERING_CREATE_INFO
+);
+
+impl<'a> PipelineRenderingCreateInfo<'a> {
+    pub const fn with_view_mask(mut self, view_mask: u32) -> Self {
+        self.inner.viewMask = view_mask;
+        self
+    }
+
+    pub const fn with_color_attachment_formats(
+        mut self,
+        color_attachment_formats: &'a [Format],
+    ) -> Self {
+        self.inner.colorAttachmentCount = color_attachment_formats.len() as u32;
+        self.inner.pColorAttachmentFormats = color_attachment_formats.as_ptr().cast();
+        self
+    }
+
+    pub const fn with_depth_attachment_format(mut self, depth_attachment_format: Format) -> Self {
+        self.inner.depthAttachmentFormat = depth_attachment_format.as_raw();
+        self
+    }
+
+    pub const fn with_stencil_attachment_format(
+        mut self,
+        stencil_attachment_format: Format,
+    ) -> Self {
+        self.inner.stencilAttachmentFormat = stencil_attachment_format.as_raw();
+        self
+    }
+}
+
 vulkan_create_info_lifetime!(
     GraphicsPipelineCreateInfo,
     VkGraphicsPipelineCreateInfo,
@@ -468,7 +503,7 @@ impl<'a> GraphicsPipelineCreateInfo<'a> {
 
     pub const fn with_input_assembly_state(
         mut self,
-        input_assembly_state: &'a PipelineInputAssemblyStateCreateInfo,
+        input_assembly_state: &'a PipelineInputAssemblyStateCreateInfo<'a>,
     ) -> Self {
         self.inner.pInputAssemblyState = input_assembly_state.as_raw();
         self
@@ -492,7 +527,7 @@ impl<'a> GraphicsPipelineCreateInfo<'a> {
 
     pub const fn with_rasterization_state(
         mut self,
-        rasterization_state: &'a PipelineRasterizationStateCreateInfo,
+        rasterization_state: &'a PipelineRasterizationStateCreateInfo<'a>,
     ) -> Self {
         self.inner.pRasterizationState = rasterization_state.as_raw();
         self
@@ -508,7 +543,7 @@ impl<'a> GraphicsPipelineCreateInfo<'a> {
 
     pub const fn with_depth_stencil_state(
         mut self,
-        depth_stencil_state: &'a PipelineDepthStencilStateCreateInfo,
+        depth_stencil_state: &'a PipelineDepthStencilStateCreateInfo<'a>,
     ) -> Self {
         self.inner.pDepthStencilState = depth_stencil_state.as_raw();
         self
@@ -535,6 +570,8 @@ impl<'a> GraphicsPipelineCreateInfo<'a> {
         self
     }
 
+    /// Pipelines used with [`CommandBuffer::cmd_begin_rendering`] leave this unset and chain a
+    /// [`PipelineRenderingCreateInfo`] with [`GraphicsPipelineCreateInfo::push_next`] instead.
     pub const fn with_render_pass(mut self, render_pass: RenderPass, subpass: u32) -> Self {
         self.inner.renderPass = render_pass.as_raw();
         self.inner.subpass = subpass;
@@ -11,6 +11,12 @@ pub use descriptor_set_layout::*;
 mod descriptor_set;
 pub use descriptor_set::*;
 
+mod descriptor_update_template;
+pub use descriptor_update_template::*;
+
+mod bindless;
+pub use bindless::*;
+
 mod pipeline_layout;
 pub use pipeline_layout::*;
 
@@ -20,5 +26,10 @@ pub use shader::*;
 mod graphics;
 pub use graphics::*;
 
-vulkan_handle!(Pipeline, VkPipeline);
-vulkan_handle!(PipelineCache, VkPipelineCache);
+mod compute;
+pub use compute::*;
+
+mod cache;
+pub use cache::*;
+
+vulkan_handle!(Pipeline, VkPipeline, PIPELINE);
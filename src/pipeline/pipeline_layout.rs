@@ -5,7 +5,7 @@ use crate::macros::*;
 use crate::*;
 use vulkan_sys::*;
 
-vulkan_handle!(PipelineLayout, VkPipelineLayout);
+vulkan_handle!(PipelineLayout, VkPipelineLayout, PIPELINE_LAYOUT);
 
 vulkan_create_info_lifetime!(
     PipelineLayoutCreateInfo,
@@ -5,7 +5,7 @@ use crate::macros::*;
 use crate::*;
 use vulkan_sys::*;
 
-vulkan_handle!(ShaderModule, VkShaderModule);
+vulkan_handle!(ShaderModule, VkShaderModule, SHADER_MODULE);
 
 vulkan_create_info_lifetime!(
     ShaderModuleCreateInfo,
@@ -0,0 +1,1927 @@
+// Copyright (c) 2024 Jacob R. Green
+// All rights reserved.
+
+use crate::macros::*;
+use crate::*;
+use vulkan_sys::*;
+
+pub type ConformanceVersion = VkConformanceVersion;
+
+/*
+   Physical Device Properties 2
+*/
+
+vulkan_create_info_lifetime!(
+    PhysicalDeviceProperties2,
+    VkPhysicalDeviceProperties2,
+    VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_PROPERTIES_2
+);
+
+impl<'a> PhysicalDeviceProperties2<'a> {
+    pub const fn properties(&self) -> &PhysicalDeviceProperties {
+        unsafe { transmute(&self.inner.properties) }
+    }
+}
+
+/*
+   Physical Device Vulkan 1.1 Properties
+*/
+
+vulkan_create_info_lifetime!(
+    PhysicalDeviceVulkan11Properties,
+    VkPhysicalDeviceVulkan11Properties,
+    VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_VULKAN_1_1_PROPERTIES
+);
+
+impl<'a> PhysicalDeviceVulkan11Properties<'a> {
+    pub const fn device_uuid(&self) -> &[u8; VK_UUID_SIZE as usize] {
+        &self.inner.deviceUUID
+    }
+
+    pub const fn driver_uuid(&self) -> &[u8; VK_UUID_SIZE as usize] {
+        &self.inner.driverUUID
+    }
+
+    pub const fn device_luid(&self) -> &[u8; VK_LUID_SIZE as usize] {
+        &self.inner.deviceLUID
+    }
+
+    pub const fn device_node_mask(&self) -> u32 {
+        self.inner.deviceNodeMask
+    }
+
+    pub const fn device_luid_valid(&self) -> bool {
+        self.inner.deviceLUIDValid != 0
+    }
+
+    pub const fn subgroup_size(&self) -> u32 {
+        self.inner.subgroupSize
+    }
+
+    pub const fn subgroup_supported_stages(&self) -> ShaderStageFlags {
+        ShaderStageFlags::from_bits_truncate(self.inner.subgroupSupportedStages)
+    }
+
+    pub const fn subgroup_supported_operations(&self) -> SubgroupFeatureFlags {
+        SubgroupFeatureFlags::from_bits_truncate(self.inner.subgroupSupportedOperations)
+    }
+
+    pub const fn subgroup_quad_operations_in_all_stages(&self) -> bool {
+        self.inner.subgroupQuadOperationsInAllStages != 0
+    }
+
+    pub const fn point_clipping_behavior(&self) -> PointClippingBehavior {
+        PointClippingBehavior::from_raw(self.inner.pointClippingBehavior)
+    }
+
+    pub const fn max_multiview_view_count(&self) -> u32 {
+        self.inner.maxMultiviewViewCount
+    }
+
+    pub const fn max_multiview_instance_index(&self) -> u32 {
+        self.inner.maxMultiviewInstanceIndex
+    }
+
+    pub const fn protected_no_fault(&self) -> bool {
+        self.inner.protectedNoFault != 0
+    }
+
+    pub const fn max_per_set_descriptors(&self) -> u32 {
+        self.inner.maxPerSetDescriptors
+    }
+
+    pub const fn max_memory_allocation_size(&self) -> DeviceSize {
+        self.inner.maxMemoryAllocationSize
+    }
+
+    pub const fn with_device_uuid(mut self, device_uuid: [u8; VK_UUID_SIZE as usize]) -> Self {
+        self.inner.deviceUUID = device_uuid;
+        self
+    }
+
+    pub const fn with_driver_uuid(mut self, driver_uuid: [u8; VK_UUID_SIZE as usize]) -> Self {
+        self.inner.driverUUID = driver_uuid;
+        self
+    }
+
+    pub const fn with_device_luid(mut self, device_luid: [u8; VK_LUID_SIZE as usize]) -> Self {
+        self.inner.deviceLUID = device_luid;
+        self
+    }
+
+    pub const fn with_device_node_mask(mut self, device_node_mask: u32) -> Self {
+        self.inner.deviceNodeMask = device_node_mask;
+        self
+    }
+
+    pub const fn with_device_luid_valid(mut self, device_luid_valid: bool) -> Self {
+        self.inner.deviceLUIDValid = device_luid_valid as VkBool32;
+        self
+    }
+
+    pub const fn with_subgroup_size(mut self, subgroup_size: u32) -> Self {
+        self.inner.subgroupSize = subgroup_size;
+        self
+    }
+
+    pub const fn with_subgroup_supported_stages(
+        mut self,
+        subgroup_supported_stages: ShaderStageFlags,
+    ) -> Self {
+        self.inner.subgroupSupportedStages = subgroup_supported_stages.bits();
+        self
+    }
+
+    pub const fn with_subgroup_supported_operations(
+        mut self,
+        subgroup_supported_operations: SubgroupFeatureFlags,
+    ) -> Self {
+        self.inner.subgroupSupportedOperations = subgroup_supported_operations.bits();
+        self
+    }
+
+    pub const fn with_subgroup_quad_operations_in_all_stages(
+        mut self,
+        subgroup_quad_operations_in_all_stages: bool,
+    ) -> Self {
+        self.inner.subgroupQuadOperationsInAllStages =
+            subgroup_quad_operations_in_all_stages as VkBool32;
+        self
+    }
+
+    pub const fn with_point_clipping_behavior(
+        mut self,
+        point_clipping_behavior: PointClippingBehavior,
+    ) -> Self {
+        self.inner.pointClippingBehavior = point_clipping_behavior.as_raw();
+        self
+    }
+
+    pub const fn with_max_multiview_view_count(mut self, max_multiview_view_count: u32) -> Self {
+        self.inner.maxMultiviewViewCount = max_multiview_view_count;
+        self
+    }
+
+    pub const fn with_max_multiview_instance_index(
+        mut self,
+        max_multiview_instance_index: u32,
+    ) -> Self {
+        self.inner.maxMultiviewInstanceIndex = max_multiview_instance_index;
+        self
+    }
+
+    pub const fn with_protected_no_fault(mut self, protected_no_fault: bool) -> Self {
+        self.inner.protectedNoFault = protected_no_fault as VkBool32;
+        self
+    }
+
+    pub const fn with_max_per_set_descriptors(mut self, max_per_set_descriptors: u32) -> Self {
+        self.inner.maxPerSetDescriptors = max_per_set_descriptors;
+        self
+    }
+
+    pub const fn with_max_memory_allocation_size(
+        mut self,
+        max_memory_allocation_size: DeviceSize,
+    ) -> Self {
+        self.inner.maxMemoryAllocationSize = max_memory_allocation_size;
+        self
+    }
+}
+
+/*
+   Physical Device Vulkan 1.2 Properties
+*/
+
+vulkan_create_info_lifetime!(
+    PhysicalDeviceVulkan12Properties,
+    VkPhysicalDeviceVulkan12Properties,
+    VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_VULKAN_1_2_PROPERTIES
+);
+
+impl<'a> PhysicalDeviceVulkan12Properties<'a> {
+    pub const fn driver_id(&self) -> DriverId {
+        DriverId::from_raw(self.inner.driverID)
+    }
+
+    pub fn driver_name(&self) -> &CStr {
+        unsafe { CStr::from_ptr(self.inner.driverName.as_ptr()) }
+    }
+
+    pub fn driver_info(&self) -> &CStr {
+        unsafe { CStr::from_ptr(self.inner.driverInfo.as_ptr()) }
+    }
+
+    pub const fn conformance_version(&self) -> ConformanceVersion {
+        self.inner.conformanceVersion
+    }
+
+    pub const fn denorm_behavior_independence(&self) -> ShaderFloatControlsIndependence {
+        ShaderFloatControlsIndependence::from_raw(self.inner.denormBehaviorIndependence)
+    }
+
+    pub const fn rounding_mode_independence(&self) -> ShaderFloatControlsIndependence {
+        ShaderFloatControlsIndependence::from_raw(self.inner.roundingModeIndependence)
+    }
+
+    pub const fn shader_signed_zero_inf_nan_preserve_float16(&self) -> bool {
+        self.inner.shaderSignedZeroInfNanPreserveFloat16 != 0
+    }
+
+    pub const fn shader_signed_zero_inf_nan_preserve_float32(&self) -> bool {
+        self.inner.shaderSignedZeroInfNanPreserveFloat32 != 0
+    }
+
+    pub const fn shader_signed_zero_inf_nan_preserve_float64(&self) -> bool {
+        self.inner.shaderSignedZeroInfNanPreserveFloat64 != 0
+    }
+
+    pub const fn shader_denorm_preserve_float16(&self) -> bool {
+        self.inner.shaderDenormPreserveFloat16 != 0
+    }
+
+    pub const fn shader_denorm_preserve_float32(&self) -> bool {
+        self.inner.shaderDenormPreserveFloat32 != 0
+    }
+
+    pub const fn shader_denorm_preserve_float64(&self) -> bool {
+        self.inner.shaderDenormPreserveFloat64 != 0
+    }
+
+    pub const fn shader_denorm_flush_to_zero_float16(&self) -> bool {
+        self.inner.shaderDenormFlushToZeroFloat16 != 0
+    }
+
+    pub const fn shader_denorm_flush_to_zero_float32(&self) -> bool {
+        self.inner.shaderDenormFlushToZeroFloat32 != 0
+    }
+
+    pub const fn shader_denorm_flush_to_zero_float64(&self) -> bool {
+        self.inner.shaderDenormFlushToZeroFloat64 != 0
+    }
+
+    pub const fn shader_rounding_mode_rte_float16(&self) -> bool {
+        self.inner.shaderRoundingModeRTEFloat16 != 0
+    }
+
+    pub const fn shader_rounding_mode_rte_float32(&self) -> bool {
+        self.inner.shaderRoundingModeRTEFloat32 != 0
+    }
+
+    pub const fn shader_rounding_mode_rte_float64(&self) -> bool {
+        self.inner.shaderRoundingModeRTEFloat64 != 0
+    }
+
+    pub const fn shader_rounding_mode_rtz_float16(&self) -> bool {
+        self.inner.shaderRoundingModeRTZFloat16 != 0
+    }
+
+    pub const fn shader_rounding_mode_rtz_float32(&self) -> bool {
+        self.inner.shaderRoundingModeRTZFloat32 != 0
+    }
+
+    pub const fn shader_rounding_mode_rtz_float64(&self) -> bool {
+        self.inner.shaderRoundingModeRTZFloat64 != 0
+    }
+
+    pub const fn max_update_after_bind_descriptors_in_all_pools(&self) -> u32 {
+        self.inner.maxUpdateAfterBindDescriptorsInAllPools
+    }
+
+    pub const fn shader_uniform_buffer_array_non_uniform_indexing_native(&self) -> bool {
+        self.inner.shaderUniformBufferArrayNonUniformIndexingNative != 0
+    }
+
+    pub const fn shader_sampled_image_array_non_uniform_indexing_native(&self) -> bool {
+        self.inner.shaderSampledImageArrayNonUniformIndexingNative != 0
+    }
+
+    pub const fn shader_storage_buffer_array_non_uniform_indexing_native(&self) -> bool {
+        self.inner.shaderStorageBufferArrayNonUniformIndexingNative != 0
+    }
+
+    pub const fn shader_storage_image_array_non_uniform_indexing_native(&self) -> bool {
+        self.inner.shaderStorageImageArrayNonUniformIndexingNative != 0
+    }
+
+    pub const fn shader_input_attachment_array_non_uniform_indexing_native(&self) -> bool {
+        self.inner
+            .shaderInputAttachmentArrayNonUniformIndexingNative
+            != 0
+    }
+
+    pub const fn robust_buffer_access_update_after_bind(&self) -> bool {
+        self.inner.robustBufferAccessUpdateAfterBind != 0
+    }
+
+    pub const fn quad_divergent_implicit_lod(&self) -> bool {
+        self.inner.quadDivergentImplicitLod != 0
+    }
+
+    pub const fn max_per_stage_descriptor_update_after_bind_samplers(&self) -> u32 {
+        self.inner.maxPerStageDescriptorUpdateAfterBindSamplers
+    }
+
+    pub const fn max_per_stage_descriptor_update_after_bind_uniform_buffers(&self) -> u32 {
+        self.inner
+            .maxPerStageDescriptorUpdateAfterBindUniformBuffers
+    }
+
+    pub const fn max_per_stage_descriptor_update_after_bind_storage_buffers(&self) -> u32 {
+        self.inner
+            .maxPerStageDescriptorUpdateAfterBindStorageBuffers
+    }
+
+    pub const fn max_per_stage_descriptor_update_after_bind_sampled_images(&self) -> u32 {
+        self.inner.maxPerStageDescriptorUpdateAfterBindSampledImages
+    }
+
+    pub const fn max_per_stage_descriptor_update_after_bind_storage_images(&self) -> u32 {
+        self.inner.maxPerStageDescriptorUpdateAfterBindStorageImages
+    }
+
+    pub const fn max_per_stage_descriptor_update_after_bind_input_attachments(&self) -> u32 {
+        self.inner
+            .maxPerStageDescriptorUpdateAfterBindInputAttachments
+    }
+
+    pub const fn max_per_stage_update_after_bind_resources(&self) -> u32 {
+        self.inner.maxPerStageUpdateAfterBindResources
+    }
+
+    pub const fn max_descriptor_set_update_after_bind_samplers(&self) -> u32 {
+        self.inner.maxDescriptorSetUpdateAfterBindSamplers
+    }
+
+    pub const fn max_descriptor_set_update_after_bind_uniform_buffers(&self) -> u32 {
+        self.inner.maxDescriptorSetUpdateAfterBindUniformBuffers
+    }
+
+    pub const fn max_descriptor_set_update_after_bind_uniform_buffers_dynamic(&self) -> u32 {
+        self.inner
+            .maxDescriptorSetUpdateAfterBindUniformBuffersDynamic
+    }
+
+    pub const fn max_descriptor_set_update_after_bind_storage_buffers(&self) -> u32 {
+        self.inner.maxDescriptorSetUpdateAfterBindStorageBuffers
+    }
+
+    pub const fn max_descriptor_set_update_after_bind_storage_buffers_dynamic(&self) -> u32 {
+        self.inner
+            .maxDescriptorSetUpdateAfterBindStorageBuffersDynamic
+    }
+
+    pub const fn max_descriptor_set_update_after_bind_sampled_images(&self) -> u32 {
+        self.inner.maxDescriptorSetUpdateAfterBindSampledImages
+    }
+
+    pub const fn max_descriptor_set_update_after_bind_storage_images(&self) -> u32 {
+        self.inner.maxDescriptorSetUpdateAfterBindStorageImages
+    }
+
+    pub const fn max_descriptor_set_update_after_bind_input_attachments(&self) -> u32 {
+        self.inner.maxDescriptorSetUpdateAfterBindInputAttachments
+    }
+
+    pub const fn supported_depth_resolve_modes(&self) -> ResolveModeFlags {
+        ResolveModeFlags::from_bits_truncate(self.inner.supportedDepthResolveModes)
+    }
+
+    pub const fn supported_stencil_resolve_modes(&self) -> ResolveModeFlags {
+        ResolveModeFlags::from_bits_truncate(self.inner.supportedStencilResolveModes)
+    }
+
+    pub const fn independent_resolve_none(&self) -> bool {
+        self.inner.independentResolveNone != 0
+    }
+
+    pub const fn independent_resolve(&self) -> bool {
+        self.inner.independentResolve != 0
+    }
+
+    pub const fn filter_minmax_single_component_formats(&self) -> bool {
+        self.inner.filterMinmaxSingleComponentFormats != 0
+    }
+
+    pub const fn filter_minmax_image_component_mapping(&self) -> bool {
+        self.inner.filterMinmaxImageComponentMapping != 0
+    }
+
+    pub const fn max_timeline_semaphore_value_difference(&self) -> u64 {
+        self.inner.maxTimelineSemaphoreValueDifference
+    }
+
+    pub const fn framebuffer_integer_color_sample_counts(&self) -> SampleCountFlags {
+        SampleCountFlags::from_bits_truncate(self.inner.framebufferIntegerColorSampleCounts)
+    }
+
+    pub const fn with_driver_id(mut self, driver_id: DriverId) -> Self {
+        self.inner.driverID = driver_id.as_raw();
+        self
+    }
+
+    pub const fn with_conformance_version(
+        mut self,
+        conformance_version: ConformanceVersion,
+    ) -> Self {
+        self.inner.conformanceVersion = conformance_version;
+        self
+    }
+
+    pub const fn with_denorm_behavior_independence(
+        mut self,
+        denorm_behavior_independence: ShaderFloatControlsIndependence,
+    ) -> Self {
+        self.inner.denormBehaviorIndependence = denorm_behavior_independence.as_raw();
+        self
+    }
+
+    pub const fn with_rounding_mode_independence(
+        mut self,
+        rounding_mode_independence: ShaderFloatControlsIndependence,
+    ) -> Self {
+        self.inner.roundingModeIndependence = rounding_mode_independence.as_raw();
+        self
+    }
+
+    pub const fn with_shader_signed_zero_inf_nan_preserve_float16(
+        mut self,
+        shader_signed_zero_inf_nan_preserve_float16: bool,
+    ) -> Self {
+        self.inner.shaderSignedZeroInfNanPreserveFloat16 =
+            shader_signed_zero_inf_nan_preserve_float16 as VkBool32;
+        self
+    }
+
+    pub const fn with_shader_signed_zero_inf_nan_preserve_float32(
+        mut self,
+        shader_signed_zero_inf_nan_preserve_float32: bool,
+    ) -> Self {
+        self.inner.shaderSignedZeroInfNanPreserveFloat32 =
+            shader_signed_zero_inf_nan_preserve_float32 as VkBool32;
+        self
+    }
+
+    pub const fn with_shader_signed_zero_inf_nan_preserve_float64(
+        mut self,
+        shader_signed_zero_inf_nan_preserve_float64: bool,
+    ) -> Self {
+        self.inner.shaderSignedZeroInfNanPreserveFloat64 =
+            shader_signed_zero_inf_nan_preserve_float64 as VkBool32;
+        self
+    }
+
+    pub const fn with_shader_denorm_preserve_float16(
+        mut self,
+        shader_denorm_preserve_float16: bool,
+    ) -> Self {
+        self.inner.shaderDenormPreserveFloat16 = shader_denorm_preserve_float16 as VkBool32;
+        self
+    }
+
+    pub const fn with_shader_denorm_preserve_float32(
+        mut self,
+        shader_denorm_preserve_float32: bool,
+    ) -> Self {
+        self.inner.shaderDenormPreserveFloat32 = shader_denorm_preserve_float32 as VkBool32;
+        self
+    }
+
+    pub const fn with_shader_denorm_preserve_float64(
+        mut self,
+        shader_denorm_preserve_float64: bool,
+    ) -> Self {
+        self.inner.shaderDenormPreserveFloat64 = shader_denorm_preserve_float64 as VkBool32;
+        self
+    }
+
+    pub const fn with_shader_denorm_flush_to_zero_float16(
+        mut self,
+        shader_denorm_flush_to_zero_float16: bool,
+    ) -> Self {
+        self.inner.shaderDenormFlushToZeroFloat16 = shader_denorm_flush_to_zero_float16 as VkBool32;
+        self
+    }
+
+    pub const fn with_shader_denorm_flush_to_zero_float32(
+        mut self,
+        shader_denorm_flush_to_zero_float32: bool,
+    ) -> Self {
+        self.inner.shaderDenormFlushToZeroFloat32 = shader_denorm_flush_to_zero_float32 as VkBool32;
+        self
+    }
+
+    pub const fn with_shader_denorm_flush_to_zero_float64(
+        mut self,
+        shader_denorm_flush_to_zero_float64: bool,
+    ) -> Self {
+        self.inner.shaderDenormFlushToZeroFloat64 = shader_denorm_flush_to_zero_float64 as VkBool32;
+        self
+    }
+
+    pub const fn with_shader_rounding_mode_rte_float16(
+        mut self,
+        shader_rounding_mode_rte_float16: bool,
+    ) -> Self {
+        self.inner.shaderRoundingModeRTEFloat16 = shader_rounding_mode_rte_float16 as VkBool32;
+        self
+    }
+
+    pub const fn with_shader_rounding_mode_rte_float32(
+        mut self,
+        shader_rounding_mode_rte_float32: bool,
+    ) -> Self {
+        self.inner.shaderRoundingModeRTEFloat32 = shader_rounding_mode_rte_float32 as VkBool32;
+        self
+    }
+
+    pub const fn with_shader_rounding_mode_rte_float64(
+        mut self,
+        shader_rounding_mode_rte_float64: bool,
+    ) -> Self {
+        self.inner.shaderRoundingModeRTEFloat64 = shader_rounding_mode_rte_float64 as VkBool32;
+        self
+    }
+
+    pub const fn with_shader_rounding_mode_rtz_float16(
+        mut self,
+        shader_rounding_mode_rtz_float16: bool,
+    ) -> Self {
+        self.inner.shaderRoundingModeRTZFloat16 = shader_rounding_mode_rtz_float16 as VkBool32;
+        self
+    }
+
+    pub const fn with_shader_rounding_mode_rtz_float32(
+        mut self,
+        shader_rounding_mode_rtz_float32: bool,
+    ) -> Self {
+        self.inner.shaderRoundingModeRTZFloat32 = shader_rounding_mode_rtz_float32 as VkBool32;
+        self
+    }
+
+    pub const fn with_shader_rounding_mode_rtz_float64(
+        mut self,
+        shader_rounding_mode_rtz_float64: bool,
+    ) -> Self {
+        self.inner.shaderRoundingModeRTZFloat64 = shader_rounding_mode_rtz_float64 as VkBool32;
+        self
+    }
+
+    pub const fn with_max_update_after_bind_descriptors_in_all_pools(
+        mut self,
+        max_update_after_bind_descriptors_in_all_pools: u32,
+    ) -> Self {
+        self.inner.maxUpdateAfterBindDescriptorsInAllPools =
+            max_update_after_bind_descriptors_in_all_pools;
+        self
+    }
+
+    pub const fn with_shader_uniform_buffer_array_non_uniform_indexing_native(
+        mut self,
+        shader_uniform_buffer_array_non_uniform_indexing_native: bool,
+    ) -> Self {
+        self.inner.shaderUniformBufferArrayNonUniformIndexingNative =
+            shader_uniform_buffer_array_non_uniform_indexing_native as VkBool32;
+        self
+    }
+
+    pub const fn with_shader_sampled_image_array_non_uniform_indexing_native(
+        mut self,
+        shader_sampled_image_array_non_uniform_indexing_native: bool,
+    ) -> Self {
+        self.inner.shaderSampledImageArrayNonUniformIndexingNative =
+            shader_sampled_image_array_non_uniform_indexing_native as VkBool32;
+        self
+    }
+
+    pub const fn with_shader_storage_buffer_array_non_uniform_indexing_native(
+        mut self,
+        shader_storage_buffer_array_non_uniform_indexing_native: bool,
+    ) -> Self {
+        self.inner.shaderStorageBufferArrayNonUniformIndexingNative =
+            shader_storage_buffer_array_non_uniform_indexing_native as VkBool32;
+        self
+    }
+
+    pub const fn with_shader_storage_image_array_non_uniform_indexing_native(
+        mut self,
+        shader_storage_image_array_non_uniform_indexing_native: bool,
+    ) -> Self {
+        self.inner.shaderStorageImageArrayNonUniformIndexingNative =
+            shader_storage_image_array_non_uniform_indexing_native as VkBool32;
+        self
+    }
+
+    pub const fn with_shader_input_attachment_array_non_uniform_indexing_native(
+        mut self,
+        shader_input_attachment_array_non_uniform_indexing_native: bool,
+    ) -> Self {
+        self.inner
+            .shaderInputAttachmentArrayNonUniformIndexingNative =
+            shader_input_attachment_array_non_uniform_indexing_native as VkBool32;
+        self
+    }
+
+    pub const fn with_robust_buffer_access_update_after_bind(
+        mut self,
+        robust_buffer_access_update_after_bind: bool,
+    ) -> Self {
+        self.inner.robustBufferAccessUpdateAfterBind =
+            robust_buffer_access_update_after_bind as VkBool32;
+        self
+    }
+
+    pub const fn with_quad_divergent_implicit_lod(
+        mut self,
+        quad_divergent_implicit_lod: bool,
+    ) -> Self {
+        self.inner.quadDivergentImplicitLod = quad_divergent_implicit_lod as VkBool32;
+        self
+    }
+
+    pub const fn with_max_per_stage_descriptor_update_after_bind_samplers(
+        mut self,
+        max_per_stage_descriptor_update_after_bind_samplers: u32,
+    ) -> Self {
+        self.inner.maxPerStageDescriptorUpdateAfterBindSamplers =
+            max_per_stage_descriptor_update_after_bind_samplers;
+        self
+    }
+
+    pub const fn with_max_per_stage_descriptor_update_after_bind_uniform_buffers(
+        mut self,
+        max_per_stage_descriptor_update_after_bind_uniform_buffers: u32,
+    ) -> Self {
+        self.inner
+            .maxPerStageDescriptorUpdateAfterBindUniformBuffers =
+            max_per_stage_descriptor_update_after_bind_uniform_buffers;
+        self
+    }
+
+    pub const fn with_max_per_stage_descriptor_update_after_bind_storage_buffers(
+        mut self,
+        max_per_stage_descriptor_update_after_bind_storage_buffers: u32,
+    ) -> Self {
+        self.inner
+            .maxPerStageDescriptorUpdateAfterBindStorageBuffers =
+            max_per_stage_descriptor_update_after_bind_storage_buffers;
+        self
+    }
+
+    pub const fn with_max_per_stage_descriptor_update_after_bind_sampled_images(
+        mut self,
+        max_per_stage_descriptor_update_after_bind_sampled_images: u32,
+    ) -> Self {
+        self.inner.maxPerStageDescriptorUpdateAfterBindSampledImages =
+            max_per_stage_descriptor_update_after_bind_sampled_images;
+        self
+    }
+
+    pub const fn with_max_per_stage_descriptor_update_after_bind_storage_images(
+        mut self,
+        max_per_stage_descriptor_update_after_bind_storage_images: u32,
+    ) -> Self {
+        self.inner.maxPerStageDescriptorUpdateAfterBindStorageImages =
+            max_per_stage_descriptor_update_after_bind_storage_images;
+        self
+    }
+
+    pub const fn with_max_per_stage_descriptor_update_after_bind_input_attachments(
+        mut self,
+        max_per_stage_descriptor_update_after_bind_input_attachments: u32,
+    ) -> Self {
+        self.inner
+            .maxPerStageDescriptorUpdateAfterBindInputAttachments =
+            max_per_stage_descriptor_update_after_bind_input_attachments;
+        self
+    }
+
+    pub const fn with_max_per_stage_update_after_bind_resources(
+        mut self,
+        max_per_stage_update_after_bind_resources: u32,
+    ) -> Self {
+        self.inner.maxPerStageUpdateAfterBindResources = max_per_stage_update_after_bind_resources;
+        self
+    }
+
+    pub const fn with_max_descriptor_set_update_after_bind_samplers(
+        mut self,
+        max_descriptor_set_update_after_bind_samplers: u32,
+    ) -> Self {
+        self.inner.maxDescriptorSetUpdateAfterBindSamplers =
+            max_descriptor_set_update_after_bind_samplers;
+        self
+    }
+
+    pub const fn with_max_descriptor_set_update_after_bind_uniform_buffers(
+        mut self,
+        max_descriptor_set_update_after_bind_uniform_buffers: u32,
+    ) -> Self {
+        self.inner.maxDescriptorSetUpdateAfterBindUniformBuffers =
+            max_descriptor_set_update_after_bind_uniform_buffers;
+        self
+    }
+
+    pub const fn with_max_descriptor_set_update_after_bind_uniform_buffers_dynamic(
+        mut self,
+        max_descriptor_set_update_after_bind_uniform_buffers_dynamic: u32,
+    ) -> Self {
+        self.inner
+            .maxDescriptorSetUpdateAfterBindUniformBuffersDynamic =
+            max_descriptor_set_update_after_bind_uniform_buffers_dynamic;
+        self
+    }
+
+    pub const fn with_max_descriptor_set_update_after_bind_storage_buffers(
+        mut self,
+        max_descriptor_set_update_after_bind_storage_buffers: u32,
+    ) -> Self {
+        self.inner.maxDescriptorSetUpdateAfterBindStorageBuffers =
+            max_descriptor_set_update_after_bind_storage_buffers;
+        self
+    }
+
+    pub const fn with_max_descriptor_set_update_after_bind_storage_buffers_dynamic(
+        mut self,
+        max_descriptor_set_update_after_bind_storage_buffers_dynamic: u32,
+    ) -> Self {
+        self.inner
+            .maxDescriptorSetUpdateAfterBindStorageBuffersDynamic =
+            max_descriptor_set_update_after_bind_storage_buffers_dynamic;
+        self
+    }
+
+    pub const fn with_max_descriptor_set_update_after_bind_sampled_images(
+        mut self,
+        max_descriptor_set_update_after_bind_sampled_images: u32,
+    ) -> Self {
+        self.inner.maxDescriptorSetUpdateAfterBindSampledImages =
+            max_descriptor_set_update_after_bind_sampled_images;
+        self
+    }
+
+    pub const fn with_max_descriptor_set_update_after_bind_storage_images(
+        mut self,
+        max_descriptor_set_update_after_bind_storage_images: u32,
+    ) -> Self {
+        self.inner.maxDescriptorSetUpdateAfterBindStorageImages =
+            max_descriptor_set_update_after_bind_storage_images;
+        self
+    }
+
+    pub const fn with_max_descriptor_set_update_after_bind_input_attachments(
+        mut self,
+        max_descriptor_set_update_after_bind_input_attachments: u32,
+    ) -> Self {
+        self.inner.maxDescriptorSetUpdateAfterBindInputAttachments =
+            max_descriptor_set_update_after_bind_input_attachments;
+        self
+    }
+
+    pub const fn with_supported_depth_resolve_modes(
+        mut self,
+        supported_depth_resolve_modes: ResolveModeFlags,
+    ) -> Self {
+        self.inner.supportedDepthResolveModes = supported_depth_resolve_modes.bits();
+        self
+    }
+
+    pub const fn with_supported_stencil_resolve_modes(
+        mut self,
+        supported_stencil_resolve_modes: ResolveModeFlags,
+    ) -> Self {
+        self.inner.supportedStencilResolveModes = supported_stencil_resolve_modes.bits();
+        self
+    }
+
+    pub const fn with_independent_resolve_none(mut self, independent_resolve_none: bool) -> Self {
+        self.inner.independentResolveNone = independent_resolve_none as VkBool32;
+        self
+    }
+
+    pub const fn with_independent_resolve(mut self, independent_resolve: bool) -> Self {
+        self.inner.independentResolve = independent_resolve as VkBool32;
+        self
+    }
+
+    pub const fn with_filter_minmax_single_component_formats(
+        mut self,
+        filter_minmax_single_component_formats: bool,
+    ) -> Self {
+        self.inner.filterMinmaxSingleComponentFormats =
+            filter_minmax_single_component_formats as VkBool32;
+        self
+    }
+
+    pub const fn with_filter_minmax_image_component_mapping(
+        mut self,
+        filter_minmax_image_component_mapping: bool,
+    ) -> Self {
+        self.inner.filterMinmaxImageComponentMapping =
+            filter_minmax_image_component_mapping as VkBool32;
+        self
+    }
+
+    pub const fn with_max_timeline_semaphore_value_difference(
+        mut self,
+        max_timeline_semaphore_value_difference: u64,
+    ) -> Self {
+        self.inner.maxTimelineSemaphoreValueDifference = max_timeline_semaphore_value_difference;
+        self
+    }
+
+    pub const fn with_framebuffer_integer_color_sample_counts(
+        mut self,
+        framebuffer_integer_color_sample_counts: SampleCountFlags,
+    ) -> Self {
+        self.inner.framebufferIntegerColorSampleCounts =
+            framebuffer_integer_color_sample_counts.bits();
+        self
+    }
+}
+
+/*
+   Physical Device Vulkan 1.3 Properties
+*/
+
+vulkan_create_info_lifetime!(
+    PhysicalDeviceVulkan13Properties,
+    VkPhysicalDeviceVulkan13Properties,
+    VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_VULKAN_1_3_PROPERTIES
+);
+
+impl<'a> PhysicalDeviceVulkan13Properties<'a> {
+    pub const fn min_subgroup_size(&self) -> u32 {
+        self.inner.minSubgroupSize
+    }
+
+    pub const fn max_subgroup_size(&self) -> u32 {
+        self.inner.maxSubgroupSize
+    }
+
+    pub const fn max_compute_workgroup_subgroups(&self) -> u32 {
+        self.inner.maxComputeWorkgroupSubgroups
+    }
+
+    pub const fn required_subgroup_size_stages(&self) -> ShaderStageFlags {
+        ShaderStageFlags::from_bits_truncate(self.inner.requiredSubgroupSizeStages)
+    }
+
+    pub const fn max_inline_uniform_block_size(&self) -> u32 {
+        self.inner.maxInlineUniformBlockSize
+    }
+
+    pub const fn max_per_stage_descriptor_inline_uniform_blocks(&self) -> u32 {
+        self.inner.maxPerStageDescriptorInlineUniformBlocks
+    }
+
+    pub const fn max_per_stage_descriptor_update_after_bind_inline_uniform_blocks(&self) -> u32 {
+        self.inner
+            .maxPerStageDescriptorUpdateAfterBindInlineUniformBlocks
+    }
+
+    pub const fn max_descriptor_set_inline_uniform_blocks(&self) -> u32 {
+        self.inner.maxDescriptorSetInlineUniformBlocks
+    }
+
+    pub const fn max_descriptor_set_update_after_bind_inline_uniform_blocks(&self) -> u32 {
+        self.inner
+            .maxDescriptorSetUpdateAfterBindInlineUniformBlocks
+    }
+
+    pub const fn max_inline_uniform_total_size(&self) -> u32 {
+        self.inner.maxInlineUniformTotalSize
+    }
+
+    pub const fn integer_dot_product_8_bit_unsigned_accelerated(&self) -> bool {
+        self.inner.integerDotProduct8BitUnsignedAccelerated != 0
+    }
+
+    pub const fn integer_dot_product_8_bit_signed_accelerated(&self) -> bool {
+        self.inner.integerDotProduct8BitSignedAccelerated != 0
+    }
+
+    pub const fn integer_dot_product_8_bit_mixed_signedness_accelerated(&self) -> bool {
+        self.inner.integerDotProduct8BitMixedSignednessAccelerated != 0
+    }
+
+    pub const fn integer_dot_product_4x8_bit_packed_unsigned_accelerated(&self) -> bool {
+        self.inner.integerDotProduct4x8BitPackedUnsignedAccelerated != 0
+    }
+
+    pub const fn integer_dot_product_4x8_bit_packed_signed_accelerated(&self) -> bool {
+        self.inner.integerDotProduct4x8BitPackedSignedAccelerated != 0
+    }
+
+    pub const fn integer_dot_product_4x8_bit_packed_mixed_signedness_accelerated(&self) -> bool {
+        self.inner
+            .integerDotProduct4x8BitPackedMixedSignednessAccelerated
+            != 0
+    }
+
+    pub const fn integer_dot_product_16_bit_unsigned_accelerated(&self) -> bool {
+        self.inner.integerDotProduct16BitUnsignedAccelerated != 0
+    }
+
+    pub const fn integer_dot_product_16_bit_signed_accelerated(&self) -> bool {
+        self.inner.integerDotProduct16BitSignedAccelerated != 0
+    }
+
+    pub const fn integer_dot_product_16_bit_mixed_signedness_accelerated(&self) -> bool {
+        self.inner.integerDotProduct16BitMixedSignednessAccelerated != 0
+    }
+
+    pub const fn integer_dot_product_32_bit_unsigned_accelerated(&self) -> bool {
+        self.inner.integerDotProduct32BitUnsignedAccelerated != 0
+    }
+
+    pub const fn integer_dot_product_32_bit_signed_accelerated(&self) -> bool {
+        self.inner.integerDotProduct32BitSignedAccelerated != 0
+    }
+
+    pub const fn integer_dot_product_32_bit_mixed_signedness_accelerated(&self) -> bool {
+        self.inner.integerDotProduct32BitMixedSignednessAccelerated != 0
+    }
+
+    pub const fn integer_dot_product_64_bit_unsigned_accelerated(&self) -> bool {
+        self.inner.integerDotProduct64BitUnsignedAccelerated != 0
+    }
+
+    pub const fn integer_dot_product_64_bit_signed_accelerated(&self) -> bool {
+        self.inner.integerDotProduct64BitSignedAccelerated != 0
+    }
+
+    pub const fn integer_dot_product_64_bit_mixed_signedness_accelerated(&self) -> bool {
+        self.inner.integerDotProduct64BitMixedSignednessAccelerated != 0
+    }
+
+    pub const fn integer_dot_product_accumulating_saturating_8_bit_unsigned_accelerated(
+        &self,
+    ) -> bool {
+        self.inner
+            .integerDotProductAccumulatingSaturating8BitUnsignedAccelerated
+            != 0
+    }
+
+    pub const fn integer_dot_product_accumulating_saturating_8_bit_signed_accelerated(
+        &self,
+    ) -> bool {
+        self.inner
+            .integerDotProductAccumulatingSaturating8BitSignedAccelerated
+            != 0
+    }
+
+    pub const fn integer_dot_product_accumulating_saturating_8_bit_mixed_signedness_accelerated(
+        &self,
+    ) -> bool {
+        self.inner
+            .integerDotProductAccumulatingSaturating8BitMixedSignednessAccelerated
+            != 0
+    }
+
+    pub const fn integer_dot_product_accumulating_saturating_4x8_bit_packed_unsigned_accelerated(
+        &self,
+    ) -> bool {
+        self.inner
+            .integerDotProductAccumulatingSaturating4x8BitPackedUnsignedAccelerated
+            != 0
+    }
+
+    pub const fn integer_dot_product_accumulating_saturating_4x8_bit_packed_signed_accelerated(
+        &self,
+    ) -> bool {
+        self.inner
+            .integerDotProductAccumulatingSaturating4x8BitPackedSignedAccelerated
+            != 0
+    }
+
+    pub const fn integer_dot_product_accumulating_saturating_4x8_bit_packed_mixed_signedness_accelerated(
+        &self,
+    ) -> bool {
+        self.inner
+            .integerDotProductAccumulatingSaturating4x8BitPackedMixedSignednessAccelerated
+            != 0
+    }
+
+    pub const fn integer_dot_product_accumulating_saturating_16_bit_unsigned_accelerated(
+        &self,
+    ) -> bool {
+        self.inner
+            .integerDotProductAccumulatingSaturating16BitUnsignedAccelerated
+            != 0
+    }
+
+    pub const fn integer_dot_product_accumulating_saturating_16_bit_signed_accelerated(
+        &self,
+    ) -> bool {
+        self.inner
+            .integerDotProductAccumulatingSaturating16BitSignedAccelerated
+            != 0
+    }
+
+    pub const fn integer_dot_product_accumulating_saturating_16_bit_mixed_signedness_accelerated(
+        &self,
+    ) -> bool {
+        self.inner
+            .integerDotProductAccumulatingSaturating16BitMixedSignednessAccelerated
+            != 0
+    }
+
+    pub const fn integer_dot_product_accumulating_saturating_32_bit_unsigned_accelerated(
+        &self,
+    ) -> bool {
+        self.inner
+            .integerDotProductAccumulatingSaturating32BitUnsignedAccelerated
+            != 0
+    }
+
+    pub const fn integer_dot_product_accumulating_saturating_32_bit_signed_accelerated(
+        &self,
+    ) -> bool {
+        self.inner
+            .integerDotProductAccumulatingSaturating32BitSignedAccelerated
+            != 0
+    }
+
+    pub const fn integer_dot_product_accumulating_saturating_32_bit_mixed_signedness_accelerated(
+        &self,
+    ) -> bool {
+        self.inner
+            .integerDotProductAccumulatingSaturating32BitMixedSignednessAccelerated
+            != 0
+    }
+
+    pub const fn integer_dot_product_accumulating_saturating_64_bit_unsigned_accelerated(
+        &self,
+    ) -> bool {
+        self.inner
+            .integerDotProductAccumulatingSaturating64BitUnsignedAccelerated
+            != 0
+    }
+
+    pub const fn integer_dot_product_accumulating_saturating_64_bit_signed_accelerated(
+        &self,
+    ) -> bool {
+        self.inner
+            .integerDotProductAccumulatingSaturating64BitSignedAccelerated
+            != 0
+    }
+
+    pub const fn integer_dot_product_accumulating_saturating_64_bit_mixed_signedness_accelerated(
+        &self,
+    ) -> bool {
+        self.inner
+            .integerDotProductAccumulatingSaturating64BitMixedSignednessAccelerated
+            != 0
+    }
+
+    pub const fn storage_texel_buffer_offset_alignment_bytes(&self) -> DeviceSize {
+        self.inner.storageTexelBufferOffsetAlignmentBytes
+    }
+
+    pub const fn storage_texel_buffer_offset_single_texel_alignment(&self) -> bool {
+        self.inner.storageTexelBufferOffsetSingleTexelAlignment != 0
+    }
+
+    pub const fn uniform_texel_buffer_offset_alignment_bytes(&self) -> DeviceSize {
+        self.inner.uniformTexelBufferOffsetAlignmentBytes
+    }
+
+    pub const fn uniform_texel_buffer_offset_single_texel_alignment(&self) -> bool {
+        self.inner.uniformTexelBufferOffsetSingleTexelAlignment != 0
+    }
+
+    pub const fn max_buffer_size(&self) -> DeviceSize {
+        self.inner.maxBufferSize
+    }
+
+    pub const fn with_min_subgroup_size(mut self, min_subgroup_size: u32) -> Self {
+        self.inner.minSubgroupSize = min_subgroup_size;
+        self
+    }
+
+    pub const fn with_max_subgroup_size(mut self, max_subgroup_size: u32) -> Self {
+        self.inner.maxSubgroupSize = max_subgroup_size;
+        self
+    }
+
+    pub const fn with_max_compute_workgroup_subgroups(
+        mut self,
+        max_compute_workgroup_subgroups: u32,
+    ) -> Self {
+        self.inner.maxComputeWorkgroupSubgroups = max_compute_workgroup_subgroups;
+        self
+    }
+
+    pub const fn with_required_subgroup_size_stages(
+        mut self,
+        required_subgroup_size_stages: ShaderStageFlags,
+    ) -> Self {
+        self.inner.requiredSubgroupSizeStages = required_subgroup_size_stages.bits();
+        self
+    }
+
+    pub const fn with_max_inline_uniform_block_size(
+        mut self,
+        max_inline_uniform_block_size: u32,
+    ) -> Self {
+        self.inner.maxInlineUniformBlockSize = max_inline_uniform_block_size;
+        self
+    }
+
+    pub const fn with_max_per_stage_descriptor_inline_uniform_blocks(
+        mut self,
+        max_per_stage_descriptor_inline_uniform_blocks: u32,
+    ) -> Self {
+        self.inner.maxPerStageDescriptorInlineUniformBlocks =
+            max_per_stage_descriptor_inline_uniform_blocks;
+        self
+    }
+
+    pub const fn with_max_per_stage_descriptor_update_after_bind_inline_uniform_blocks(
+        mut self,
+        max_per_stage_descriptor_update_after_bind_inline_uniform_blocks: u32,
+    ) -> Self {
+        self.inner
+            .maxPerStageDescriptorUpdateAfterBindInlineUniformBlocks =
+            max_per_stage_descriptor_update_after_bind_inline_uniform_blocks;
+        self
+    }
+
+    pub const fn with_max_descriptor_set_inline_uniform_blocks(
+        mut self,
+        max_descriptor_set_inline_uniform_blocks: u32,
+    ) -> Self {
+        self.inner.maxDescriptorSetInlineUniformBlocks = max_descriptor_set_inline_uniform_blocks;
+        self
+    }
+
+    pub const fn with_max_descriptor_set_update_after_bind_inline_uniform_blocks(
+        mut self,
+        max_descriptor_set_update_after_bind_inline_uniform_blocks: u32,
+    ) -> Self {
+        self.inner
+            .maxDescriptorSetUpdateAfterBindInlineUniformBlocks =
+            max_descriptor_set_update_after_bind_inline_uniform_blocks;
+        self
+    }
+
+    pub const fn with_max_inline_uniform_total_size(
+        mut self,
+        max_inline_uniform_total_size: u32,
+    ) -> Self {
+        self.inner.maxInlineUniformTotalSize = max_inline_uniform_total_size;
+        self
+    }
+
+    pub const fn with_integer_dot_product_8_bit_unsigned_accelerated(
+        mut self,
+        integer_dot_product_8_bit_unsigned_accelerated: bool,
+    ) -> Self {
+        self.inner.integerDotProduct8BitUnsignedAccelerated =
+            integer_dot_product_8_bit_unsigned_accelerated as VkBool32;
+        self
+    }
+
+    pub const fn with_integer_dot_product_8_bit_signed_accelerated(
+        mut self,
+        integer_dot_product_8_bit_signed_accelerated: bool,
+    ) -> Self {
+        self.inner.integerDotProduct8BitSignedAccelerated =
+            integer_dot_product_8_bit_signed_accelerated as VkBool32;
+        self
+    }
+
+    pub const fn with_integer_dot_product_8_bit_mixed_signedness_accelerated(
+        mut self,
+        integer_dot_product_8_bit_mixed_signedness_accelerated: bool,
+    ) -> Self {
+        self.inner.integerDotProduct8BitMixedSignednessAccelerated =
+            integer_dot_product_8_bit_mixed_signedness_accelerated as VkBool32;
+        self
+    }
+
+    pub const fn with_integer_dot_product_4x8_bit_packed_unsigned_accelerated(
+        mut self,
+        integer_dot_product_4x8_bit_packed_unsigned_accelerated: bool,
+    ) -> Self {
+        self.inner.integerDotProduct4x8BitPackedUnsignedAccelerated =
+            integer_dot_product_4x8_bit_packed_unsigned_accelerated as VkBool32;
+        self
+    }
+
+    pub const fn with_integer_dot_product_4x8_bit_packed_signed_accelerated(
+        mut self,
+        integer_dot_product_4x8_bit_packed_signed_accelerated: bool,
+    ) -> Self {
+        self.inner.integerDotProduct4x8BitPackedSignedAccelerated =
+            integer_dot_product_4x8_bit_packed_signed_accelerated as VkBool32;
+        self
+    }
+
+    pub const fn with_integer_dot_product_4x8_bit_packed_mixed_signedness_accelerated(
+        mut self,
+        integer_dot_product_4x8_bit_packed_mixed_signedness_accelerated: bool,
+    ) -> Self {
+        self.inner
+            .integerDotProduct4x8BitPackedMixedSignednessAccelerated =
+            integer_dot_product_4x8_bit_packed_mixed_signedness_accelerated as VkBool32;
+        self
+    }
+
+    pub const fn with_integer_dot_product_16_bit_unsigned_accelerated(
+        mut self,
+        integer_dot_product_16_bit_unsigned_accelerated: bool,
+    ) -> Self {
+        self.inner.integerDotProduct16BitUnsignedAccelerated =
+            integer_dot_product_16_bit_unsigned_accelerated as VkBool32;
+        self
+    }
+
+    pub const fn with_integer_dot_product_16_bit_signed_accelerated(
+        mut self,
+        integer_dot_product_16_bit_signed_accelerated: bool,
+    ) -> Self {
+        self.inner.integerDotProduct16BitSignedAccelerated =
+            integer_dot_product_16_bit_signed_accelerated as VkBool32;
+        self
+    }
+
+    pub const fn with_integer_dot_product_16_bit_mixed_signedness_accelerated(
+        mut self,
+        integer_dot_product_16_bit_mixed_signedness_accelerated: bool,
+    ) -> Self {
+        self.inner.integerDotProduct16BitMixedSignednessAccelerated =
+            integer_dot_product_16_bit_mixed_signedness_accelerated as VkBool32;
+        self
+    }
+
+    pub const fn with_integer_dot_product_32_bit_unsigned_accelerated(
+        mut self,
+        integer_dot_product_32_bit_unsigned_accelerated: bool,
+    ) -> Self {
+        self.inner.integerDotProduct32BitUnsignedAccelerated =
+            integer_dot_product_32_bit_unsigned_accelerated as VkBool32;
+        self
+    }
+
+    pub const fn with_integer_dot_product_32_bit_signed_accelerated(
+        mut self,
+        integer_dot_product_32_bit_signed_accelerated: bool,
+    ) -> Self {
+        self.inner.integerDotProduct32BitSignedAccelerated =
+            integer_dot_product_32_bit_signed_accelerated as VkBool32;
+        self
+    }
+
+    pub const fn with_integer_dot_product_32_bit_mixed_signedness_accelerated(
+        mut self,
+        integer_dot_product_32_bit_mixed_signedness_accelerated: bool,
+    ) -> Self {
+        self.inner.integerDotProduct32BitMixedSignednessAccelerated =
+            integer_dot_product_32_bit_mixed_signedness_accelerated as VkBool32;
+        self
+    }
+
+    pub const fn with_integer_dot_product_64_bit_unsigned_accelerated(
+        mut self,
+        integer_dot_product_64_bit_unsigned_accelerated: bool,
+    ) -> Self {
+        self.inner.integerDotProduct64BitUnsignedAccelerated =
+            integer_dot_product_64_bit_unsigned_accelerated as VkBool32;
+        self
+    }
+
+    pub const fn with_integer_dot_product_64_bit_signed_accelerated(
+        mut self,
+        integer_dot_product_64_bit_signed_accelerated: bool,
+    ) -> Self {
+        self.inner.integerDotProduct64BitSignedAccelerated =
+            integer_dot_product_64_bit_signed_accelerated as VkBool32;
+        self
+    }
+
+    pub const fn with_integer_dot_product_64_bit_mixed_signedness_accelerated(
+        mut self,
+        integer_dot_product_64_bit_mixed_signedness_accelerated: bool,
+    ) -> Self {
+        self.inner.integerDotProduct64BitMixedSignednessAccelerated =
+            integer_dot_product_64_bit_mixed_signedness_accelerated as VkBool32;
+        self
+    }
+
+    pub const fn with_integer_dot_product_accumulating_saturating_8_bit_unsigned_accelerated(
+        mut self,
+        integer_dot_product_accumulating_saturating_8_bit_unsigned_accelerated: bool,
+    ) -> Self {
+        self.inner
+            .integerDotProductAccumulatingSaturating8BitUnsignedAccelerated =
+            integer_dot_product_accumulating_saturating_8_bit_unsigned_accelerated as VkBool32;
+        self
+    }
+
+    pub const fn with_integer_dot_product_accumulating_saturating_8_bit_signed_accelerated(
+        mut self,
+        integer_dot_product_accumulating_saturating_8_bit_signed_accelerated: bool,
+    ) -> Self {
+        self.inner
+            .integerDotProductAccumulatingSaturating8BitSignedAccelerated =
+            integer_dot_product_accumulating_saturating_8_bit_signed_accelerated as VkBool32;
+        self
+    }
+
+    pub const fn with_integer_dot_product_accumulating_saturating_8_bit_mixed_signedness_accelerated(
+        mut self,
+        integer_dot_product_accumulating_saturating_8_bit_mixed_signedness_accelerated: bool,
+    ) -> Self {
+        self.inner
+            .integerDotProductAccumulatingSaturating8BitMixedSignednessAccelerated =
+            integer_dot_product_accumulating_saturating_8_bit_mixed_signedness_accelerated
+                as VkBool32;
+        self
+    }
+
+    pub const fn with_integer_dot_product_accumulating_saturating_4x8_bit_packed_unsigned_accelerated(
+        mut self,
+        integer_dot_product_accumulating_saturating_4x8_bit_packed_unsigned_accelerated: bool,
+    ) -> Self {
+        self.inner
+            .integerDotProductAccumulatingSaturating4x8BitPackedUnsignedAccelerated =
+            integer_dot_product_accumulating_saturating_4x8_bit_packed_unsigned_accelerated
+                as VkBool32;
+        self
+    }
+
+    pub const fn with_integer_dot_product_accumulating_saturating_4x8_bit_packed_signed_accelerated(
+        mut self,
+        integer_dot_product_accumulating_saturating_4x8_bit_packed_signed_accelerated: bool,
+    ) -> Self {
+        self.inner
+            .integerDotProductAccumulatingSaturating4x8BitPackedSignedAccelerated =
+            integer_dot_product_accumulating_saturating_4x8_bit_packed_signed_accelerated
+                as VkBool32;
+        self
+    }
+
+    pub const fn with_integer_dot_product_accumulating_saturating_4x8_bit_packed_mixed_signedness_accelerated(
+        mut self,
+        integer_dot_product_accumulating_saturating_4x8_bit_packed_mixed_signedness_accelerated: bool,
+    ) -> Self {
+        self.inner
+            .integerDotProductAccumulatingSaturating4x8BitPackedMixedSignednessAccelerated =
+            integer_dot_product_accumulating_saturating_4x8_bit_packed_mixed_signedness_accelerated
+                as VkBool32;
+        self
+    }
+
+    pub const fn with_integer_dot_product_accumulating_saturating_16_bit_unsigned_accelerated(
+        mut self,
+        integer_dot_product_accumulating_saturating_16_bit_unsigned_accelerated: bool,
+    ) -> Self {
+        self.inner
+            .integerDotProductAccumulatingSaturating16BitUnsignedAccelerated =
+            integer_dot_product_accumulating_saturating_16_bit_unsigned_accelerated as VkBool32;
+        self
+    }
+
+    pub const fn with_integer_dot_product_accumulating_saturating_16_bit_signed_accelerated(
+        mut self,
+        integer_dot_product_accumulating_saturating_16_bit_signed_accelerated: bool,
+    ) -> Self {
+        self.inner
+            .integerDotProductAccumulatingSaturating16BitSignedAccelerated =
+            integer_dot_product_accumulating_saturating_16_bit_signed_accelerated as VkBool32;
+        self
+    }
+
+    pub const fn with_integer_dot_product_accumulating_saturating_16_bit_mixed_signedness_accelerated(
+        mut self,
+        integer_dot_product_accumulating_saturating_16_bit_mixed_signedness_accelerated: bool,
+    ) -> Self {
+        self.inner
+            .integerDotProductAccumulatingSaturating16BitMixedSignednessAccelerated =
+            integer_dot_product_accumulating_saturating_16_bit_mixed_signedness_accelerated
+                as VkBool32;
+        self
+    }
+
+    pub const fn with_integer_dot_product_accumulating_saturating_32_bit_unsigned_accelerated(
+        mut self,
+        integer_dot_product_accumulating_saturating_32_bit_unsigned_accelerated: bool,
+    ) -> Self {
+        self.inner
+            .integerDotProductAccumulatingSaturating32BitUnsignedAccelerated =
+            integer_dot_product_accumulating_saturating_32_bit_unsigned_accelerated as VkBool32;
+        self
+    }
+
+    pub const fn with_integer_dot_product_accumulating_saturating_32_bit_signed_accelerated(
+        mut self,
+        integer_dot_product_accumulating_saturating_32_bit_signed_accelerated: bool,
+    ) -> Self {
+        self.inner
+            .integerDotProductAccumulatingSaturating32BitSignedAccelerated =
+            integer_dot_product_accumulating_saturating_32_bit_signed_accelerated as VkBool32;
+        self
+    }
+
+    pub const fn with_integer_dot_product_accumulating_saturating_32_bit_mixed_signedness_accelerated(
+        mut self,
+        integer_dot_product_accumulating_saturating_32_bit_mixed_signedness_accelerated: bool,
+    ) -> Self {
+        self.inner
+            .integerDotProductAccumulatingSaturating32BitMixedSignednessAccelerated =
+            integer_dot_product_accumulating_saturating_32_bit_mixed_signedness_accelerated
+                as VkBool32;
+        self
+    }
+
+    pub const fn with_integer_dot_product_accumulating_saturating_64_bit_unsigned_accelerated(
+        mut self,
+        integer_dot_product_accumulating_saturating_64_bit_unsigned_accelerated: bool,
+    ) -> Self {
+        self.inner
+            .integerDotProductAccumulatingSaturating64BitUnsignedAccelerated =
+            integer_dot_product_accumulating_saturating_64_bit_unsigned_accelerated as VkBool32;
+        self
+    }
+
+    pub const fn with_integer_dot_product_accumulating_saturating_64_bit_signed_accelerated(
+        mut self,
+        integer_dot_product_accumulating_saturating_64_bit_signed_accelerated: bool,
+    ) -> Self {
+        self.inner
+            .integerDotProductAccumulatingSaturating64BitSignedAccelerated =
+            integer_dot_product_accumulating_saturating_64_bit_signed_accelerated as VkBool32;
+        self
+    }
+
+    pub const fn with_integer_dot_product_accumulating_saturating_64_bit_mixed_signedness_accelerated(
+        mut self,
+        integer_dot_product_accumulating_saturating_64_bit_mixed_signedness_accelerated: bool,
+    ) -> Self {
+        self.inner
+            .integerDotProductAccumulatingSaturating64BitMixedSignednessAccelerated =
+            integer_dot_product_accumulating_saturating_64_bit_mixed_signedness_accelerated
+                as VkBool32;
+        self
+    }
+
+    pub const fn with_storage_texel_buffer_offset_alignment_bytes(
+        mut self,
+        storage_texel_buffer_offset_alignment_bytes: DeviceSize,
+    ) -> Self {
+        self.inner.storageTexelBufferOffsetAlignmentBytes =
+            storage_texel_buffer_offset_alignment_bytes;
+        self
+    }
+
+    pub const fn with_storage_texel_buffer_offset_single_texel_alignment(
+        mut self,
+        storage_texel_buffer_offset_single_texel_alignment: bool,
+    ) -> Self {
+        self.inner.storageTexelBufferOffsetSingleTexelAlignment =
+            storage_texel_buffer_offset_single_texel_alignment as VkBool32;
+        self
+    }
+
+    pub const fn with_uniform_texel_buffer_offset_alignment_bytes(
+        mut self,
+        uniform_texel_buffer_offset_alignment_bytes: DeviceSize,
+    ) -> Self {
+        self.inner.uniformTexelBufferOffsetAlignmentBytes =
+            uniform_texel_buffer_offset_alignment_bytes;
+        self
+    }
+
+    pub const fn with_uniform_texel_buffer_offset_single_texel_alignment(
+        mut self,
+        uniform_texel_buffer_offset_single_texel_alignment: bool,
+    ) -> Self {
+        self.inner.uniformTexelBufferOffsetSingleTexelAlignment =
+            uniform_texel_buffer_offset_single_texel_alignment as VkBool32;
+        self
+    }
+
+    pub const fn with_max_buffer_size(mut self, max_buffer_size: DeviceSize) -> Self {
+        self.inner.maxBufferSize = max_buffer_size;
+        self
+    }
+}
+
+/*
+   Physical Device Driver Properties
+*/
+
+vulkan_create_info_lifetime!(
+    PhysicalDeviceDriverProperties,
+    VkPhysicalDeviceDriverProperties,
+    VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_DRIVER_PROPERTIES
+);
+
+impl<'a> PhysicalDeviceDriverProperties<'a> {
+    pub const fn driver_id(&self) -> DriverId {
+        DriverId::from_raw(self.inner.driverID)
+    }
+
+    pub fn driver_name(&self) -> &CStr {
+        unsafe { CStr::from_ptr(self.inner.driverName.as_ptr()) }
+    }
+
+    pub fn driver_info(&self) -> &CStr {
+        unsafe { CStr::from_ptr(self.inner.driverInfo.as_ptr()) }
+    }
+
+    pub const fn conformance_version(&self) -> ConformanceVersion {
+        self.inner.conformanceVersion
+    }
+
+    pub const fn with_driver_id(mut self, driver_id: DriverId) -> Self {
+        self.inner.driverID = driver_id.as_raw();
+        self
+    }
+
+    pub const fn with_conformance_version(
+        mut self,
+        conformance_version: ConformanceVersion,
+    ) -> Self {
+        self.inner.conformanceVersion = conformance_version;
+        self
+    }
+}
+
+/*
+   Physical Device Subgroup Properties
+*/
+
+vulkan_create_info_lifetime!(
+    PhysicalDeviceSubgroupProperties,
+    VkPhysicalDeviceSubgroupProperties,
+    VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_SUBGROUP_PROPERTIES
+);
+
+impl<'a> PhysicalDeviceSubgroupProperties<'a> {
+    pub const fn subgroup_size(&self) -> u32 {
+        self.inner.subgroupSize
+    }
+
+    pub const fn supported_stages(&self) -> ShaderStageFlags {
+        ShaderStageFlags::from_bits_truncate(self.inner.supportedStages)
+    }
+
+    pub const fn supported_operations(&self) -> SubgroupFeatureFlags {
+        SubgroupFeatureFlags::from_bits_truncate(self.inner.supportedOperations)
+    }
+
+    pub const fn quad_operations_in_all_stages(&self) -> bool {
+        self.inner.quadOperationsInAllStages != 0
+    }
+
+    pub const fn with_subgroup_size(mut self, subgroup_size: u32) -> Self {
+        self.inner.subgroupSize = subgroup_size;
+        self
+    }
+
+    pub const fn with_supported_stages(mut self, supported_stages: ShaderStageFlags) -> Self {
+        self.inner.supportedStages = supported_stages.bits();
+        self
+    }
+
+    pub const fn with_supported_operations(
+        mut self,
+        supported_operations: SubgroupFeatureFlags,
+    ) -> Self {
+        self.inner.supportedOperations = supported_operations.bits();
+        self
+    }
+
+    pub const fn with_quad_operations_in_all_stages(
+        mut self,
+        quad_operations_in_all_stages: bool,
+    ) -> Self {
+        self.inner.quadOperationsInAllStages = quad_operations_in_all_stages as VkBool32;
+        self
+    }
+}
+
+/*
+   Physical Device Descriptor Indexing Properties
+*/
+
+vulkan_create_info_lifetime!(
+    PhysicalDeviceDescriptorIndexingProperties,
+    VkPhysicalDeviceDescriptorIndexingProperties,
+    VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_DESCRIPTOR_INDEXING_PROPERTIES
+);
+
+impl<'a> PhysicalDeviceDescriptorIndexingProperties<'a> {
+    pub const fn max_update_after_bind_descriptors_in_all_pools(&self) -> u32 {
+        self.inner.maxUpdateAfterBindDescriptorsInAllPools
+    }
+
+    pub const fn shader_uniform_buffer_array_non_uniform_indexing_native(&self) -> bool {
+        self.inner.shaderUniformBufferArrayNonUniformIndexingNative != 0
+    }
+
+    pub const fn shader_sampled_image_array_non_uniform_indexing_native(&self) -> bool {
+        self.inner.shaderSampledImageArrayNonUniformIndexingNative != 0
+    }
+
+    pub const fn shader_storage_buffer_array_non_uniform_indexing_native(&self) -> bool {
+        self.inner.shaderStorageBufferArrayNonUniformIndexingNative != 0
+    }
+
+    pub const fn shader_storage_image_array_non_uniform_indexing_native(&self) -> bool {
+        self.inner.shaderStorageImageArrayNonUniformIndexingNative != 0
+    }
+
+    pub const fn shader_input_attachment_array_non_uniform_indexing_native(&self) -> bool {
+        self.inner
+            .shaderInputAttachmentArrayNonUniformIndexingNative
+            != 0
+    }
+
+    pub const fn robust_buffer_access_update_after_bind(&self) -> bool {
+        self.inner.robustBufferAccessUpdateAfterBind != 0
+    }
+
+    pub const fn quad_divergent_implicit_lod(&self) -> bool {
+        self.inner.quadDivergentImplicitLod != 0
+    }
+
+    pub const fn max_per_stage_descriptor_update_after_bind_samplers(&self) -> u32 {
+        self.inner.maxPerStageDescriptorUpdateAfterBindSamplers
+    }
+
+    pub const fn max_per_stage_descriptor_update_after_bind_uniform_buffers(&self) -> u32 {
+        self.inner
+            .maxPerStageDescriptorUpdateAfterBindUniformBuffers
+    }
+
+    pub const fn max_per_stage_descriptor_update_after_bind_storage_buffers(&self) -> u32 {
+        self.inner
+            .maxPerStageDescriptorUpdateAfterBindStorageBuffers
+    }
+
+    pub const fn max_per_stage_descriptor_update_after_bind_sampled_images(&self) -> u32 {
+        self.inner.maxPerStageDescriptorUpdateAfterBindSampledImages
+    }
+
+    pub const fn max_per_stage_descriptor_update_after_bind_storage_images(&self) -> u32 {
+        self.inner.maxPerStageDescriptorUpdateAfterBindStorageImages
+    }
+
+    pub const fn max_per_stage_descriptor_update_after_bind_input_attachments(&self) -> u32 {
+        self.inner
+            .maxPerStageDescriptorUpdateAfterBindInputAttachments
+    }
+
+    pub const fn max_per_stage_update_after_bind_resources(&self) -> u32 {
+        self.inner.maxPerStageUpdateAfterBindResources
+    }
+
+    pub const fn max_descriptor_set_update_after_bind_samplers(&self) -> u32 {
+        self.inner.maxDescriptorSetUpdateAfterBindSamplers
+    }
+
+    pub const fn max_descriptor_set_update_after_bind_uniform_buffers(&self) -> u32 {
+        self.inner.maxDescriptorSetUpdateAfterBindUniformBuffers
+    }
+
+    pub const fn max_descriptor_set_update_after_bind_uniform_buffers_dynamic(&self) -> u32 {
+        self.inner
+            .maxDescriptorSetUpdateAfterBindUniformBuffersDynamic
+    }
+
+    pub const fn max_descriptor_set_update_after_bind_storage_buffers(&self) -> u32 {
+        self.inner.maxDescriptorSetUpdateAfterBindStorageBuffers
+    }
+
+    pub const fn max_descriptor_set_update_after_bind_storage_buffers_dynamic(&self) -> u32 {
+        self.inner
+            .maxDescriptorSetUpdateAfterBindStorageBuffersDynamic
+    }
+
+    pub const fn max_descriptor_set_update_after_bind_sampled_images(&self) -> u32 {
+        self.inner.maxDescriptorSetUpdateAfterBindSampledImages
+    }
+
+    pub const fn max_descriptor_set_update_after_bind_storage_images(&self) -> u32 {
+        self.inner.maxDescriptorSetUpdateAfterBindStorageImages
+    }
+
+    pub const fn max_descriptor_set_update_after_bind_input_attachments(&self) -> u32 {
+        self.inner.maxDescriptorSetUpdateAfterBindInputAttachments
+    }
+
+    pub const fn with_max_update_after_bind_descriptors_in_all_pools(
+        mut self,
+        max_update_after_bind_descriptors_in_all_pools: u32,
+    ) -> Self {
+        self.inner.maxUpdateAfterBindDescriptorsInAllPools =
+            max_update_after_bind_descriptors_in_all_pools;
+        self
+    }
+
+    pub const fn with_shader_uniform_buffer_array_non_uniform_indexing_native(
+        mut self,
+        shader_uniform_buffer_array_non_uniform_indexing_native: bool,
+    ) -> Self {
+        self.inner.shaderUniformBufferArrayNonUniformIndexingNative =
+            shader_uniform_buffer_array_non_uniform_indexing_native as VkBool32;
+        self
+    }
+
+    pub const fn with_shader_sampled_image_array_non_uniform_indexing_native(
+        mut self,
+        shader_sampled_image_array_non_uniform_indexing_native: bool,
+    ) -> Self {
+        self.inner.shaderSampledImageArrayNonUniformIndexingNative =
+            shader_sampled_image_array_non_uniform_indexing_native as VkBool32;
+        self
+    }
+
+    pub const fn with_shader_storage_buffer_array_non_uniform_indexing_native(
+        mut self,
+        shader_storage_buffer_array_non_uniform_indexing_native: bool,
+    ) -> Self {
+        self.inner.shaderStorageBufferArrayNonUniformIndexingNative =
+            shader_storage_buffer_array_non_uniform_indexing_native as VkBool32;
+        self
+    }
+
+    pub const fn with_shader_storage_image_array_non_uniform_indexing_native(
+        mut self,
+        shader_storage_image_array_non_uniform_indexing_native: bool,
+    ) -> Self {
+        self.inner.shaderStorageImageArrayNonUniformIndexingNative =
+            shader_storage_image_array_non_uniform_indexing_native as VkBool32;
+        self
+    }
+
+    pub const fn with_shader_input_attachment_array_non_uniform_indexing_native(
+        mut self,
+        shader_input_attachment_array_non_uniform_indexing_native: bool,
+    ) -> Self {
+        self.inner
+            .shaderInputAttachmentArrayNonUniformIndexingNative =
+            shader_input_attachment_array_non_uniform_indexing_native as VkBool32;
+        self
+    }
+
+    pub const fn with_robust_buffer_access_update_after_bind(
+        mut self,
+        robust_buffer_access_update_after_bind: bool,
+    ) -> Self {
+        self.inner.robustBufferAccessUpdateAfterBind =
+            robust_buffer_access_update_after_bind as VkBool32;
+        self
+    }
+
+    pub const fn with_quad_divergent_implicit_lod(
+        mut self,
+        quad_divergent_implicit_lod: bool,
+    ) -> Self {
+        self.inner.quadDivergentImplicitLod = quad_divergent_implicit_lod as VkBool32;
+        self
+    }
+
+    pub const fn with_max_per_stage_descriptor_update_after_bind_samplers(
+        mut self,
+        max_per_stage_descriptor_update_after_bind_samplers: u32,
+    ) -> Self {
+        self.inner.maxPerStageDescriptorUpdateAfterBindSamplers =
+            max_per_stage_descriptor_update_after_bind_samplers;
+        self
+    }
+
+    pub const fn with_max_per_stage_descriptor_update_after_bind_uniform_buffers(
+        mut self,
+        max_per_stage_descriptor_update_after_bind_uniform_buffers: u32,
+    ) -> Self {
+        self.inner
+            .maxPerStageDescriptorUpdateAfterBindUniformBuffers =
+            max_per_stage_descriptor_update_after_bind_uniform_buffers;
+        self
+    }
+
+    pub const fn with_max_per_stage_descriptor_update_after_bind_storage_buffers(
+        mut self,
+        max_per_stage_descriptor_update_after_bind_storage_buffers: u32,
+    ) -> Self {
+        self.inner
+            .maxPerStageDescriptorUpdateAfterBindStorageBuffers =
+            max_per_stage_descriptor_update_after_bind_storage_buffers;
+        self
+    }
+
+    pub const fn with_max_per_stage_descriptor_update_after_bind_sampled_images(
+        mut self,
+        max_per_stage_descriptor_update_after_bind_sampled_images: u32,
+    ) -> Self {
+        self.inner.maxPerStageDescriptorUpdateAfterBindSampledImages =
+            max_per_stage_descriptor_update_after_bind_sampled_images;
+        self
+    }
+
+    pub const fn with_max_per_stage_descriptor_update_after_bind_storage_images(
+        mut self,
+        max_per_stage_descriptor_update_after_bind_storage_images: u32,
+    ) -> Self {
+        self.inner.maxPerStageDescriptorUpdateAfterBindStorageImages =
+            max_per_stage_descriptor_update_after_bind_storage_images;
+        self
+    }
+
+    pub const fn with_max_per_stage_descriptor_update_after_bind_input_attachments(
+        mut self,
+        max_per_stage_descriptor_update_after_bind_input_attachments: u32,
+    ) -> Self {
+        self.inner
+            .maxPerStageDescriptorUpdateAfterBindInputAttachments =
+            max_per_stage_descriptor_update_after_bind_input_attachments;
+        self
+    }
+
+    pub const fn with_max_per_stage_update_after_bind_resources(
+        mut self,
+        max_per_stage_update_after_bind_resources: u32,
+    ) -> Self {
+        self.inner.maxPerStageUpdateAfterBindResources = max_per_stage_update_after_bind_resources;
+        self
+    }
+
+    pub const fn with_max_descriptor_set_update_after_bind_samplers(
+        mut self,
+        max_descriptor_set_update_after_bind_samplers: u32,
+    ) -> Self {
+        self.inner.maxDescriptorSetUpdateAfterBindSamplers =
+            max_descriptor_set_update_after_bind_samplers;
+        self
+    }
+
+    pub const fn with_max_descriptor_set_update_after_bind_uniform_buffers(
+        mut self,
+        max_descriptor_set_update_after_bind_uniform_buffers: u32,
+    ) -> Self {
+        self.inner.maxDescriptorSetUpdateAfterBindUniformBuffers =
+            max_descriptor_set_update_after_bind_uniform_buffers;
+        self
+    }
+
+    pub const fn with_max_descriptor_set_update_after_bind_uniform_buffers_dynamic(
+        mut self,
+        max_descriptor_set_update_after_bind_uniform_buffers_dynamic: u32,
+    ) -> Self {
+        self.inner
+            .maxDescriptorSetUpdateAfterBindUniformBuffersDynamic =
+            max_descriptor_set_update_after_bind_uniform_buffers_dynamic;
+        self
+    }
+
+    pub const fn with_max_descriptor_set_update_after_bind_storage_buffers(
+        mut self,
+        max_descriptor_set_update_after_bind_storage_buffers: u32,
+    ) -> Self {
+        self.inner.maxDescriptorSetUpdateAfterBindStorageBuffers =
+            max_descriptor_set_update_after_bind_storage_buffers;
+        self
+    }
+
+    pub const fn with_max_descriptor_set_update_after_bind_storage_buffers_dynamic(
+        mut self,
+        max_descriptor_set_update_after_bind_storage_buffers_dynamic: u32,
+    ) -> Self {
+        self.inner
+            .maxDescriptorSetUpdateAfterBindStorageBuffersDynamic =
+            max_descriptor_set_update_after_bind_storage_buffers_dynamic;
+        self
+    }
+
+    pub const fn with_max_descriptor_set_update_after_bind_sampled_images(
+        mut self,
+        max_descriptor_set_update_after_bind_sampled_images: u32,
+    ) -> Self {
+        self.inner.maxDescriptorSetUpdateAfterBindSampledImages =
+            max_descriptor_set_update_after_bind_sampled_images;
+        self
+    }
+
+    pub const fn with_max_descriptor_set_update_after_bind_storage_images(
+        mut self,
+        max_descriptor_set_update_after_bind_storage_images: u32,
+    ) -> Self {
+        self.inner.maxDescriptorSetUpdateAfterBindStorageImages =
+            max_descriptor_set_update_after_bind_storage_images;
+        self
+    }
+
+    pub const fn with_max_descriptor_set_update_after_bind_input_attachments(
+        mut self,
+        max_descriptor_set_update_after_bind_input_attachments: u32,
+    ) -> Self {
+        self.inner.maxDescriptorSetUpdateAfterBindInputAttachments =
+            max_descriptor_set_update_after_bind_input_attachments;
+        self
+    }
+}
@@ -0,0 +1,263 @@
+// Copyright (c) 2024 Jacob R. Green
+// All rights reserved.
+
+use crate::macros::*;
+use crate::*;
+use vulkan_sys::*;
+
+vulkan_handle!(QueryPool, VkQueryPool, QUERY_POOL);
+
+vulkan_create_info_lifetime!(
+    QueryPoolCreateInfo,
+    VkQueryPoolCreateInfo,
+    VK_STRUCTURE_TYPE_QUERY_POOL_CREATE_INFO
+);
+
+impl<'a> QueryPoolCreateInfo<'a> {
+    pub const fn with_query_type(mut self, query_type: QueryType) -> Self {
+        self.inner.queryType = query_type.as_raw();
+        self
+    }
+
+    pub const fn with_query_count(mut self, query_count: u32) -> Self {
+        self.inner.queryCount = query_count;
+        self
+    }
+
+    /// Only used by `PIPELINE_STATISTICS` pools.
+    pub const fn with_pipeline_statistics(
+        mut self,
+        pipeline_statistics: QueryPipelineStatisticFlags,
+    ) -> Self {
+        self.inner.pipelineStatistics = pipeline_statistics.bits();
+        self
+    }
+}
+
+/*
+   Query Results
+*/
+
+/// Results read back by [`Device::get_query_pool_results`], widened to `u64`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct QueryResults {
+    data: Vec<u64>,
+    values_per_query: usize,
+    with_availability: bool,
+}
+
+impl QueryResults {
+    pub(crate) fn new(data: Vec<u64>, values_per_query: usize, with_availability: bool) -> Self {
+        Self {
+            data,
+            values_per_query,
+            with_availability,
+        }
+    }
+
+    /// Results read without `RESULT_64`.
+    pub(crate) fn from_u32(
+        data: Vec<u32>,
+        values_per_query: usize,
+        with_availability: bool,
+    ) -> Self {
+        Self::new(
+            data.into_iter().map(u64::from).collect(),
+            values_per_query,
+            with_availability,
+        )
+    }
+
+    const fn stride(&self) -> usize {
+        self.values_per_query + self.with_availability as usize
+    }
+
+    pub fn len(&self) -> usize {
+        match self.stride() {
+            0 => 0,
+            stride => self.data.len() / stride,
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// The raw values of query `index`, relative to the first query read.
+    ///
+    /// `None` when the query was read with `WITH_AVAILABILITY` and is not yet available.
+    pub fn values(&self, index: usize) -> Option<&[u64]> {
+        self.values_with_availability(index)
+            .and_then(|(values, available)| available.then_some(values))
+    }
+
+    /// The raw values of query `index` and whether the query was available.
+    ///
+    /// Queries read without `WITH_AVAILABILITY` always count as available. The values of an
+    /// unavailable query are intermediate results when read with `PARTIAL` and undefined
+    /// otherwise.
+    pub fn values_with_availability(&self, index: usize) -> Option<(&[u64], bool)> {
+        let start = index * self.stride();
+        let query = self.data.get(start..start + self.stride())?;
+        let (values, availability) = query.split_at(self.values_per_query);
+        Some((values, availability.first() != Some(&0)))
+    }
+
+    /// Samples passed for an `OCCLUSION` query.
+    pub fn occlusion(&self, index: usize) -> Option<u64> {
+        self.values(index)?.first().copied()
+    }
+
+    /// Ticks of a `TIMESTAMP` query; multiply by `timestamp_period` for nanoseconds.
+    pub fn timestamp(&self, index: usize) -> Option<u64> {
+        self.values(index)?.first().copied()
+    }
+
+    /// Decodes a `PIPELINE_STATISTICS` query created with `statistics`.
+    pub fn pipeline_statistics(
+        &self,
+        index: usize,
+        statistics: QueryPipelineStatisticFlags,
+    ) -> Option<PipelineStatistics> {
+        Some(PipelineStatistics::decode(self.values(index)?, statistics))
+    }
+}
+
+/// Counters of a pipeline statistics query, `None` for statistics the pool was not created with.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct PipelineStatistics {
+    pub input_assembly_vertices: Option<u64>,
+    pub input_assembly_primitives: Option<u64>,
+    pub vertex_shader_invocations: Option<u64>,
+    pub geometry_shader_invocations: Option<u64>,
+    pub geometry_shader_primitives: Option<u64>,
+    pub clipping_invocations: Option<u64>,
+    pub clipping_primitives: Option<u64>,
+    pub fragment_shader_invocations: Option<u64>,
+    pub tessellation_control_shader_patches: Option<u64>,
+    pub tessellation_evaluation_shader_invocations: Option<u64>,
+    pub compute_shader_invocations: Option<u64>,
+}
+
+impl PipelineStatistics {
+    /// Values are written in order of increasing statistic bit, one per enabled statistic.
+    fn decode(values: &[u64], statistics: QueryPipelineStatisticFlags) -> Self {
+        let mut values = values.iter().copied();
+        let mut next = |statistic: QueryPipelineStatisticFlags| {
+            statistics
+                .contains(statistic)
+                .then(|| values.next())
+                .flatten()
+        };
+
+        Self {
+            input_assembly_vertices: next(QueryPipelineStatisticFlags::INPUT_ASSEMBLY_VERTICES),
+            input_assembly_primitives: next(QueryPipelineStatisticFlags::INPUT_ASSEMBLY_PRIMITIVES),
+            vertex_shader_invocations: next(QueryPipelineStatisticFlags::VERTEX_SHADER_INVOCATIONS),
+            geometry_shader_invocations: next(
+                QueryPipelineStatisticFlags::GEOMETRY_SHADER_INVOCATIONS,
+            ),
+            geometry_shader_primitives: next(
+                QueryPipelineStatisticFlags::GEOMETRY_SHADER_PRIMITIVES,
+            ),
+            clipping_invocations: next(QueryPipelineStatisticFlags::CLIPPING_INVOCATIONS),
+            clipping_primitives: next(QueryPipelineStatisticFlags::CLIPPING_PRIMITIVES),
+            fragment_shader_invocations: next(
+                QueryPipelineStatisticFlags::FRAGMENT_SHADER_INVOCATIONS,
+            ),
+            tessellation_control_shader_patches: next(
+                QueryPipelineStatisticFlags::TESSELLATION_CONTROL_SHADER_PATCHES,
+            ),
+            tessellation_evaluation_shader_invocations: next(
+                QueryPipelineStatisticFlags::TESSELLATION_EVALUATION_SHADER_INVOCATIONS,
+            ),
+            compute_shader_invocations: next(
+                QueryPipelineStatisticFlags::COMPUTE_SHADER_INVOCATIONS,
+            ),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn decodes_a_partial_statistics_mask() {
+        let statistics = QueryPipelineStatisticFlags::INPUT_ASSEMBLY_VERTICES
+            | QueryPipelineStatisticFlags::FRAGMENT_SHADER_INVOCATIONS
+            | QueryPipelineStatisticFlags::COMPUTE_SHADER_INVOCATIONS;
+
+        assert_eq!(
+            PipelineStatistics::decode(&[3, 1024, 64], statistics),
+            PipelineStatistics {
+                input_assembly_vertices: Some(3),
+                fragment_shader_invocations: Some(1024),
+                compute_shader_invocations: Some(64),
+                ..Default::default()
+            }
+        );
+    }
+
+    #[test]
+    fn decodes_statistics_of_each_query() {
+        let statistics = QueryPipelineStatisticFlags::VERTEX_SHADER_INVOCATIONS
+            | QueryPipelineStatisticFlags::CLIPPING_PRIMITIVES;
+        let results = QueryResults::new(vec![6, 2, 9, 3], 2, false);
+
+        assert_eq!(results.len(), 2);
+        assert_eq!(
+            results.pipeline_statistics(1, statistics),
+            Some(PipelineStatistics {
+                vertex_shader_invocations: Some(9),
+                clipping_primitives: Some(3),
+                ..Default::default()
+            })
+        );
+        assert_eq!(results.pipeline_statistics(2, statistics), None);
+    }
+
+    #[test]
+    fn strides_over_availability() {
+        // Two values per query, then the availability value.
+        let results = QueryResults::new(vec![10, 11, 1, 20, 21, 0, 30, 31, 1], 2, true);
+
+        assert_eq!(results.len(), 3);
+        assert_eq!(
+            results.values_with_availability(0),
+            Some((&[10, 11][..], true))
+        );
+        assert_eq!(
+            results.values_with_availability(1),
+            Some((&[20, 21][..], false))
+        );
+        assert_eq!(results.values(1), None);
+        assert_eq!(results.values(2), Some(&[30, 31][..]));
+        assert_eq!(results.values_with_availability(3), None);
+    }
+
+    #[test]
+    fn queries_without_availability_are_available() {
+        let results = QueryResults::new(vec![5, 0], 1, false);
+
+        assert_eq!(results.len(), 2);
+        assert_eq!(results.occlusion(0), Some(5));
+        assert_eq!(results.values_with_availability(1), Some((&[0][..], true)));
+    }
+
+    #[test]
+    fn len_ignores_a_trailing_partial_query() {
+        assert_eq!(QueryResults::new(vec![1, 2, 3], 1, true).len(), 1);
+        assert!(QueryResults::new(Vec::new(), 1, true).is_empty());
+        assert!(QueryResults::new(vec![1], 0, false).is_empty());
+    }
+
+    #[test]
+    fn widens_32_bit_results_without_sign_extension() {
+        let results = QueryResults::from_u32(vec![u32::MAX, 1, 7, 0], 1, true);
+
+        assert_eq!(results.len(), 2);
+        assert_eq!(results.timestamp(0), Some(u64::from(u32::MAX)));
+        assert_eq!(results.values_with_availability(1), Some((&[7][..], false)));
+    }
+}
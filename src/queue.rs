@@ -5,7 +5,7 @@ use crate::macros::*;
 use crate::*;
 use vulkan_sys::*;
 
-vulkan_handle!(Queue, VkQueue);
+vulkan_dispatchable_handle!(Queue, VkQueue, QUEUE, dispatch::DeviceDispatch);
 
 vulkan_create_info_lifetime!(SubmitInfo, VkSubmitInfo, VK_STRUCTURE_TYPE_SUBMIT_INFO);
 
@@ -24,9 +24,9 @@ impl<'a> SubmitInfo<'a> {
         self
     }
 
-    pub const fn with_command_buffers(mut self, command_buffers: &'a [CommandBuffer]) -> Self {
+    pub fn with_command_buffers(mut self, command_buffers: &'a CommandBufferArray<'a>) -> Self {
         self.inner.commandBufferCount = command_buffers.len() as u32;
-        self.inner.pCommandBuffers = command_buffers.as_ptr() as *const _;
+        self.inner.pCommandBuffers = command_buffers.as_raw().as_ptr();
         self
     }
 
@@ -37,6 +37,114 @@ impl<'a> SubmitInfo<'a> {
     }
 }
 
+vulkan_create_info_lifetime!(
+    TimelineSemaphoreSubmitInfo,
+    VkTimelineSemaphoreSubmitInfo,
+    VK_STRUCTURE_TYPE_TIMELINE_SEMAPHORE_SUBMIT_INFO
+);
+
+impl<'a> TimelineSemaphoreSubmitInfo<'a> {
+    /// One value per wait semaphore of the [`SubmitInfo`] this is chained to; entries for binary
+    /// semaphores are ignored.
+    pub const fn with_wait_semaphore_values(mut self, wait_semaphore_values: &'a [u64]) -> Self {
+        self.inner.waitSemaphoreValueCount = wait_semaphore_values.len() as u32;
+        self.inner.pWaitSemaphoreValues = wait_semaphore_values.as_ptr();
+        self
+    }
+
+    pub const fn with_signal_semaphore_values(
+        mut self,
+        signal_semaphore_values: &'a [u64],
+    ) -> Self {
+        self.inner.signalSemaphoreValueCount = signal_semaphore_values.len() as u32;
+        self.inner.pSignalSemaphoreValues = signal_semaphore_values.as_ptr();
+        self
+    }
+}
+
+vulkan_create_info_lifetime!(
+    SemaphoreSubmitInfo,
+    VkSemaphoreSubmitInfo,
+    VK_STRUCTURE_TYPE_SEMAPHORE_SUBMIT_INFO
+);
+
+impl<'a> SemaphoreSubmitInfo<'a> {
+    pub const fn with_semaphore(mut self, semaphore: Semaphore) -> Self {
+        self.inner.semaphore = semaphore.as_raw();
+        self
+    }
+
+    /// The value to wait for or signal; ignored for binary semaphores.
+    pub const fn with_value(mut self, value: u64) -> Self {
+        self.inner.value = value;
+        self
+    }
+
+    pub const fn with_stage_mask(mut self, stage_mask: PipelineStageFlags2) -> Self {
+        self.inner.stageMask = stage_mask.bits();
+        self
+    }
+
+    pub const fn with_device_index(mut self, device_index: u32) -> Self {
+        self.inner.deviceIndex = device_index;
+        self
+    }
+}
+
+vulkan_create_info_lifetime!(
+    CommandBufferSubmitInfo,
+    VkCommandBufferSubmitInfo,
+    VK_STRUCTURE_TYPE_COMMAND_BUFFER_SUBMIT_INFO
+);
+
+impl<'a> CommandBufferSubmitInfo<'a> {
+    pub const fn with_command_buffer(mut self, command_buffer: &CommandBuffer) -> Self {
+        self.inner.commandBuffer = command_buffer.as_raw();
+        self
+    }
+
+    pub const fn with_device_mask(mut self, device_mask: u32) -> Self {
+        self.inner.deviceMask = device_mask;
+        self
+    }
+}
+
+vulkan_create_info_lifetime!(SubmitInfo2, VkSubmitInfo2, VK_STRUCTURE_TYPE_SUBMIT_INFO_2);
+
+impl<'a> SubmitInfo2<'a> {
+    pub const fn with_flags(mut self, flags: SubmitFlags) -> Self {
+        self.inner.flags = flags.bits();
+        self
+    }
+
+    pub const fn with_wait_semaphore_infos(
+        mut self,
+        wait_semaphore_infos: &'a [SemaphoreSubmitInfo<'a>],
+    ) -> Self {
+        self.inner.waitSemaphoreInfoCount = wait_semaphore_infos.len() as u32;
+        self.inner.pWaitSemaphoreInfos = wait_semaphore_infos.as_ptr().cast();
+        self
+    }
+
+    pub const fn with_command_buffer_infos(
+        mut self,
+        command_buffer_infos: &'a [CommandBufferSubmitInfo<'a>],
+    ) -> Self {
+        self.inner.commandBufferInfoCount = command_buffer_infos.len() as u32;
+        self.inner.pCommandBufferInfos = command_buffer_infos.as_ptr().cast();
+        self
+    }
+
+    pub const fn with_signal_semaphore_infos(
+        mut self,
+        signal_semaphore_infos: &'a [SemaphoreSubmitInfo<'a>],
+    ) -> Self {
+        self.inner.signalSemaphoreInfoCount = signal_semaphore_infos.len() as u32;
+        self.inner.pSignalSemaphoreInfos = signal_semaphore_infos.as_ptr().cast();
+        self
+    }
+}
+
 vulkan_create_info_lifetime!(
     PresentInfoKHR,
     VkPresentInfoKHR,
@@ -71,7 +179,7 @@ impl Queue {
     pub fn submit(&self, submits: &[SubmitInfo], fence: Option<Fence>) -> Result<()> {
         unsafe {
             queue_submit(
-                vkQueueSubmit,
+                self.dispatch().vkQueueSubmit,
                 self.handle,
                 transmute(submits),
                 transmute(fence),
@@ -79,11 +187,33 @@ impl Queue {
         }
     }
 
+    /// Requires Vulkan 1.3 or `VK_KHR_synchronization2`.
+    pub fn submit2(&self, submits: &[SubmitInfo2], fence: Option<Fence>) -> Result<()> {
+        let dispatch = self.dispatch();
+        unsafe {
+            queue_submit2(
+                dispatch
+                    .vkQueueSubmit2
+                    .or(dispatch.vkQueueSubmit2KHR)
+                    .expect("vkQueueSubmit2 is not available"),
+                self.as_raw(),
+                transmute(submits),
+                transmute(fence),
+            )
+        }
+    }
+
     pub fn present_khr(&self, present_info: &PresentInfoKHR) -> Result<()> {
-        queue_present_khr(vkQueuePresentKHR, self.as_raw(), present_info.as_raw())
+        queue_present_khr(
+            self.dispatch()
+                .vkQueuePresentKHR
+                .expect("vkQueuePresentKHR is not available"),
+            self.as_raw(),
+            present_info.as_raw(),
+        )
     }
 
     pub fn wait_idle(&self) -> Result<()> {
-        queue_wait_idle(vkQueueWaitIdle, self.as_raw())
+        queue_wait_idle(self.dispatch().vkQueueWaitIdle, self.as_raw())
     }
 }
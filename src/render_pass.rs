@@ -5,7 +5,7 @@ use crate::macros::*;
 use crate::*;
 use vulkan_sys::*;
 
-vulkan_handle!(RenderPass, VkRenderPass);
+vulkan_handle!(RenderPass, VkRenderPass, RENDER_PASS);
 
 vulkan_create_info_lifetime!(
     RenderPassCreateInfo,
@@ -5,7 +5,7 @@ use crate::macros::*;
 use crate::*;
 use vulkan_sys::*;
 
-vulkan_handle!(Sampler, VkSampler);
+vulkan_handle!(Sampler, VkSampler, SAMPLER);
 
 vulkan_create_info_lifetime!(
     SamplerCreateInfo,
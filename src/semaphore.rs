@@ -5,10 +5,80 @@ use crate::macros::*;
 use crate::*;
 use vulkan_sys::*;
 
-vulkan_handle!(Semaphore, VkSemaphore);
+vulkan_handle!(Semaphore, VkSemaphore, SEMAPHORE);
 
-vulkan_create_info!(
+vulkan_create_info_lifetime!(
     SemaphoreCreateInfo,
     VkSemaphoreCreateInfo,
     VK_STRUCTURE_TYPE_SEMAPHORE_CREATE_INFO
 );
+
+vulkan_create_info_lifetime!(
+    SemaphoreTypeCreateInfo,
+    VkSemaphoreTypeCreateInfo,
+    VK_STRUCTURE_TYPE_SEMAPHORE_TYPE_CREATE_INFO
+);
+
+impl<'a> SemaphoreTypeCreateInfo<'a> {
+    pub const fn with_semaphore_type(mut self, semaphore_type: SemaphoreType) -> Self {
+        self.inner.semaphoreType = semaphore_type.as_raw();
+        self
+    }
+
+    pub const fn with_initial_value(mut self, initial_value: u64) -> Self {
+        self.inner.initialValue = initial_value;
+        self
+    }
+
+    /// A timeline semaphore starting at `initial_value`, chained with
+    /// [`SemaphoreCreateInfo::push_next`].
+    pub const fn timeline(initial_value: u64) -> Self {
+        Self::new()
+            .with_semaphore_type(SemaphoreType::TIMELINE)
+            .with_initial_value(initial_value)
+    }
+}
+
+vulkan_create_info_lifetime!(
+    SemaphoreWaitInfo,
+    VkSemaphoreWaitInfo,
+    VK_STRUCTURE_TYPE_SEMAPHORE_WAIT_INFO
+);
+
+impl<'a> SemaphoreWaitInfo<'a> {
+    /// [`SemaphoreWaitFlags::ANY`] returns once any semaphore reaches its value instead of all.
+    pub const fn with_flags(mut self, flags: SemaphoreWaitFlags) -> Self {
+        self.inner.flags = flags.bits();
+        self
+    }
+
+    /// Waits for each of `semaphores` to reach the value at the same index of `values`.
+    pub const fn with_semaphores(mut self, semaphores: &'a [Semaphore], values: &'a [u64]) -> Self {
+        assert!(
+            semaphores.len() == values.len(),
+            "every semaphore needs a matching value"
+        );
+        self.inner.semaphoreCount = semaphores.len() as u32;
+        self.inner.pSemaphores = semaphores.as_ptr().cast();
+        self.inner.pValues = values.as_ptr();
+        self
+    }
+}
+
+vulkan_create_info_lifetime!(
+    SemaphoreSignalInfo,
+    VkSemaphoreSignalInfo,
+    VK_STRUCTURE_TYPE_SEMAPHORE_SIGNAL_INFO
+);
+
+impl<'a> SemaphoreSignalInfo<'a> {
+    pub const fn with_semaphore(mut self, semaphore: Semaphore) -> Self {
+        self.inner.semaphore = semaphore.as_raw();
+        self
+    }
+
+    pub const fn with_value(mut self, value: u64) -> Self {
+        self.inner.value = value;
+        self
+    }
+}
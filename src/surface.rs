@@ -7,16 +7,18 @@ use crate::*;
 use vulkan_sys::*;
 
 /*
-   Surface
+   Win32 Surface
 */
 
-vulkan_create_info!(
+#[cfg(target_os = "windows")]
+vulkan_create_info_lifetime!(
     Win32SurfaceCreateInfoKHR,
     VkWin32SurfaceCreateInfoKHR,
     VK_STRUCTURE_TYPE_WIN32_SURFACE_CREATE_INFO_KHR
 );
 
-impl crate::Win32SurfaceCreateInfoKHR {
+#[cfg(target_os = "windows")]
+impl<'a> crate::Win32SurfaceCreateInfoKHR<'a> {
     pub const fn with_hinstance(
         mut self,
         hinstance: windows::Win32::Foundation::HINSTANCE,
@@ -31,4 +33,197 @@ impl crate::Win32SurfaceCreateInfoKHR {
     }
 }
 
-vulkan_handle!(SurfaceKHR, VkSurfaceKHR);
+/*
+   Xlib Surface
+*/
+
+#[cfg(feature = "xlib")]
+vulkan_create_info_lifetime!(
+    XlibSurfaceCreateInfoKHR,
+    VkXlibSurfaceCreateInfoKHR,
+    VK_STRUCTURE_TYPE_XLIB_SURFACE_CREATE_INFO_KHR
+);
+
+#[cfg(feature = "xlib")]
+impl<'a> XlibSurfaceCreateInfoKHR<'a> {
+    pub const fn with_dpy(mut self, dpy: *mut std::ffi::c_void) -> Self {
+        self.inner.dpy = dpy.cast();
+        self
+    }
+
+    pub const fn with_window(mut self, window: std::ffi::c_ulong) -> Self {
+        self.inner.window = window as _;
+        self
+    }
+}
+
+/*
+   XCB Surface
+*/
+
+#[cfg(feature = "xcb")]
+vulkan_create_info_lifetime!(
+    XcbSurfaceCreateInfoKHR,
+    VkXcbSurfaceCreateInfoKHR,
+    VK_STRUCTURE_TYPE_XCB_SURFACE_CREATE_INFO_KHR
+);
+
+#[cfg(feature = "xcb")]
+impl<'a> XcbSurfaceCreateInfoKHR<'a> {
+    pub const fn with_connection(mut self, connection: *mut std::ffi::c_void) -> Self {
+        self.inner.connection = connection.cast();
+        self
+    }
+
+    pub const fn with_window(mut self, window: u32) -> Self {
+        self.inner.window = window as _;
+        self
+    }
+}
+
+/*
+   Wayland Surface
+*/
+
+#[cfg(feature = "wayland")]
+vulkan_create_info_lifetime!(
+    WaylandSurfaceCreateInfoKHR,
+    VkWaylandSurfaceCreateInfoKHR,
+    VK_STRUCTURE_TYPE_WAYLAND_SURFACE_CREATE_INFO_KHR
+);
+
+#[cfg(feature = "wayland")]
+impl<'a> WaylandSurfaceCreateInfoKHR<'a> {
+    pub const fn with_display(mut self, display: *mut std::ffi::c_void) -> Self {
+        self.inner.display = display.cast();
+        self
+    }
+
+    pub const fn with_surface(mut self, surface: *mut std::ffi::c_void) -> Self {
+        self.inner.surface = surface.cast();
+        self
+    }
+}
+
+/*
+   Headless Surface
+*/
+
+vulkan_create_info_lifetime!(
+    HeadlessSurfaceCreateInfoEXT,
+    VkHeadlessSurfaceCreateInfoEXT,
+    VK_STRUCTURE_TYPE_HEADLESS_SURFACE_CREATE_INFO_EXT
+);
+
+/*
+   Surface
+*/
+
+vulkan_handle!(SurfaceKHR, VkSurfaceKHR, SURFACE_KHR);
+
+/*
+   Window Handle Integration
+*/
+
+#[cfg(feature = "raw-window-handle")]
+#[derive(Debug, thiserror::Error)]
+pub enum WindowSurfaceError {
+    #[error("window handle unavailable: {0:?}")]
+    Handle(raw_window_handle::HandleError),
+    #[error("window system is unsupported or its cargo feature is not enabled")]
+    UnsupportedWindowSystem,
+    #[error("vulkan error: {0:?}")]
+    Vulkan(Error),
+}
+
+#[cfg(feature = "raw-window-handle")]
+impl From<raw_window_handle::HandleError> for WindowSurfaceError {
+    fn from(error: raw_window_handle::HandleError) -> Self {
+        Self::Handle(error)
+    }
+}
+
+#[cfg(feature = "raw-window-handle")]
+impl From<Error> for WindowSurfaceError {
+    fn from(error: Error) -> Self {
+        Self::Vulkan(error)
+    }
+}
+
+#[cfg(feature = "raw-window-handle")]
+impl SurfaceKHR {
+    /// Instance extensions that must be enabled to create a surface for `display`.
+    pub fn required_extensions(
+        display: &impl raw_window_handle::HasDisplayHandle,
+    ) -> std::result::Result<&'static [&'static CStr], WindowSurfaceError> {
+        use extension_names::*;
+        use raw_window_handle::RawDisplayHandle;
+
+        match display.display_handle()?.as_raw() {
+            #[cfg(target_os = "windows")]
+            RawDisplayHandle::Windows(_) => Ok(&[KHR_SURFACE, KHR_WIN32_SURFACE]),
+            #[cfg(feature = "xlib")]
+            RawDisplayHandle::Xlib(_) => Ok(&[KHR_SURFACE, KHR_XLIB_SURFACE]),
+            #[cfg(feature = "xcb")]
+            RawDisplayHandle::Xcb(_) => Ok(&[KHR_SURFACE, KHR_XCB_SURFACE]),
+            #[cfg(feature = "wayland")]
+            RawDisplayHandle::Wayland(_) => Ok(&[KHR_SURFACE, KHR_WAYLAND_SURFACE]),
+            _ => Err(WindowSurfaceError::UnsupportedWindowSystem),
+        }
+    }
+
+    /// Creates a surface for `window` using the matching platform surface extension.
+    #[allow(unused_variables)]
+    pub fn from_window(
+        instance: &Instance,
+        window: &(impl raw_window_handle::HasWindowHandle + raw_window_handle::HasDisplayHandle),
+        allocator: Option<&AllocationCallbacks>,
+    ) -> std::result::Result<Self, WindowSurfaceError> {
+        use raw_window_handle::{RawDisplayHandle, RawWindowHandle};
+
+        let display_handle = window.display_handle()?.as_raw();
+        let window_handle = window.window_handle()?.as_raw();
+
+        match (display_handle, window_handle) {
+            #[cfg(target_os = "windows")]
+            (_, RawWindowHandle::Win32(handle)) => {
+                let create_info = Win32SurfaceCreateInfoKHR::new()
+                    .with_hinstance(windows::Win32::Foundation::HINSTANCE(
+                        handle.hinstance.map_or(0, |hinstance| hinstance.get()),
+                    ))
+                    .with_hwnd(windows::Win32::Foundation::HWND(handle.hwnd.get()));
+                Ok(instance.create_win32_surface_khr(&create_info, allocator)?)
+            }
+            #[cfg(feature = "xlib")]
+            (RawDisplayHandle::Xlib(display), RawWindowHandle::Xlib(handle)) => {
+                let create_info = XlibSurfaceCreateInfoKHR::new()
+                    .with_dpy(
+                        display
+                            .display
+                            .map_or(std::ptr::null_mut(), |display| display.as_ptr()),
+                    )
+                    .with_window(handle.window);
+                Ok(instance.create_xlib_surface_khr(&create_info, allocator)?)
+            }
+            #[cfg(feature = "xcb")]
+            (RawDisplayHandle::Xcb(display), RawWindowHandle::Xcb(handle)) => {
+                let create_info = XcbSurfaceCreateInfoKHR::new()
+                    .with_connection(
+                        display
+                            .connection
+                            .map_or(std::ptr::null_mut(), |connection| connection.as_ptr()),
+                    )
+                    .with_window(handle.window.get());
+                Ok(instance.create_xcb_surface_khr(&create_info, allocator)?)
+            }
+            #[cfg(feature = "wayland")]
+            (RawDisplayHandle::Wayland(display), RawWindowHandle::Wayland(handle)) => {
+                let create_info = WaylandSurfaceCreateInfoKHR::new()
+                    .with_display(display.display.as_ptr())
+                    .with_surface(handle.surface.as_ptr());
+                Ok(instance.create_wayland_surface_khr(&create_info, allocator)?)
+            }
+            _ => Err(WindowSurfaceError::UnsupportedWindowSystem),
+        }
+    }
+}
@@ -9,7 +9,7 @@ use vulkan_sys::*;
    Swapchain
 */
 
-vulkan_handle!(SwapchainKHR, VkSwapchainKHR);
+vulkan_handle!(SwapchainKHR, VkSwapchainKHR, SWAPCHAIN_KHR);
 
 vulkan_create_info_lifetime!(
     SwapchainCreateInfoKHR,